@@ -0,0 +1,347 @@
+use crate::scalar::{Field, Real, Scalar};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// a [complex number] `re + im * i` where `T` is `f32` by default
+///
+/// [complex number]: https://en.wikipedia.org/wiki/Complex_number
+///
+/// it implements `Field` so it can be stored in a `Vector` or a `Matrix`
+pub struct Complex<T = f32> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Field> Complex<T> {
+    /// creates the complex number `re + im * i`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let z = Complex::new(1., 2.);
+    /// assert_eq!(z * z, Complex::new(-3., 4.));
+    /// ```
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+
+    /// the imaginary unit `i`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::<f32>::i() * Complex::i(), Complex::new(-1., 0.));
+    /// ```
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+
+    /// returns the [complex conjugate] `re - im * i`
+    ///
+    /// [complex conjugate]: https://en.wikipedia.org/wiki/Complex_conjugate
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::new(1., 2.).conj(), Complex::new(1., -2.));
+    /// ```
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// returns the squared absolute value `re^2 + im^2` which needs no square root
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::new(3., 4.).norm_sqr(), 25.);
+    /// ```
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// multiplies the real and the imaginary part with the real scalar
+    pub fn scale(&self, scalar: T) -> Self {
+        Complex::new(self.re * scalar, self.im * scalar)
+    }
+
+    /// returns the multiplicative inverse `1 / z`
+    ///
+    /// note for zero the parts are infinite or NaN like the division of floats
+    pub fn inv(&self) -> Self {
+        let norm_sqr = self.norm_sqr();
+        Complex::new(self.re / norm_sqr, -self.im / norm_sqr)
+    }
+}
+
+impl<T: Real> Complex<T> {
+    /// creates the complex number from its polar form `r * e^(theta * i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let z = Complex::from_polar(2_f64, std::f64::consts::FRAC_PI_2);
+    /// assert!((z - Complex::new(0., 2.)).abs() < 1e-15);
+    /// ```
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// returns the polar form `(r, theta)` with `r = abs()` and `theta = arg()`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let (r, theta) = Complex::new(0., -3.).to_polar();
+    /// assert_eq!(r, 3.);
+    /// assert_eq!(theta, -std::f32::consts::FRAC_PI_2);
+    /// ```
+    pub fn to_polar(&self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    /// returns the absolute value also called modulus
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::new(3., -4.).abs(), 5.);
+    /// ```
+    pub fn abs(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// returns the [argument] in radians in the range `(-pi, pi]`
+    ///
+    /// [argument]: https://en.wikipedia.org/wiki/Argument_(complex_analysis)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::new(-1., 0.).arg(), std::f32::consts::PI);
+    /// ```
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// returns `e^z`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let z = Complex::new(0_f64, std::f64::consts::PI).exp();
+    /// assert!((z - Complex::new(-1., 0.)).abs() < 1e-15);
+    /// ```
+    pub fn exp(&self) -> Self {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// returns the principal value of the natural logarithm, the imaginary part is in `(-pi, pi]`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let z = Complex::new(-1_f64, 0.).ln();
+    /// assert_eq!(z, Complex::new(0., std::f64::consts::PI));
+    /// ```
+    pub fn ln(&self) -> Self {
+        let (r, theta) = self.to_polar();
+        Complex::new(r.ln(), theta)
+    }
+
+    /// returns the principal square root which has a non negative real part
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// assert_eq!(Complex::new(-4., 0.).sqrt(), Complex::new(0., 2.));
+    /// assert_eq!(Complex::new(3., 4.).sqrt(), Complex::new(2., 1.));
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        // computed from the absolute value to avoid the rounding errors of sin and cos
+        let half = T::from_f64(0.5);
+        let r = self.abs();
+        let re = ((r + self.re) * half).sqrt();
+        let im = ((r - self.re) * half).sqrt();
+        if self.im < T::zero() {
+            Complex::new(re, -im)
+        } else {
+            Complex::new(re, im)
+        }
+    }
+
+    /// returns `z^exp` of the principal value
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// let z = Complex::new(0_f64, 1.).powf(2.);
+    /// assert!((z - Complex::new(-1., 0.)).abs() < 1e-15);
+    /// ```
+    pub fn powf(&self, exp: T) -> Self {
+        let (r, theta) = self.to_polar();
+        if r == T::zero() {
+            return Complex::new(T::zero(), T::zero());
+        }
+        Complex::from_polar((r.ln() * exp).exp(), theta * exp)
+    }
+
+    /// returns true if the real or the imaginary part is NaN
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+}
+
+impl<T: Field> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
+
+impl<T: Field> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Field> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T: Field> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<T: Field> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let norm_sqr = other.norm_sqr();
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / norm_sqr,
+            (self.im * other.re - self.re * other.im) / norm_sqr,
+        )
+    }
+}
+
+impl<T: Field> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Field> Mul<T> for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        self.scale(other)
+    }
+}
+
+impl<T: Field> Div<T> for Complex<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
+
+impl<T: Field> AddAssign for Complex<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Field> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Field> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Field> DivAssign for Complex<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Field> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, x| sum + x)
+    }
+}
+
+impl<T: Field> Product for Complex<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, x| product * x)
+    }
+}
+
+impl<T: Field> Scalar for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T: Field> Field for Complex<T> {}
+
+/// formats the number as `re+imi` or `re-imi`, the precision is used for both parts
+///
+/// ## Example
+///
+/// ```rust
+/// use math::complex::Complex;
+/// assert_eq!(format!("{}", Complex::new(1., -2.5)), "1-2.5i");
+/// assert_eq!(format!("{:.2}", Complex::new(0.5, 1.)), "0.50+1.00i");
+/// ```
+impl<T: Field + PartialOrd + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (sign, im) = if self.im < T::zero() {
+            ('-', -self.im)
+        } else {
+            ('+', self.im)
+        };
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}{:.*}i", precision, self.re, sign, precision, im),
+            None => write!(f, "{}{}{}i", self.re, sign, im),
+        }
+    }
+}
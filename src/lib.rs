@@ -1,6 +1,8 @@
+pub mod complex;
 pub mod linear_algebra;
 pub mod misc;
 pub mod random;
+pub mod scalar;
 
 #[cfg(test)]
 mod tests {
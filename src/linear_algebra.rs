@@ -1,5 +1,44 @@
+pub use binary::BinaryScalar;
+pub use cholesky::{CholeskyDecomposition, LdltDecomposition};
+pub use eigen::{Eigen, HermitianEigen, SymmetricEigen};
+pub use error::{FormatError, MathError};
+pub use fixed::{Mat2, Mat3, Mat4, SMatrix, SVector, Vec2, Vec3, Vec4};
+pub use gpu::{BufferLayout, BufferReader, BufferWriter, GpuData, GpuScalar};
+pub use iterative::{IterativeOptions, IterativeSolution, Jacobi, LinearOperator};
+pub use layout::Layout;
+pub use lu::LuDecomposition;
+pub use market::{MarketFormat, MarketScalar};
 pub use matrix::Matrix;
+pub use ordering::FillOrdering;
+pub use qr::QrDecomposition;
+pub use quaternion::{EulerOrder, Quaternion};
+pub use solve::{Solution, SolveMethod};
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use sparse_cholesky::{SparseCholesky, SymbolicCholesky};
+pub use sparse_lu::{SparseLu, SymbolicLu};
+pub use svd::Svd;
 pub use vector::Vector;
+pub use view::{MatrixView, MatrixViewMut, SliceRange, VectorView, VectorViewMut};
 
+pub mod binary;
+pub mod cholesky;
+pub mod eigen;
+pub mod error;
+pub mod fixed;
+pub mod gpu;
+pub mod iterative;
+pub mod layout;
+pub mod lu;
+pub mod market;
 pub mod matrix;
+pub mod ordering;
+pub mod qr;
+pub mod quaternion;
+pub mod solve;
+pub mod sparse;
+pub mod sparse_cholesky;
+pub mod sparse_lu;
+pub mod svd;
+pub mod transform;
 pub mod vector;
+pub mod view;
@@ -0,0 +1,329 @@
+use crate::complex::Complex;
+use crate::linear_algebra::{FormatError, Layout, Matrix, Vector};
+use crate::scalar::Scalar;
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+
+// the binary format starts with a header of 16 bytes
+//
+// | offset | size | field                                  |
+// |--------|------|----------------------------------------|
+// | 0      | 4    | magic `b"RSMT"`                        |
+// | 4      | 1    | version                                |
+// | 5      | 1    | endianness 0 little, 1 big             |
+// | 6      | 1    | kind 0 vector, 1 matrix                |
+// | 7      | 1    | dtype of `BinaryScalar`                |
+// | 8      | 1    | layout 0 row major, 1 col major        |
+// | 9      | 7    | reserved zeros                         |
+//
+// it is followed by the u64 len of a vector or the u64 cols and rows of a matrix
+// and the components in the layout, all numbers have the endianness of the header
+const MAGIC: [u8; 4] = *b"RSMT";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 16;
+const KIND_VECTOR: u8 = 0;
+const KIND_MATRIX: u8 = 1;
+const ROW_MAJOR: u8 = 0;
+const COL_MAJOR: u8 = 1;
+
+/// a scalar that can be stored in the binary format of `to_writer` and `from_reader`
+///
+/// the dtype is stored in the header so a file can only be read as the scalar type it was written with
+pub trait BinaryScalar: Scalar {
+    /// the code of the type in the header
+    const DTYPE: u8;
+    /// the number of bytes of one value
+    const SIZE: usize;
+
+    /// appends the little endian bytes
+    fn write_le(self, bytes: &mut Vec<u8>);
+
+    /// reads the value from `SIZE` bytes
+    fn read(bytes: &[u8], big_endian: bool) -> Self;
+}
+
+macro_rules! impl_binary_scalar {
+    ($($t:ty, $dtype:expr);*) => ($(
+        impl BinaryScalar for $t {
+            const DTYPE: u8 = $dtype;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn write_le(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read(bytes: &[u8], big_endian: bool) -> Self {
+                let mut array = [0; std::mem::size_of::<$t>()];
+                array.copy_from_slice(bytes);
+                if big_endian {
+                    <$t>::from_be_bytes(array)
+                } else {
+                    <$t>::from_le_bytes(array)
+                }
+            }
+        }
+    )*)
+}
+
+impl_binary_scalar!(i32, 1; i64, 2; u32, 3; u64, 4; f32, 5; f64, 6);
+
+// the real part is followed by the imaginary part
+macro_rules! impl_binary_complex {
+    ($($t:ty, $dtype:expr);*) => ($(
+        impl BinaryScalar for Complex<$t> {
+            const DTYPE: u8 = $dtype;
+            const SIZE: usize = 2 * <$t>::SIZE;
+
+            fn write_le(self, bytes: &mut Vec<u8>) {
+                self.re.write_le(bytes);
+                self.im.write_le(bytes);
+            }
+
+            fn read(bytes: &[u8], big_endian: bool) -> Self {
+                let (re, im) = bytes.split_at(<$t>::SIZE);
+                Complex::new(<$t>::read(re, big_endian), <$t>::read(im, big_endian))
+            }
+        }
+    )*)
+}
+
+impl_binary_complex!(f32, 7; f64, 8);
+
+impl<T: BinaryScalar> Vector<T> {
+    /// writes the vector in a versioned little endian binary format that `from_reader` reads back
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2., 3.]);
+    /// let mut bytes = Vec::new();
+    /// vector.to_writer(&mut bytes).unwrap();
+    /// assert_eq!(bytes.len(), 16 + 8 + 3 * 4);
+    /// assert_eq!(Vector::from_reader(&bytes[..]).unwrap(), vector);
+    /// ```
+    pub fn to_writer(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// returns the bytes that `to_writer` writes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = header(KIND_VECTOR, T::DTYPE, ROW_MAJOR, &[self.len()]);
+        for i in 0..self.len() {
+            self.index(i).write_le(&mut bytes);
+        }
+        bytes
+    }
+
+    /// reads a vector that was written with `to_writer`
+    ///
+    /// the reader is left after the data so more values can follow
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, FormatError> {
+        let header = read_header(&mut reader, KIND_VECTOR, T::DTYPE, 1)?;
+        Ok(Vector::from_vec(read_data(
+            &mut reader,
+            header.dims[0],
+            header.big_endian,
+        )?))
+    }
+
+    /// same as `from_reader` but returns an error if there are bytes left after the data
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{FormatError, Vector};
+    /// let bytes = Vector::new(vec![1., 2.]).to_bytes();
+    /// assert!(matches!(
+    ///     Vector::<f32>::from_bytes(&bytes[..bytes.len() - 1]),
+    ///     Err(FormatError::Truncated { expected: 8, actual: 7 })
+    /// ));
+    /// assert!(matches!(
+    ///     Vector::<f64>::from_bytes(&bytes),
+    ///     Err(FormatError::WrongDtype { expected: 6, actual: 5 })
+    /// ));
+    /// ```
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, FormatError> {
+        let vector = Vector::from_reader(&mut bytes)?;
+        check_trailing(bytes)?;
+        Ok(vector)
+    }
+}
+
+impl<T: BinaryScalar> Matrix<T> {
+    /// writes the matrix in a versioned little endian binary format that `from_reader` reads back
+    ///
+    /// a `ColMajor` matrix is written in that layout, any other layout is written as `RowMajor`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Layout, Matrix};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.transpose();
+    /// let mut bytes = Vec::new();
+    /// matrix.to_writer(&mut bytes).unwrap();
+    /// let result = Matrix::from_reader(&bytes[..]).unwrap();
+    /// assert_eq!(result, matrix);
+    /// assert_eq!(result.layout(), Layout::ColMajor);
+    /// ```
+    pub fn to_writer(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// returns the bytes that `to_writer` writes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (layout, data) = if self.layout() == Layout::ColMajor {
+            let mut transpose = self.clone();
+            transpose.transpose();
+            (COL_MAJOR, transpose.matrix_flatt())
+        } else {
+            (ROW_MAJOR, self.matrix_flatt())
+        };
+        let mut bytes = header(KIND_MATRIX, T::DTYPE, layout, &[self.cols(), self.rows()]);
+        for value in data {
+            value.write_le(&mut bytes);
+        }
+        bytes
+    }
+
+    /// reads a matrix that was written with `to_writer`
+    ///
+    /// the reader is left after the data so more values can follow
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, FormatError> {
+        let header = read_header(&mut reader, KIND_MATRIX, T::DTYPE, 2)?;
+        let (cols, rows) = (header.dims[0], header.dims[1]);
+        let len = cols.checked_mul(rows).ok_or(FormatError::InvalidHeader {
+            field: "rows",
+            value: rows as u64,
+        })?;
+        let data = read_data(&mut reader, len, header.big_endian)?;
+        let layout = match header.layout {
+            ROW_MAJOR => Layout::RowMajor,
+            _ => Layout::ColMajor,
+        };
+        Ok(Matrix::from_layout(data, cols, rows, layout))
+    }
+
+    /// same as `from_reader` but returns an error if there are bytes left after the data
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, FormatError> {
+        let matrix = Matrix::from_reader(&mut bytes)?;
+        check_trailing(bytes)?;
+        Ok(matrix)
+    }
+}
+
+struct Header {
+    big_endian: bool,
+    layout: u8,
+    dims: Vec<usize>,
+}
+
+fn header(kind: u8, dtype: u8, layout: u8, dims: &[usize]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + 8 * dims.len());
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&[VERSION, 0, kind, dtype, layout]);
+    bytes.resize(HEADER_LEN, 0);
+    for &dim in dims {
+        (dim as u64).write_le(&mut bytes);
+    }
+    bytes
+}
+
+fn read_header(
+    reader: &mut impl Read,
+    kind: u8,
+    dtype: u8,
+    dims: usize,
+) -> Result<Header, FormatError> {
+    let bytes = read_bytes(reader, HEADER_LEN)?;
+    if bytes[..4] != MAGIC {
+        return Err(FormatError::BadMagic);
+    }
+    if bytes[4] == 0 || bytes[4] > VERSION {
+        return Err(FormatError::UnsupportedVersion(bytes[4]));
+    }
+    let fields = [
+        ("endianness", bytes[5], 1),
+        ("kind", bytes[6], KIND_MATRIX),
+        ("layout", bytes[8], COL_MAJOR),
+    ];
+    for &(field, value, max) in fields.iter() {
+        if value > max {
+            return Err(FormatError::InvalidHeader {
+                field,
+                value: value as u64,
+            });
+        }
+    }
+    if let Some(&value) = bytes[9..].iter().find(|&&byte| byte != 0) {
+        return Err(FormatError::InvalidHeader {
+            field: "reserved",
+            value: value as u64,
+        });
+    }
+    if bytes[6] != kind {
+        return Err(FormatError::InvalidHeader {
+            field: "kind",
+            value: bytes[6] as u64,
+        });
+    }
+    if bytes[7] != dtype {
+        return Err(FormatError::WrongDtype {
+            expected: dtype,
+            actual: bytes[7],
+        });
+    }
+    let big_endian = bytes[5] == 1;
+    let dims = read_bytes(reader, 8 * dims)?
+        .chunks(8)
+        .map(|chunk| {
+            let dim = u64::read(chunk, big_endian);
+            usize::try_from(dim).map_err(|_| FormatError::InvalidHeader {
+                field: "dimension",
+                value: dim,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Header {
+        big_endian,
+        layout: bytes[8],
+        dims,
+    })
+}
+
+fn read_data<T: BinaryScalar>(
+    reader: &mut impl Read,
+    len: usize,
+    big_endian: bool,
+) -> Result<Vec<T>, FormatError> {
+    let size = len.checked_mul(T::SIZE).ok_or(FormatError::InvalidHeader {
+        field: "dimension",
+        value: len as u64,
+    })?;
+    Ok(read_bytes(reader, size)?
+        .chunks(T::SIZE)
+        .map(|chunk| T::read(chunk, big_endian))
+        .collect())
+}
+
+// reads exactly `len` bytes, the buffer only grows with the input so a corrupt len does not allocate it
+fn read_bytes(reader: &mut impl Read, len: usize) -> Result<Vec<u8>, FormatError> {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < len {
+        return Err(FormatError::Truncated {
+            expected: len,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes)
+}
+
+fn check_trailing(bytes: &[u8]) -> Result<(), FormatError> {
+    if bytes.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::TrailingBytes { len: bytes.len() })
+    }
+}
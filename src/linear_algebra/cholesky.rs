@@ -0,0 +1,381 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::Real;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [Cholesky decomposition] `A = L * L^T` of a symmetric positive definite matrix
+///
+/// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+///
+/// L is lower triangular with a positive diagonal
+pub struct CholeskyDecomposition<T = f32> {
+    n: usize,
+    // L stored row by row, the part above the diagonal is zero
+    l: Vec<T>,
+}
+
+impl<T: Real> CholeskyDecomposition<T> {
+    /// decomposes the symmetric positive definite matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CholeskyDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// let cholesky = CholeskyDecomposition::new(&matrix);
+    /// assert_eq!(cholesky.l(), Matrix::new(vec![vec![2., 0.], vec![1., 2.]]));
+    /// ```
+    /// note it panics if the matrix is not symmetric or not positive definite
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not symmetric or not positive definite
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CholeskyDecomposition, MathError, Matrix};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// assert_eq!(
+    ///     CholeskyDecomposition::try_new(&matrix),
+    ///     Err(MathError::NotPositiveDefinite)
+    /// );
+    /// ```
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        check_symmetric(matrix)?;
+
+        let n = matrix.rows();
+        let a = matrix.matrix_flatt();
+        let mut l = vec![T::zero(); n * n];
+
+        for j in 0..n {
+            let s: T = (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum();
+            let d = a[j * n + j] - s;
+            if d <= T::zero() || d.is_nan() {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            let l_jj = d.sqrt();
+            l[j * n + j] = l_jj;
+
+            for i in (j + 1)..n {
+                let s: T = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum();
+                l[i * n + j] = (a[i * n + j] - s) / l_jj;
+            }
+        }
+
+        Ok(Self { n, l })
+    }
+
+    /// returns the lower triangular matrix L
+    pub fn l(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.l.clone(), self.n, self.n)
+    }
+
+    /// returns the [determinant] of the decomposed matrix
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// assert_eq!(matrix.cholesky().det(), 16.);
+    /// ```
+    pub fn det(&self) -> T {
+        (0..self.n)
+            .map(|i| self.l[i * self.n + i])
+            .fold(T::one(), |det, x| det * x * x)
+    }
+
+    /// returns the natural logarithm of the determinant
+    ///
+    /// this doesn't overflow like `det()` for large matrices
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// assert_eq!(matrix.cholesky().log_det(), 16_f32.ln());
+    /// ```
+    pub fn log_det(&self) -> T {
+        T::from_f64(2.) * (0..self.n).map(|i| self.l[i * self.n + i].ln()).sum::<T>()
+    }
+
+    /// solves the system `A * x = b` and returns x
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// let x = matrix.cholesky().solve(&Vector::new(vec![6., 7.]));
+    /// assert_eq!(x, Vector::new(vec![1., 1.]));
+    /// ```
+    /// note it panics if b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if b has the wrong len
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        if b.len() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.len(),
+            });
+        }
+        let mut x = b.vec();
+        self.substitute(&mut x, 1);
+        Ok(Vector::from_vec(x))
+    }
+
+    /// solves the system `A * X = B` for every column of B and returns X
+    ///
+    /// note it panics if B has the wrong shape
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.try_solve_mat(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat` but returns an error if B has the wrong shape
+    pub fn try_solve_mat(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if b.cols() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.cols(),
+            });
+        }
+        let k = b.rows();
+        let mut x = b.matrix_flatt();
+        self.substitute(&mut x, k);
+        Ok(Matrix::from_flatt(x, self.n, k))
+    }
+
+    // solves L * y = b and then L^T * x = y in place for the k columns of x
+    fn substitute(&self, x: &mut [T], k: usize) {
+        let n = self.n;
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.l[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= l_ij * x[j * k + c];
+                }
+            }
+            for c in 0..k {
+                x[i * k + c] /= self.l[i * n + i];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let l_ji = self.l[j * n + i];
+                for c in 0..k {
+                    x[i * k + c] -= l_ji * x[j * k + c];
+                }
+            }
+            for c in 0..k {
+                x[i * k + c] /= self.l[i * n + i];
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [LDL decomposition] `A = L * D * L^T` of a symmetric positive semidefinite matrix
+///
+/// [LDL decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition#LDL_decomposition
+///
+/// L is lower triangular with ones on the diagonal and D is diagonal,
+/// unlike the cholesky decomposition this needs no square roots and works if D has zeros
+pub struct LdltDecomposition<T = f32> {
+    n: usize,
+    // L stored row by row including the ones on the diagonal
+    l: Vec<T>,
+    d: Vec<T>,
+}
+
+impl<T: Real> LdltDecomposition<T> {
+    /// decomposes the symmetric positive semidefinite matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LdltDecomposition, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 1.]]);
+    /// let ldlt = LdltDecomposition::new(&matrix);
+    /// assert_eq!(ldlt.l(), Matrix::new(vec![vec![1., 0.], vec![0.5, 1.]]));
+    /// assert_eq!(ldlt.d(), Vector::new(vec![4., 0.]));
+    /// ```
+    /// note it panics if the matrix is not symmetric or has a negative eigenvalue
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not symmetric or has a negative eigenvalue
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        check_symmetric(matrix)?;
+
+        let n = matrix.rows();
+        let a = matrix.matrix_flatt();
+        let max = a.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = T::from_usize(n) * T::epsilon() * max;
+
+        let mut l = vec![T::zero(); n * n];
+        let mut d = vec![T::zero(); n];
+
+        for j in 0..n {
+            let s: T = (0..j).map(|k| l[j * n + k] * l[j * n + k] * d[k]).sum();
+            let d_j = a[j * n + j] - s;
+            if d_j < -tolerance || d_j.is_nan() {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            l[j * n + j] = T::one();
+
+            if d_j <= tolerance {
+                // the whole column has to vanish for a semidefinite matrix so L keeps zeros,
+                // otherwise the matrix is indefinite like `[[0, 1], [1, 0]]`
+                for i in (j + 1)..n {
+                    let s: T = (0..j).map(|k| l[i * n + k] * l[j * n + k] * d[k]).sum();
+                    if (a[i * n + j] - s).abs() > tolerance {
+                        return Err(MathError::NotPositiveDefinite);
+                    }
+                }
+                continue;
+            }
+            d[j] = d_j;
+
+            for i in (j + 1)..n {
+                let s: T = (0..j).map(|k| l[i * n + k] * l[j * n + k] * d[k]).sum();
+                l[i * n + j] = (a[i * n + j] - s) / d_j;
+            }
+        }
+
+        Ok(Self { n, l, d })
+    }
+
+    /// returns the lower triangular matrix L with ones on the diagonal
+    pub fn l(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.l.clone(), self.n, self.n)
+    }
+
+    /// returns the diagonal of D
+    pub fn d(&self) -> Vector<T> {
+        Vector::from_vec(self.d.clone())
+    }
+
+    /// returns true if D has a zero on the diagonal
+    pub fn is_singular(&self) -> bool {
+        self.d.contains(&T::zero())
+    }
+
+    /// returns the [determinant] of the decomposed matrix
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    pub fn det(&self) -> T {
+        self.d.iter().copied().product()
+    }
+
+    /// returns the natural logarithm of the determinant, `-inf` if the matrix is singular
+    pub fn log_det(&self) -> T {
+        self.d.iter().map(|d| d.ln()).sum()
+    }
+
+    /// solves the system `A * x = b` and returns x
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// let x = matrix.ldlt().solve(&Vector::new(vec![6., 7.]));
+    /// assert_eq!(x, Vector::new(vec![1., 1.]));
+    /// ```
+    /// note it panics if the matrix is singular or b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if the matrix is singular or b has the wrong len
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        if b.len() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.len(),
+            });
+        }
+        let mut x = b.vec();
+        self.substitute(&mut x, 1)?;
+        Ok(Vector::from_vec(x))
+    }
+
+    /// solves the system `A * X = B` for every column of B and returns X
+    ///
+    /// note it panics if the matrix is singular or B has the wrong shape
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.try_solve_mat(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat` but returns an error if the matrix is singular or B has the wrong shape
+    pub fn try_solve_mat(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if b.cols() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.cols(),
+            });
+        }
+        let k = b.rows();
+        let mut x = b.matrix_flatt();
+        self.substitute(&mut x, k)?;
+        Ok(Matrix::from_flatt(x, self.n, k))
+    }
+
+    // solves L * y = b, D * z = y and then L^T * x = z in place for the k columns of x
+    fn substitute(&self, x: &mut [T], k: usize) -> Result<(), MathError> {
+        if self.is_singular() {
+            return Err(MathError::Singular);
+        }
+
+        let n = self.n;
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.l[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= l_ij * x[j * k + c];
+                }
+            }
+        }
+        for i in 0..n {
+            for c in 0..k {
+                x[i * k + c] /= self.d[i];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let l_ji = self.l[j * n + i];
+                for c in 0..k {
+                    x[i * k + c] -= l_ji * x[j * k + c];
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_symmetric<T: Real>(matrix: &Matrix<T>) -> Result<(), MathError> {
+    if !matrix.is_square() {
+        return Err(MathError::NotSquare {
+            cols: matrix.cols(),
+            rows: matrix.rows(),
+        });
+    }
+    if !matrix.is_symmetric() {
+        return Err(MathError::NotSymmetric);
+    }
+    Ok(())
+}
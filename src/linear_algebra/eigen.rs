@@ -0,0 +1,1112 @@
+use crate::complex::Complex;
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::{Real, Scalar};
+
+// the cyclic jacobi method converges quadratically so this is never reached in practice
+const MAX_SWEEPS: usize = 64;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [eigendecomposition] `A = V * diag(λ) * V^T` of a symmetric matrix
+///
+/// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+///
+/// all eigenvalues λ are real and sorted from the largest to the smallest,
+/// the eigenvectors are orthonormal and stored in the columns of V in the same order
+pub struct SymmetricEigen<T = f32> {
+    n: usize,
+    values: Vec<T>,
+    // V stored row by row, the eigenvector i is the column i
+    vectors: Vec<T>,
+}
+
+impl<T: Real> SymmetricEigen<T> {
+    /// decomposes the symmetric matrix with the cyclic [Jacobi eigenvalue algorithm]
+    ///
+    /// [Jacobi eigenvalue algorithm]: https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, SymmetricEigen};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 2.]]);
+    /// let eigen = SymmetricEigen::new(&matrix);
+    /// let values = eigen.eigenvalues();
+    /// assert!((values.index(0) - 3.).abs() < 1e-5);
+    /// assert!((values.index(1) - 1.).abs() < 1e-5);
+    /// ```
+    /// note it panics if the matrix is not symmetric
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not symmetric or the iteration did not converge
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix, SymmetricEigen};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![3., 2.]]);
+    /// assert_eq!(SymmetricEigen::try_new(&matrix), Err(MathError::NotSymmetric));
+    /// ```
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        if !matrix.is_square() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+        if !matrix.is_symmetric() {
+            return Err(MathError::NotSymmetric);
+        }
+
+        let n = matrix.rows();
+        let mut a = matrix.matrix_flatt();
+        let mut v = vec![T::zero(); n * n];
+        for i in 0..n {
+            v[i * n + i] = T::one();
+        }
+
+        let norm = a.iter().map(|&x| x * x).sum::<T>().sqrt();
+        let mut converged = false;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (a_pp, a_qq, a_pq) = (a[p * n + p], a[q * n + q], a[p * n + q]);
+                    // off diagonal elements below the rounding error of the diagonal are dropped
+                    let threshold =
+                        T::epsilon() * ((a_pp * a_qq).abs().sqrt() + T::epsilon() * norm);
+                    if a_pq.abs() <= threshold {
+                        a[p * n + q] = T::zero();
+                        a[q * n + p] = T::zero();
+                        continue;
+                    }
+                    rotated = true;
+
+                    let theta = (a_qq - a_pp) / (T::from_f64(2.) * a_pq);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (a_kp, a_kq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * a_kp - s * a_kq;
+                        a[k * n + q] = s * a_kp + c * a_kq;
+                    }
+                    for k in 0..n {
+                        let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * a_pk - s * a_qk;
+                        a[q * n + k] = s * a_pk + c * a_qk;
+                    }
+                    a[p * n + q] = T::zero();
+                    a[q * n + p] = T::zero();
+
+                    for k in 0..n {
+                        let (v_kp, v_kq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * v_kp - s * v_kq;
+                        v[k * n + q] = s * v_kp + c * v_kq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Err(MathError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[j * n + j].total_cmp(&a[i * n + i]));
+
+        let values = order.iter().map(|&i| a[i * n + i]).collect();
+        let mut vectors = vec![T::zero(); n * n];
+        for (col, &i) in order.iter().enumerate() {
+            // the sign of an eigenvector is arbitrary so the largest component is made positive
+            let largest = (0..n)
+                .max_by(|&j, &k| v[j * n + i].abs().total_cmp(&v[k * n + i].abs()))
+                .unwrap_or(0);
+            let sign = if v[largest * n + i] < T::zero() {
+                -T::one()
+            } else {
+                T::one()
+            };
+            for k in 0..n {
+                vectors[k * n + col] = sign * v[k * n + i];
+            }
+        }
+
+        Ok(Self { n, values, vectors })
+    }
+
+    /// returns all eigenvalues sorted from the largest to the smallest
+    pub fn eigenvalues(&self) -> Vector<T> {
+        Vector::from_vec(self.values.clone())
+    }
+
+    /// returns the matrix V where the column i is the eigenvector of the eigenvalue i
+    pub fn eigenvectors(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.vectors.clone(), self.n, self.n)
+    }
+
+    /// returns the normalized eigenvector that belongs to `eigenvalues().index(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![3., 0.], vec![0., 5.]]);
+    /// let eigen = matrix.symmetric_eigen();
+    /// assert_eq!(eigen.eigenvector(0), Vector::new(vec![0., 1.]));
+    /// assert_eq!(eigen.eigenvector(1), Vector::new(vec![1., 0.]));
+    /// ```
+    pub fn eigenvector(&self, i: usize) -> Vector<T> {
+        if i >= self.n {
+            panic!("index out of bounds max eigenvector {}", self.n - 1)
+        }
+        Vector::from_vec((0..self.n).map(|k| self.vectors[k * self.n + i]).collect())
+    }
+
+    /// returns the dominant eigenvalue which has the largest absolute value and its eigenvector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 0.], vec![0., -4.]]);
+    /// let (val, vec) = matrix.symmetric_eigen().dominant();
+    /// assert_eq!(val, -4.);
+    /// assert_eq!(vec, Vector::new(vec![0., 1.]));
+    /// ```
+    pub fn dominant(&self) -> (T, Vector<T>) {
+        if self.n == 0 {
+            panic!("the matrix has no eigenvalues");
+        }
+        // the values are sorted so the dominant one is either the first or the last
+        let i = if self.values[self.n - 1].abs() > self.values[0].abs() {
+            self.n - 1
+        } else {
+            0
+        };
+        (self.values[i], self.eigenvector(i))
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [eigendecomposition] `A = V * diag(λ) * V^H` of a Hermitian matrix
+///
+/// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+///
+/// all eigenvalues λ are real and sorted from the largest to the smallest,
+/// the complex eigenvectors are orthonormal and stored in the columns of the unitary matrix V
+pub struct HermitianEigen<T = f32> {
+    n: usize,
+    values: Vec<T>,
+    // V stored row by row, the eigenvector i is the column i
+    vectors: Vec<Complex<T>>,
+}
+
+impl<T: Real> HermitianEigen<T> {
+    /// decomposes the Hermitian matrix with the complex cyclic [Jacobi eigenvalue algorithm]
+    ///
+    /// [Jacobi eigenvalue algorithm]: https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::{HermitianEigen, Matrix};
+    /// // the pauli matrix y
+    /// let matrix = Matrix::from_vec(vec![
+    ///     vec![Complex::new(0_f32, 0.), Complex::new(0., 1.)],
+    ///     vec![Complex::new(0., -1.), Complex::new(0., 0.)],
+    /// ]);
+    /// let eigen = HermitianEigen::new(&matrix);
+    /// let values = eigen.eigenvalues();
+    /// assert!((values.index(0) - 1.).abs() < 1e-5);
+    /// assert!((values.index(1) + 1.).abs() < 1e-5);
+    /// ```
+    /// note it panics if the matrix is not Hermitian
+    pub fn new(matrix: &Matrix<Complex<T>>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not Hermitian or the iteration did not converge
+    ///
+    /// a matrix that is square but not Hermitian returns `MathError::NotSymmetric`
+    pub fn try_new(matrix: &Matrix<Complex<T>>) -> Result<Self, MathError> {
+        if !matrix.is_square() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+        if !matrix.is_hermitian() {
+            return Err(MathError::NotSymmetric);
+        }
+
+        let n = matrix.rows();
+        let mut a = matrix.matrix_flatt();
+        let mut v = vec![Complex::zero(); n * n];
+        for i in 0..n {
+            v[i * n + i] = Complex::one();
+        }
+
+        let norm = a.iter().map(|x| x.norm_sqr()).sum::<T>().sqrt();
+        let mut converged = false;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (a_pp, a_qq, a_pq) = (a[p * n + p].re, a[q * n + q].re, a[p * n + q]);
+                    let r = a_pq.abs();
+                    let threshold =
+                        T::epsilon() * ((a_pp * a_qq).abs().sqrt() + T::epsilon() * norm);
+                    if r <= threshold {
+                        a[p * n + q] = Complex::zero();
+                        a[q * n + p] = Complex::zero();
+                        continue;
+                    }
+                    rotated = true;
+
+                    // U = diag(1, conj(e)) * R removes the phase e of a_pq and then does the real
+                    // jacobi rotation R of the symmetric matrix [a_pp, r] [r, a_qq]
+                    let e = a_pq / r;
+                    let theta = (a_qq - a_pp) / (T::from_f64(2.) * r);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+                    let (u_qp, u_qq) = (e.conj() * -s, e.conj() * c);
+
+                    for k in 0..n {
+                        let (a_kp, a_kq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = a_kp * c + a_kq * u_qp;
+                        a[k * n + q] = a_kp * s + a_kq * u_qq;
+                    }
+                    for k in 0..n {
+                        let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = a_pk * c + a_qk * u_qp.conj();
+                        a[q * n + k] = a_pk * s + a_qk * u_qq.conj();
+                    }
+                    a[p * n + q] = Complex::zero();
+                    a[q * n + p] = Complex::zero();
+                    a[p * n + p].im = T::zero();
+                    a[q * n + q].im = T::zero();
+
+                    for k in 0..n {
+                        let (v_kp, v_kq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = v_kp * c + v_kq * u_qp;
+                        v[k * n + q] = v_kp * s + v_kq * u_qq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Err(MathError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[j * n + j].re.total_cmp(&a[i * n + i].re));
+
+        let values = order.iter().map(|&i| a[i * n + i].re).collect();
+        let mut vectors = vec![Complex::zero(); n * n];
+        for (col, &i) in order.iter().enumerate() {
+            // the phase of an eigenvector is arbitrary so the largest component is made real and positive
+            let largest = (0..n)
+                .max_by(|&j, &k| v[j * n + i].abs().total_cmp(&v[k * n + i].abs()))
+                .unwrap_or(0);
+            let x = v[largest * n + i];
+            let phase = if x.abs() > T::zero() {
+                x.conj() / x.abs()
+            } else {
+                Complex::one()
+            };
+            for k in 0..n {
+                vectors[k * n + col] = v[k * n + i] * phase;
+            }
+        }
+
+        Ok(Self { n, values, vectors })
+    }
+
+    /// returns all eigenvalues sorted from the largest to the smallest
+    pub fn eigenvalues(&self) -> Vector<T> {
+        Vector::from_vec(self.values.clone())
+    }
+
+    /// returns the unitary matrix V where the column i is the eigenvector of the eigenvalue i
+    pub fn eigenvectors(&self) -> Matrix<Complex<T>> {
+        Matrix::from_flatt(self.vectors.clone(), self.n, self.n)
+    }
+
+    /// returns the normalized eigenvector that belongs to `eigenvalues().index(i)`,
+    /// its largest component is real and positive
+    pub fn eigenvector(&self, i: usize) -> Vector<Complex<T>> {
+        if i >= self.n {
+            panic!("index out of bounds max eigenvector {}", self.n - 1)
+        }
+        Vector::from_vec((0..self.n).map(|k| self.vectors[k * self.n + i]).collect())
+    }
+}
+
+// the number of francis steps to find one eigenvalue before giving up, usually it takes 2 to 4
+const MAX_STEPS: usize = 100;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [eigendecomposition] `A * V = V * D` of a general real square matrix
+///
+/// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+///
+/// the matrix is reduced to [Hessenberg form] and then to the real Schur form with the
+/// shifted [Francis QR algorithm], the eigenvalues are in the order they appear on the diagonal
+/// of the Schur form and complex eigenvalues come in conjugated pairs next to each other
+///
+/// [Hessenberg form]: https://en.wikipedia.org/wiki/Hessenberg_matrix
+/// [Francis QR algorithm]: https://en.wikipedia.org/wiki/QR_algorithm
+pub struct Eigen<T = f32> {
+    n: usize,
+    real: Vec<T>,
+    imag: Vec<T>,
+    // V stored row by row, a complex pair λ = a ± bi at i and i + 1 has the eigenvector
+    // column i + column i+1 * i and its conjugate
+    vectors: Option<Vec<Vec<T>>>,
+}
+
+impl<T: Real> Eigen<T> {
+    /// computes the eigenvalues and the right eigenvectors of the square matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Eigen, Matrix};
+    /// // rotates by 90 degrees so it has the eigenvalues i and -i
+    /// let matrix = Matrix::new(vec![vec![0., -1.], vec![1., 0.]]);
+    /// let eigen = Eigen::new(&matrix);
+    /// assert_eq!(eigen.eigenvalue(0), (0., 1.));
+    /// assert_eq!(eigen.eigenvalue(1), (0., -1.));
+    /// ```
+    /// note the matrix has to be a [square matrix]
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not square or the iteration did not converge,
+    /// which is also the case for a matrix with nan or infinite values
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        Self::decompose(matrix, true)
+    }
+
+    /// computes only the eigenvalues of the square matrix which is faster than `new`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Eigen, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![0., 3.]]);
+    /// let eigen = Eigen::new_without_vectors(&matrix);
+    /// assert_eq!(eigen.real_eigenvalues(), Vector::new(vec![2., 3.]));
+    /// assert_eq!(eigen.has_vectors(), false);
+    /// ```
+    pub fn new_without_vectors(matrix: &Matrix<T>) -> Self {
+        Self::try_new_without_vectors(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new_without_vectors` but returns an error if the matrix is not square
+    /// or the iteration did not converge like for a matrix with nan or infinite values
+    pub fn try_new_without_vectors(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        Self::decompose(matrix, false)
+    }
+
+    /// returns the real parts of all eigenvalues
+    pub fn real_eigenvalues(&self) -> Vector<T> {
+        Vector::from_vec(self.real.clone())
+    }
+
+    /// returns the imaginary parts of all eigenvalues
+    pub fn imag_eigenvalues(&self) -> Vector<T> {
+        Vector::from_vec(self.imag.clone())
+    }
+
+    /// returns the real and the imaginary part of the eigenvalue i
+    pub fn eigenvalue(&self, i: usize) -> (T, T) {
+        if i >= self.n {
+            panic!("index out of bounds max eigenvalue {}", self.n - 1)
+        }
+        (self.real[i], self.imag[i])
+    }
+
+    /// returns all eigenvalues as complex numbers
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::{Eigen, Matrix};
+    /// let matrix = Matrix::new(vec![vec![0., -1.], vec![1., 0.]]);
+    /// let values = Eigen::new(&matrix).complex_eigenvalues();
+    /// assert_eq!(values.index(0), Complex::new(0., 1.));
+    /// assert_eq!(values.index(1), Complex::new(0., -1.));
+    /// ```
+    pub fn complex_eigenvalues(&self) -> Vector<Complex<T>> {
+        Vector::from_vec(
+            self.real
+                .iter()
+                .zip(&self.imag)
+                .map(|(&re, &im)| Complex::new(re, im))
+                .collect(),
+        )
+    }
+
+    /// returns the normalized eigenvector of eigenvalue i as a complex vector
+    ///
+    /// note it panics if the eigenvectors were not computed
+    pub fn complex_eigenvector(&self, i: usize) -> Vector<Complex<T>> {
+        let (real, imag) = self.eigenvector(i);
+        Vector::from_vec(
+            real.vec()
+                .into_iter()
+                .zip(imag.vec())
+                .map(|(re, im)| Complex::new(re, im))
+                .collect(),
+        )
+    }
+
+    /// returns true if all eigenvalues are real
+    pub fn is_real(&self) -> bool {
+        self.imag.iter().all(|&x| x == T::zero())
+    }
+
+    /// returns true if the eigenvectors were computed
+    pub fn has_vectors(&self) -> bool {
+        self.vectors.is_some()
+    }
+
+    /// returns the real block diagonal matrix D with `A * V = V * D`
+    ///
+    /// real eigenvalues are on the diagonal and a complex pair a ± bi is the 2 * 2 block
+    /// `[a, b]` `[-b, a]`
+    pub fn d(&self) -> Matrix<T> {
+        let n = self.n;
+        let mut d = vec![T::zero(); n * n];
+        for i in 0..n {
+            d[i * n + i] = self.real[i];
+            if self.imag[i] > T::zero() {
+                d[i * n + i + 1] = self.imag[i];
+            } else if self.imag[i] < T::zero() {
+                d[i * n + i - 1] = self.imag[i];
+            }
+        }
+        Matrix::from_flatt(d, n, n)
+    }
+
+    /// returns the real matrix V with `A * V = V * D`
+    ///
+    /// the column i is the eigenvector of a real eigenvalue i, for a complex pair at i and i + 1
+    /// the columns i and i + 1 are the real and imaginary part of the eigenvector of eigenvalue i
+    ///
+    /// note it panics if the eigenvectors were not computed
+    pub fn eigenvectors(&self) -> Matrix<T> {
+        let n = self.n;
+        let v = self.vectors();
+        Matrix::from_flatt(v.iter().flatten().copied().collect(), n, n)
+    }
+
+    /// returns the real and the imaginary part of the normalized eigenvector of eigenvalue i
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 0.], vec![0., 3.]]);
+    /// let (real, imag) = matrix.eigen().eigenvector(1);
+    /// assert_eq!(real, Vector::new(vec![0., 1.]));
+    /// assert_eq!(imag, Vector::new(vec![0., 0.]));
+    /// ```
+    /// note it panics if the eigenvectors were not computed
+    pub fn eigenvector(&self, i: usize) -> (Vector<T>, Vector<T>) {
+        if i >= self.n {
+            panic!("index out of bounds max eigenvector {}", self.n - 1)
+        }
+        let v = self.vectors();
+        let column =
+            |j: usize| -> Vector<T> { Vector::from_vec(v.iter().map(|row| row[j]).collect()) };
+
+        if self.imag[i] > T::zero() {
+            (column(i), column(i + 1))
+        } else if self.imag[i] < T::zero() {
+            let mut imag = column(i);
+            imag.mul_scalar(&-T::one());
+            (column(i - 1), imag)
+        } else {
+            (column(i), Vector::zero(self.n))
+        }
+    }
+
+    fn vectors(&self) -> &Vec<Vec<T>> {
+        match &self.vectors {
+            Some(vectors) => vectors,
+            None => panic!("the eigenvectors were not computed"),
+        }
+    }
+
+    fn decompose(matrix: &Matrix<T>, with_vectors: bool) -> Result<Self, MathError> {
+        if !matrix.is_square() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+
+        let n = matrix.rows();
+        let flatt = matrix.matrix_flatt();
+        check_finite(&flatt)?;
+        let mut h: Vec<Vec<T>> = (0..n).map(|i| flatt[i * n..(i + 1) * n].to_vec()).collect();
+        let mut v: Vec<Vec<T>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { T::one() } else { T::zero() })
+                    .collect()
+            })
+            .collect();
+
+        hessenberg(&mut h, &mut v, with_vectors);
+        let norm = hessenberg_norm(&h);
+        let (real, imag) = schur(&mut h, &mut v, norm, with_vectors)?;
+        check_finite(&real)?;
+        check_finite(&imag)?;
+
+        let vectors = if with_vectors {
+            back_substitute(&mut h, &mut v, &real, &imag, norm);
+            normalize(&mut v, &imag);
+            v.iter().try_for_each(|row| check_finite(row))?;
+            Some(v)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            n,
+            real,
+            imag,
+            vectors,
+        })
+    }
+}
+
+// the iteration can not converge on values that are nan or infinite
+fn check_finite<T: Real>(values: &[T]) -> Result<(), MathError> {
+    if values.iter().any(|x| !x.is_finite()) {
+        return Err(MathError::NoConvergence { iterations: 0 });
+    }
+    Ok(())
+}
+
+// reduces h to upper hessenberg form with householder reflections and accumulates them in v
+fn hessenberg<T: Real>(h: &mut [Vec<T>], v: &mut [Vec<T>], with_vectors: bool) {
+    let n = h.len();
+    if n < 3 {
+        return;
+    }
+    let high = n - 1;
+    let mut ort = vec![T::zero(); n];
+
+    for m in 1..high {
+        let scale: T = (m..=high).map(|i| h[i][m - 1].abs()).sum();
+        if scale == T::zero() {
+            continue;
+        }
+
+        let mut norm = T::zero();
+        for i in (m..=high).rev() {
+            ort[i] = h[i][m - 1] / scale;
+            norm += ort[i] * ort[i];
+        }
+        let mut g = norm.sqrt();
+        if ort[m] > T::zero() {
+            g = -g;
+        }
+        norm -= ort[m] * g;
+        ort[m] -= g;
+
+        for j in m..n {
+            let f: T = (m..=high).rev().map(|i| ort[i] * h[i][j]).sum::<T>() / norm;
+            for (row, &o) in h[m..=high].iter_mut().zip(&ort[m..=high]) {
+                row[j] -= f * o;
+            }
+        }
+        for row in h.iter_mut() {
+            let f: T = (m..=high).rev().map(|j| ort[j] * row[j]).sum::<T>() / norm;
+            for j in m..=high {
+                row[j] -= f * ort[j];
+            }
+        }
+        ort[m] *= scale;
+        h[m][m - 1] = scale * g;
+    }
+
+    if !with_vectors {
+        return;
+    }
+    for m in (1..high).rev() {
+        if h[m][m - 1] == T::zero() {
+            continue;
+        }
+        for i in (m + 1)..=high {
+            ort[i] = h[i][m - 1];
+        }
+        for j in m..=high {
+            let g: T = (m..=high).map(|i| ort[i] * v[i][j]).sum();
+            // the double division avoids a possible underflow
+            let g = (g / ort[m]) / h[m][m - 1];
+            for (row, &o) in v[m..=high].iter_mut().zip(&ort[m..=high]) {
+                row[j] += g * o;
+            }
+        }
+    }
+}
+
+// the sum of the absolute values of all elements of the hessenberg matrix h
+fn hessenberg_norm<T: Real>(h: &[Vec<T>]) -> T {
+    h.iter()
+        .enumerate()
+        .map(|(i, row)| row[i.max(1) - 1..].iter().map(|x| x.abs()).sum::<T>())
+        .sum()
+}
+
+// reduces the hessenberg matrix h to the real schur form with the double shift francis qr steps
+// and returns the real and imaginary parts of the eigenvalues
+fn schur<T: Real>(
+    h: &mut [Vec<T>],
+    v: &mut [Vec<T>],
+    norm: T,
+    with_vectors: bool,
+) -> Result<(Vec<T>, Vec<T>), MathError> {
+    let nn = h.len();
+    let mut d = vec![T::zero(); nn];
+    let mut e = vec![T::zero(); nn];
+    if nn == 0 {
+        return Ok((d, e));
+    }
+
+    let eps = T::epsilon();
+    let mut exshift = T::zero();
+    let (mut p, mut q, mut r, mut s, mut z): (T, T, T, T, T);
+    let (mut w, mut x, mut y): (T, T, T);
+
+    let mut n = nn as isize - 1;
+    let mut iter = 0;
+    while n >= 0 {
+        let nu = n as usize;
+
+        // looks for a single small sub diagonal element
+        let mut l = nu;
+        while l > 0 {
+            s = h[l - 1][l - 1].abs() + h[l][l].abs();
+            if s == T::zero() {
+                s = norm;
+            }
+            if h[l][l - 1].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == nu {
+            // one root found
+            h[nu][nu] += exshift;
+            d[nu] = h[nu][nu];
+            e[nu] = T::zero();
+            n -= 1;
+            iter = 0;
+        } else if l + 1 == nu {
+            // two roots found
+            w = h[nu][nu - 1] * h[nu - 1][nu];
+            p = (h[nu - 1][nu - 1] - h[nu][nu]) / T::from_f64(2.);
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[nu][nu] += exshift;
+            h[nu - 1][nu - 1] += exshift;
+            x = h[nu][nu];
+
+            if q >= T::zero() {
+                // real pair
+                z = if p >= T::zero() { p + z } else { p - z };
+                d[nu - 1] = x + z;
+                d[nu] = d[nu - 1];
+                if z != T::zero() {
+                    d[nu] = x - w / z;
+                }
+                e[nu - 1] = T::zero();
+                e[nu] = T::zero();
+                x = h[nu][nu - 1];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+
+                let (upper, lower) = h.split_at_mut(nu);
+                let rows = upper[nu - 1][nu - 1..].iter_mut();
+                for (a, b) in rows.zip(lower[0][nu - 1..].iter_mut()) {
+                    z = *a;
+                    *a = q * z + p * *b;
+                    *b = q * *b - p * z;
+                }
+                for row in h.iter_mut().take(nu + 1) {
+                    z = row[nu - 1];
+                    row[nu - 1] = q * z + p * row[nu];
+                    row[nu] = q * row[nu] - p * z;
+                }
+                if with_vectors {
+                    for row in v.iter_mut() {
+                        z = row[nu - 1];
+                        row[nu - 1] = q * z + p * row[nu];
+                        row[nu] = q * row[nu] - p * z;
+                    }
+                }
+            } else {
+                // complex pair
+                d[nu - 1] = x + p;
+                d[nu] = x + p;
+                e[nu - 1] = z;
+                e[nu] = -z;
+            }
+            n -= 2;
+            iter = 0;
+        } else {
+            // no convergence yet so a shift is formed
+            x = h[nu][nu];
+            y = T::zero();
+            w = T::zero();
+            if l < nu {
+                y = h[nu - 1][nu - 1];
+                w = h[nu][nu - 1] * h[nu - 1][nu];
+            }
+
+            // wilkinson's original ad hoc shift
+            if iter == 10 {
+                exshift += x;
+                for (i, row) in h.iter_mut().enumerate().take(nu + 1) {
+                    row[i] -= x;
+                }
+                s = h[nu][nu - 1].abs() + h[nu - 1][nu - 2].abs();
+                x = T::from_f64(0.75) * s;
+                y = x;
+                w = -T::from_f64(0.4375) * s * s;
+            }
+
+            // matlab's new ad hoc shift
+            if iter == 30 {
+                s = (y - x) / T::from_f64(2.);
+                s = s * s + w;
+                if s > T::zero() {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / T::from_f64(2.) + s);
+                    for (i, row) in h.iter_mut().enumerate().take(nu + 1) {
+                        row[i] -= s;
+                    }
+                    exshift += s;
+                    x = T::from_f64(0.964);
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iter += 1;
+            if iter > MAX_STEPS {
+                return Err(MathError::NoConvergence {
+                    iterations: MAX_STEPS,
+                });
+            }
+
+            // looks for two consecutive small sub diagonal elements
+            let mut m = nu - 2;
+            loop {
+                z = h[m][m];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r - s;
+                r = h[m + 2][m + 1];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[m][m - 1].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=nu {
+                h[i][i - 2] = T::zero();
+                if i > m + 2 {
+                    h[i][i - 3] = T::zero();
+                }
+            }
+
+            // double qr step on the rows l..=n and the columns m..=n
+            for k in m..nu {
+                let notlast = k != nu - 1;
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if notlast { h[k + 2][k - 1] } else { T::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == T::zero() {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+                s = (p * p + q * q + r * r).sqrt();
+                if p < T::zero() {
+                    s = -s;
+                }
+                if s == T::zero() {
+                    continue;
+                }
+
+                if k != m {
+                    h[k][k - 1] = -s * x;
+                } else if l != m {
+                    h[k][k - 1] = -h[k][k - 1];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q /= p;
+                r /= p;
+
+                let (upper, lower) = h.split_at_mut(k + 1);
+                let (row_k1, rest) = lower.split_first_mut().unwrap();
+                let mut row_k2 = rest.first_mut().filter(|_| notlast);
+                for j in k..nn {
+                    p = upper[k][j] + q * row_k1[j];
+                    if let Some(row_k2) = row_k2.as_mut() {
+                        p += r * row_k2[j];
+                        row_k2[j] -= p * z;
+                    }
+                    upper[k][j] -= p * x;
+                    row_k1[j] -= p * y;
+                }
+                for row in h.iter_mut().take(nu.min(k + 3) + 1) {
+                    p = x * row[k] + y * row[k + 1];
+                    if notlast {
+                        p += z * row[k + 2];
+                        row[k + 2] -= p * r;
+                    }
+                    row[k] -= p;
+                    row[k + 1] -= p * q;
+                }
+                if with_vectors {
+                    for row in v.iter_mut() {
+                        p = x * row[k] + y * row[k + 1];
+                        if notlast {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k] -= p;
+                        row[k + 1] -= p * q;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((d, e))
+}
+
+// solves for the eigenvectors of the schur form h and transforms them back with v
+fn back_substitute<T: Real>(h: &mut [Vec<T>], v: &mut [Vec<T>], d: &[T], e: &[T], norm: T) {
+    let nn = h.len();
+    let eps = T::epsilon();
+    if norm == T::zero() {
+        return;
+    }
+
+    let (mut r, mut s, mut z) = (T::zero(), T::zero(), T::zero());
+    let (mut t, mut w, mut x, mut y): (T, T, T, T);
+
+    for n in (0..nn).rev() {
+        let p = d[n];
+        let q = e[n];
+
+        if q == T::zero() {
+            // real vector
+            let mut l = n;
+            h[n][n] = T::one();
+            for i in (0..n).rev() {
+                w = h[i][i] - p;
+                r = (l..=n).map(|j| h[i][j] * h[j][n]).sum();
+                if e[i] < T::zero() {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+                l = i;
+                if e[i] == T::zero() {
+                    h[i][n] = if w != T::zero() {
+                        -r / w
+                    } else {
+                        -r / (eps * norm)
+                    };
+                } else {
+                    x = h[i][i + 1];
+                    y = h[i + 1][i];
+                    let q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    t = (x * s - z * r) / q;
+                    h[i][n] = t;
+                    h[i + 1][n] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                // overflow control
+                t = h[i][n].abs();
+                if (eps * t) * t > T::one() {
+                    for row in h.iter_mut().take(n + 1).skip(i) {
+                        row[n] /= t;
+                    }
+                }
+            }
+        } else if q < T::zero() {
+            // complex vector, the last component is imaginary so the matrix is triangular
+            let mut l = n - 1;
+            if h[n][n - 1].abs() > h[n - 1][n].abs() {
+                h[n - 1][n - 1] = q / h[n][n - 1];
+                h[n - 1][n] = -(h[n][n] - p) / h[n][n - 1];
+            } else {
+                let (cr, ci) = cdiv(T::zero(), -h[n - 1][n], h[n - 1][n - 1] - p, q);
+                h[n - 1][n - 1] = cr;
+                h[n - 1][n] = ci;
+            }
+            h[n][n - 1] = T::zero();
+            h[n][n] = T::one();
+
+            for i in (0..n.saturating_sub(1)).rev() {
+                let ra: T = (l..=n).map(|j| h[i][j] * h[j][n - 1]).sum();
+                let sa: T = (l..=n).map(|j| h[i][j] * h[j][n]).sum();
+                w = h[i][i] - p;
+
+                if e[i] < T::zero() {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if e[i] == T::zero() {
+                    let (cr, ci) = cdiv(-ra, -sa, w, q);
+                    h[i][n - 1] = cr;
+                    h[i][n] = ci;
+                } else {
+                    // solves the complex equations
+                    x = h[i][i + 1];
+                    y = h[i + 1][i];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * T::from_f64(2.) * q;
+                    if vr == T::zero() && vi == T::zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let (cr, ci) = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[i][n - 1] = cr;
+                    h[i][n] = ci;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[i + 1][n - 1] = (-ra - w * h[i][n - 1] + q * h[i][n]) / x;
+                        h[i + 1][n] = (-sa - w * h[i][n] - q * h[i][n - 1]) / x;
+                    } else {
+                        let (cr, ci) = cdiv(-r - y * h[i][n - 1], -s - y * h[i][n], z, q);
+                        h[i + 1][n - 1] = cr;
+                        h[i + 1][n] = ci;
+                    }
+                }
+
+                // overflow control
+                t = h[i][n - 1].abs().max(h[i][n].abs());
+                if (eps * t) * t > T::one() {
+                    for row in h.iter_mut().take(n + 1).skip(i) {
+                        row[n - 1] /= t;
+                        row[n] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    // back transformation to get the eigenvectors of the original matrix
+    for j in (0..nn).rev() {
+        for row in v.iter_mut() {
+            row[j] = (0..=j).map(|k| row[k] * h[k][j]).sum();
+        }
+    }
+}
+
+// scales every eigenvector to length 1, a complex pair is scaled by its complex length
+fn normalize<T: Real>(v: &mut [Vec<T>], imag: &[T]) {
+    let n = imag.len();
+    let mut j = 0;
+    while j < n {
+        let cols = if imag[j] > T::zero() {
+            j..j + 2
+        } else {
+            j..j + 1
+        };
+        let norm = v
+            .iter()
+            .map(|row| cols.clone().map(|c| row[c] * row[c]).sum::<T>())
+            .sum::<T>()
+            .sqrt();
+        if norm != T::zero() {
+            for row in v.iter_mut() {
+                for c in cols.clone() {
+                    row[c] /= norm;
+                }
+            }
+        }
+        j = cols.end;
+    }
+}
+
+// complex division (xr + xi * i) / (yr + yi * i)
+fn cdiv<T: Real>(xr: T, xi: T, yr: T, yi: T) -> (T, T) {
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the error returned by the `try_*` functions
+///
+/// the panicking functions panic with the `Display` message of this error
+pub enum MathError {
+    /// a vector len or a matrix dimension is not the one that was expected
+    ShapeMismatch { expected: usize, actual: usize },
+    /// the matrix has to be a [square matrix]
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    NotSquare { cols: usize, rows: usize },
+    /// the matrix has to be a [symmetric matrix]
+    ///
+    /// [symmetric matrix]: https://en.wikipedia.org/wiki/Symmetric_matrix
+    NotSymmetric,
+    /// the matrix is not [positive definite] or for the LDL^T decomposition not positive semidefinite
+    ///
+    /// [positive definite]: https://en.wikipedia.org/wiki/Definite_matrix
+    NotPositiveDefinite,
+    /// the matrix is [singular] or does not have full rank
+    ///
+    /// [singular]: https://en.wikipedia.org/wiki/Invertible_matrix
+    Singular,
+    /// the index is larger than the last valid index
+    IndexOutOfBounds { index: usize, len: usize },
+    /// the input has no elements
+    EmptyInput,
+    /// an iterative algorithm did not converge in the maximal number of iterations
+    NoConvergence { iterations: usize },
+    /// the strides of a matrix layout map two different indices to the same position
+    OverlappingStrides { strides: (usize, usize) },
+    /// the sparse matrix does not have the nonzero pattern that the symbolic analysis was computed for
+    PatternMismatch,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::ShapeMismatch { expected, actual } => {
+                write!(f, "wrong shape expected {}, got {}", expected, actual)
+            }
+            MathError::NotSquare { cols, rows } => write!(
+                f,
+                "the matrix has to be a square matrix, got {} cols and {} rows",
+                cols, rows
+            ),
+            MathError::NotSymmetric => write!(f, "the matrix has to be symmetric"),
+            MathError::NotPositiveDefinite => write!(f, "the matrix is not positive definite"),
+            MathError::Singular => write!(f, "the matrix is singular"),
+            MathError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds for len {}", index, len)
+            }
+            MathError::EmptyInput => write!(f, "the input is empty"),
+            MathError::NoConvergence { iterations } => {
+                write!(f, "did not converge after {} iterations", iterations)
+            }
+            MathError::OverlappingStrides { strides } => write!(
+                f,
+                "the strides ({}, {}) map different indices to the same position",
+                strides.0, strides.1
+            ),
+            MathError::PatternMismatch => write!(
+                f,
+                "the nonzero pattern differs from the pattern of the symbolic analysis"
+            ),
+        }
+    }
+}
+
+impl Error for MathError {}
+
+#[derive(Debug)]
+/// the error returned when a `Vector` or a `Matrix` is read from a file format
+pub enum FormatError {
+    /// the reader failed
+    Io(io::Error),
+    /// the input ended after `actual` bytes of a part that needs `expected` bytes
+    Truncated { expected: usize, actual: usize },
+    /// the input does not start with the magic bytes of the format
+    BadMagic,
+    /// the version of the format is newer than the one this crate can read
+    UnsupportedVersion(u8),
+    /// the stored scalar type is not the requested one
+    WrongDtype { expected: u8, actual: u8 },
+    /// a field of the header has a value that is not defined by the format
+    InvalidHeader { field: &'static str, value: u64 },
+    /// there are bytes left after the data
+    TrailingBytes { len: usize },
+    /// the line of a text format is malformed, the first line is 1
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::Io(error) => write!(f, "failed to read the input: {}", error),
+            FormatError::Truncated { expected, actual } => write!(
+                f,
+                "the input is truncated expected {} bytes, got {}",
+                expected, actual
+            ),
+            FormatError::BadMagic => write!(f, "the input does not start with the magic bytes"),
+            FormatError::UnsupportedVersion(version) => {
+                write!(f, "the format version {} is not supported", version)
+            }
+            FormatError::WrongDtype { expected, actual } => write!(
+                f,
+                "wrong scalar type expected dtype {}, got {}",
+                expected, actual
+            ),
+            FormatError::InvalidHeader { field, value } => {
+                write!(f, "invalid value {} of the header field {}", value, field)
+            }
+            FormatError::TrailingBytes { len } => {
+                write!(f, "there are {} bytes left after the data", len)
+            }
+            FormatError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FormatError {
+    fn from(error: io::Error) -> Self {
+        FormatError::Io(error)
+    }
+}
@@ -0,0 +1,702 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::{Matrix, MatrixView, Vector, VectorView};
+use crate::scalar::{Field, Real, Scalar};
+use std::convert::TryFrom;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// a vector with `N` components of the scalar type `T` that is stored on the stack
+///
+/// the len is part of the type so functions like `cross_vec` only exist for `N = 3`
+/// and a vector of the wrong len does not compile
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Vec3, Vector};
+/// let a = Vec3::new([1., 0., 0.]);
+/// let b = Vec3::new([0., 1., 0.]);
+/// assert_eq!(a.cross_vec(b), Vec3::new([0., 0., 1.]));
+/// assert_eq!(Vector::from(a + b), Vector::new(vec![1., 1., 0.]));
+/// ```
+pub struct SVector<const N: usize, T = f32> {
+    data: [T; N],
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// a matrix of the scalar type `T` with `R` arrays of `C` components that is stored on the stack
+///
+/// it is laid out like the `Vec<Vec<T>>` of `Matrix::new` so the first index of `index(row, col)`
+/// is smaller than `R` and the converted `Matrix` has `R` cols and `C` rows
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Mat2, Matrix, SMatrix, Vec2};
+/// let a = SMatrix::new([[1., 2., 3.], [4., 5., 6.]]);
+/// let b = SMatrix::new([[1., 0.], [0., 1.], [1., 1.]]);
+/// let product: Mat2 = a * b;
+/// assert_eq!(product, Mat2::new([[4., 5.], [10., 11.]]));
+/// assert_eq!(product * Vec2::new([1., -1.]), Vec2::new([-1., -1.]));
+/// assert_eq!(Matrix::from(a).dot_mat(&Matrix::from(b)), Matrix::from(product));
+/// ```
+pub struct SMatrix<const R: usize, const C: usize, T = f32> {
+    data: [[T; C]; R],
+}
+
+/// a vector with 2 components
+pub type Vec2<T = f32> = SVector<2, T>;
+/// a vector with 3 components
+pub type Vec3<T = f32> = SVector<3, T>;
+/// a vector with 4 components
+pub type Vec4<T = f32> = SVector<4, T>;
+/// a 2x2 matrix
+pub type Mat2<T = f32> = SMatrix<2, 2, T>;
+/// a 3x3 matrix
+pub type Mat3<T = f32> = SMatrix<3, 3, T>;
+/// a 4x4 matrix
+pub type Mat4<T = f32> = SMatrix<4, 4, T>;
+
+impl<const N: usize, T: Scalar> SVector<N, T> {
+    /// creates the vector from an array
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec3;
+    /// let vector = Vec3::new([1., 2., 3.]);
+    /// assert_eq!(vector[1], 2.);
+    /// ```
+    pub fn new(data: [T; N]) -> Self {
+        SVector { data }
+    }
+
+    /// creates the vector with all components being zero
+    pub fn zero() -> Self {
+        SVector::new([T::zero(); N])
+    }
+
+    /// creates the vector whose component `i` is `f(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec4;
+    /// assert_eq!(Vec4::from_fn(|i| i as f32), Vec4::new([0., 1., 2., 3.]));
+    /// ```
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        SVector::new(std::array::from_fn(f))
+    }
+
+    /// returns the number of components `N`
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// returns true if `N` is zero
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// returns the components as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// returns the components as an array
+    pub fn to_array(&self) -> [T; N] {
+        self.data
+    }
+
+    /// returns a borrowed view of the vector so it can be used with the functions of `Vector`
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView::from_slice(&self.data)
+    }
+
+    /// copies the components in to a dynamic `Vector`
+    pub fn to_vector(&self) -> Vector<T> {
+        Vector::from_vec(self.data.to_vec())
+    }
+
+    /// returns the vector with the components at the indices
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Vec2, Vec4};
+    /// let vector = Vec4::new([1., 2., 3., 4.]);
+    /// assert_eq!(vector.swizzle([3, 0]), Vec2::new([4., 1.]));
+    /// ```
+    /// note it panics if an index is out of bounds, the named swizzles like `xzy()` check it at compile time
+    pub fn swizzle<const M: usize>(&self, indices: [usize; M]) -> SVector<M, T> {
+        SVector::new(indices.map(|i| self[i]))
+    }
+
+    /// returns the [dot product]
+    ///
+    /// [dot product]: https://en.wikipedia.org/wiki/Dot_product
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec3;
+    /// let a = Vec3::new([2., 7., 1.]);
+    /// let b = Vec3::new([8., 2., 8.]);
+    /// assert_eq!(a.dot_vec(b), 38.);
+    /// ```
+    pub fn dot_vec(&self, other: SVector<N, T>) -> T {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| a * b)
+            .sum()
+    }
+
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        SVector::new(self.data.map(f))
+    }
+
+    fn zip(mut self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        for i in 0..N {
+            self.data[i] = f(self.data[i], other.data[i]);
+        }
+        self
+    }
+}
+
+impl<T: Scalar> SVector<3, T> {
+    /// returns the [cross product] which is only defined for 3 dimensional vectors
+    ///
+    /// [cross product]: https://en.wikipedia.org/wiki/Cross_product
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec3;
+    /// let a = Vec3::new([1., 2., 3.]);
+    /// let b = Vec3::new([-2., 0., 1.]);
+    /// assert_eq!(a.cross_vec(b), Vec3::new([2., -7., 4.]));
+    /// ```
+    pub fn cross_vec(&self, other: SVector<3, T>) -> SVector<3, T> {
+        let (a, b) = (self.data, other.data);
+        SVector::new([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+}
+
+impl<const N: usize, T: Real> SVector<N, T> {
+    /// returns the magnetude of the vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec2;
+    /// assert_eq!(Vec2::new([3., 4.]).mag(), 5.);
+    /// ```
+    pub fn mag(&self) -> T {
+        self.dot_vec(*self).sqrt()
+    }
+
+    /// calculates the [Euclidean distance] between 2 vectors
+    ///
+    /// [Euclidean distance]:https://en.wikipedia.org/wiki/Euclidean_distance
+    pub fn dist(&self, other: SVector<N, T>) -> T {
+        (*self - other).mag()
+    }
+
+    /// normalizes the vetor same dirction but the magnetude is 1
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vec3;
+    /// let mut vector = Vec3::new([0., 3., 4.]);
+    /// vector.unit();
+    /// assert_eq!(vector, Vec3::new([0., 0.6, 0.8]));
+    /// ```
+    pub fn unit(&mut self) {
+        *self /= self.mag();
+    }
+}
+
+// defines the named component getters, the index is checked against `N` at compile time
+macro_rules! impl_component {
+    ($($name:ident $i:literal),*) => {
+        impl<const N: usize, T: Scalar> SVector<N, T> {
+            $(
+                #[doc = concat!("returns the component `", stringify!($name), "` at index ", $i)]
+                ///
+                /// note it does not compile if the vector has not enough components
+                pub fn $name(&self) -> T {
+                    const { assert!($i < N, "the vector has not enough components") };
+                    self.data[$i]
+                }
+            )*
+        }
+    };
+}
+
+impl_component!(x 0, y 1, z 2, w 3);
+
+// defines the swizzles like `xzy()` that return a new vector of the named components,
+// the indices are checked against `N` at compile time
+macro_rules! impl_swizzle {
+    ($len:literal; $($name:ident $($i:literal)+),*) => {
+        impl<const N: usize, T: Scalar> SVector<N, T> {
+            $(
+                #[doc = concat!("returns the swizzle `", stringify!($name), "` as a new vector")]
+                pub fn $name(&self) -> SVector<$len, T> {
+                    const { $(assert!($i < N, "the vector has not enough components");)+ };
+                    SVector::new([$(self.data[$i]),+])
+                }
+            )*
+        }
+    };
+}
+
+impl_swizzle!(
+    2;
+    xx 0 0, xy 0 1, xz 0 2, xw 0 3, yx 1 0, yy 1 1, yz 1 2, yw 1 3, zx 2 0, zy 2 1, zz 2 2,
+    zw 2 3, wx 3 0, wy 3 1, wz 3 2, ww 3 3
+);
+impl_swizzle!(
+    3;
+    xxx 0 0 0, xxy 0 0 1, xxz 0 0 2, xxw 0 0 3, xyx 0 1 0, xyy 0 1 1, xyz 0 1 2, xyw 0 1 3,
+    xzx 0 2 0, xzy 0 2 1, xzz 0 2 2, xzw 0 2 3, xwx 0 3 0, xwy 0 3 1, xwz 0 3 2, xww 0 3 3,
+    yxx 1 0 0, yxy 1 0 1, yxz 1 0 2, yxw 1 0 3, yyx 1 1 0, yyy 1 1 1, yyz 1 1 2, yyw 1 1 3,
+    yzx 1 2 0, yzy 1 2 1, yzz 1 2 2, yzw 1 2 3, ywx 1 3 0, ywy 1 3 1, ywz 1 3 2, yww 1 3 3,
+    zxx 2 0 0, zxy 2 0 1, zxz 2 0 2, zxw 2 0 3, zyx 2 1 0, zyy 2 1 1, zyz 2 1 2, zyw 2 1 3,
+    zzx 2 2 0, zzy 2 2 1, zzz 2 2 2, zzw 2 2 3, zwx 2 3 0, zwy 2 3 1, zwz 2 3 2, zww 2 3 3,
+    wxx 3 0 0, wxy 3 0 1, wxz 3 0 2, wxw 3 0 3, wyx 3 1 0, wyy 3 1 1, wyz 3 1 2, wyw 3 1 3,
+    wzx 3 2 0, wzy 3 2 1, wzz 3 2 2, wzw 3 2 3, wwx 3 3 0, wwy 3 3 1, wwz 3 3 2, www 3 3 3
+);
+
+impl<const R: usize, const C: usize, T: Scalar> SMatrix<R, C, T> {
+    /// creates the matrix from `R` arrays of `C` components like `Matrix::new`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::SMatrix;
+    /// let matrix = SMatrix::new([[3., 2., 4.], [4., 5., 6.]]);
+    /// assert_eq!(matrix[(1, 0)], 4.);
+    /// ```
+    pub fn new(data: [[T; C]; R]) -> Self {
+        SMatrix { data }
+    }
+
+    /// creates the matrix with all components being zero
+    pub fn zero() -> Self {
+        SMatrix::new([[T::zero(); C]; R])
+    }
+
+    /// creates the matrix whose `index(row, col)` is `f(row, col)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::SMatrix;
+    /// let matrix = SMatrix::<2, 3>::from_fn(|row, col| (row * 3 + col) as f32);
+    /// assert_eq!(matrix, SMatrix::new([[0., 1., 2.], [3., 4., 5.]]));
+    /// ```
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        SMatrix::new(std::array::from_fn(|row| {
+            std::array::from_fn(|col| f(row, col))
+        }))
+    }
+
+    /// return index(row, col) from matrix
+    pub fn index(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+
+    /// returns the arrays of the matrix
+    pub fn to_array(&self) -> [[T; C]; R] {
+        self.data
+    }
+
+    /// returns all components one array after the other like `Matrix::matrix_flatt`
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// returns a borrowed view of the matrix so it can be used with the functions of `Matrix`
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::from_parts(self.as_slice(), 0, R, C, (C, 1))
+    }
+
+    /// copies the components in to a dynamic `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.as_slice().to_vec(), R, C)
+    }
+
+    /// returns the [transpose] which has `C` arrays of `R` components
+    ///
+    /// [transpose]: https://en.wikipedia.org/wiki/Transpose
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::SMatrix;
+    /// let matrix = SMatrix::new([[1., 2., 3.], [4., 5., 6.]]);
+    /// assert_eq!(matrix.transpose(), SMatrix::new([[1., 4.], [2., 5.], [3., 6.]]));
+    /// ```
+    pub fn transpose(&self) -> SMatrix<C, R, T> {
+        SMatrix::from_fn(|row, col| self.data[col][row])
+    }
+
+    /// computes the dot product between the vector and this matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{SMatrix, Vec2, Vec3};
+    /// let matrix = SMatrix::new([[1., -1., 2.], [0., -3., 1.]]);
+    /// assert_eq!(matrix.dot_vec(Vec3::new([2., 1., 0.])), Vec2::new([1., -3.]));
+    /// ```
+    pub fn dot_vec(&self, vector: SVector<C, T>) -> SVector<R, T> {
+        SVector::from_fn(|row| SVector::new(self.data[row]).dot_vec(vector))
+    }
+
+    /// computes the [matrix product] the inner dimension `C` is checked at compile time
+    ///
+    /// [matrix product]: https://en.wikipedia.org/wiki/Matrix_multiplication
+    pub fn dot_mat<const K: usize>(&self, other: SMatrix<C, K, T>) -> SMatrix<R, K, T> {
+        SMatrix::from_fn(|row, col| (0..C).map(|i| self.data[row][i] * other.data[i][col]).sum())
+    }
+
+    fn map(mut self, f: impl Fn(T) -> T) -> Self {
+        self.data.iter_mut().flatten().for_each(|x| *x = f(*x));
+        self
+    }
+
+    fn zip(mut self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        self.data
+            .iter_mut()
+            .flatten()
+            .zip(other.as_slice())
+            .for_each(|(x, &y)| *x = f(*x, y));
+        self
+    }
+}
+
+impl<const N: usize, T: Scalar> SMatrix<N, N, T> {
+    /// creates the [identity matrix]
+    ///
+    /// [identity matrix]: https://en.wikipedia.org/wiki/Identity_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat3, Vec3};
+    /// let vector = Vec3::new([1., 2., 3.]);
+    /// assert_eq!(Mat3::identity() * vector, vector);
+    /// ```
+    pub fn identity() -> Self {
+        SMatrix::from_fn(|row, col| if row == col { T::one() } else { T::zero() })
+    }
+}
+
+impl<const N: usize, T: Scalar> Index<usize> for SVector<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<const N: usize, T: Scalar> IndexMut<usize> for SVector<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Index<(usize, usize)> for SMatrix<R, C, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row][col]
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> IndexMut<(usize, usize)> for SMatrix<R, C, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row][col]
+    }
+}
+
+// implements the component wise `op` and `op_assign` with another value of the same type and with scalars
+macro_rules! impl_fixed_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $symbol:tt) => {
+        impl<const N: usize, T: Scalar> $op for SVector<N, T> {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                self.zip(other, |a, b| a $symbol b)
+            }
+        }
+
+        impl<const N: usize, T: Scalar> $op<T> for SVector<N, T> {
+            type Output = Self;
+
+            fn $method(self, scalar: T) -> Self {
+                self.map(|a| a $symbol scalar)
+            }
+        }
+
+        impl<const N: usize, T: Scalar> $op_assign for SVector<N, T> {
+            fn $method_assign(&mut self, other: Self) {
+                *self = *self $symbol other;
+            }
+        }
+
+        impl<const N: usize, T: Scalar> $op_assign<T> for SVector<N, T> {
+            fn $method_assign(&mut self, scalar: T) {
+                *self = *self $symbol scalar;
+            }
+        }
+
+        impl<const R: usize, const C: usize, T: Scalar> $op<T> for SMatrix<R, C, T> {
+            type Output = Self;
+
+            fn $method(self, scalar: T) -> Self {
+                self.map(|a| a $symbol scalar)
+            }
+        }
+
+        impl<const R: usize, const C: usize, T: Scalar> $op_assign<T> for SMatrix<R, C, T> {
+            fn $method_assign(&mut self, scalar: T) {
+                *self = *self $symbol scalar;
+            }
+        }
+    };
+}
+
+impl_fixed_op!(Add, add, AddAssign, add_assign, +);
+impl_fixed_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_fixed_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_fixed_op!(Div, div, DivAssign, div_assign, /);
+
+impl<const R: usize, const C: usize, T: Scalar> Add for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> AddAssign for SMatrix<R, C, T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Sub for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> SubAssign for SMatrix<R, C, T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// the matrix product `a * b` same as `a.dot_mat(b)`
+impl<const R: usize, const C: usize, const K: usize, T: Scalar> Mul<SMatrix<C, K, T>>
+    for SMatrix<R, C, T>
+{
+    type Output = SMatrix<R, K, T>;
+
+    fn mul(self, other: SMatrix<C, K, T>) -> SMatrix<R, K, T> {
+        self.dot_mat(other)
+    }
+}
+
+/// the matrix vector product `a * v` same as `a.dot_vec(v)`
+impl<const R: usize, const C: usize, T: Scalar> Mul<SVector<C, T>> for SMatrix<R, C, T> {
+    type Output = SVector<R, T>;
+
+    fn mul(self, vector: SVector<C, T>) -> SVector<R, T> {
+        self.dot_vec(vector)
+    }
+}
+
+impl<const N: usize, T: Field> Neg for SVector<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Field> Neg for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+// implements `scalar * vector` and `scalar * matrix` for the primitive scalars
+macro_rules! impl_scalar_fixed_mul {
+    ($($t:ty)*) => ($(
+        impl<const N: usize> Mul<SVector<N, $t>> for $t {
+            type Output = SVector<N, $t>;
+
+            fn mul(self, vector: SVector<N, $t>) -> SVector<N, $t> {
+                vector * self
+            }
+        }
+
+        impl<const R: usize, const C: usize> Mul<SMatrix<R, C, $t>> for $t {
+            type Output = SMatrix<R, C, $t>;
+
+            fn mul(self, matrix: SMatrix<R, C, $t>) -> SMatrix<R, C, $t> {
+                matrix * self
+            }
+        }
+    )*)
+}
+
+impl_scalar_fixed_mul!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+impl<const N: usize, T: Scalar> From<[T; N]> for SVector<N, T> {
+    fn from(data: [T; N]) -> Self {
+        SVector::new(data)
+    }
+}
+
+impl<const N: usize, T: Scalar> From<SVector<N, T>> for [T; N] {
+    fn from(vector: SVector<N, T>) -> Self {
+        vector.data
+    }
+}
+
+impl<const N: usize, T: Scalar> From<SVector<N, T>> for Vector<T> {
+    fn from(vector: SVector<N, T>) -> Self {
+        vector.to_vector()
+    }
+}
+
+/// converts a dynamic `Vector` with the len `N`
+///
+/// ## Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use math::linear_algebra::{MathError, Vec3, Vector};
+/// let vector = Vector::new(vec![1., 2., 3.]);
+/// assert_eq!(Vec3::try_from(&vector), Ok(Vec3::new([1., 2., 3.])));
+/// assert_eq!(
+///     Vec3::try_from(&Vector::new(vec![1., 2.])),
+///     Err(MathError::ShapeMismatch { expected: 3, actual: 2 })
+/// );
+/// ```
+impl<'a, const N: usize, T: Scalar> TryFrom<&'a Vector<T>> for SVector<N, T> {
+    type Error = MathError;
+
+    fn try_from(vector: &'a Vector<T>) -> Result<Self, MathError> {
+        if vector.len() != N {
+            return Err(MathError::ShapeMismatch {
+                expected: N,
+                actual: vector.len(),
+            });
+        }
+        Ok(SVector::from_fn(|i| vector.index(i)))
+    }
+}
+
+impl<const N: usize, T: Scalar> TryFrom<Vector<T>> for SVector<N, T> {
+    type Error = MathError;
+
+    fn try_from(vector: Vector<T>) -> Result<Self, MathError> {
+        SVector::try_from(&vector)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<[[T; C]; R]> for SMatrix<R, C, T> {
+    fn from(data: [[T; C]; R]) -> Self {
+        SMatrix::new(data)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<SMatrix<R, C, T>> for [[T; C]; R] {
+    fn from(matrix: SMatrix<R, C, T>) -> Self {
+        matrix.data
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<SMatrix<R, C, T>> for Matrix<T> {
+    fn from(matrix: SMatrix<R, C, T>) -> Self {
+        matrix.to_matrix()
+    }
+}
+
+/// converts a dynamic `Matrix` with `R` cols and `C` rows in any layout
+///
+/// ## Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use math::linear_algebra::{MathError, Mat2, Matrix, SMatrix};
+/// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]);
+/// matrix.transpose();
+/// assert_eq!(
+///     SMatrix::try_from(&matrix),
+///     Ok(SMatrix::new([[1., 3., 5.], [2., 4., 6.]]))
+/// );
+/// assert_eq!(
+///     Mat2::try_from(&matrix),
+///     Err(MathError::ShapeMismatch { expected: 2, actual: 3 })
+/// );
+/// ```
+impl<'a, const R: usize, const C: usize, T: Scalar> TryFrom<&'a Matrix<T>> for SMatrix<R, C, T> {
+    type Error = MathError;
+
+    fn try_from(matrix: &'a Matrix<T>) -> Result<Self, MathError> {
+        if matrix.cols() != R {
+            return Err(MathError::ShapeMismatch {
+                expected: R,
+                actual: matrix.cols(),
+            });
+        }
+        if matrix.rows() != C {
+            return Err(MathError::ShapeMismatch {
+                expected: C,
+                actual: matrix.rows(),
+            });
+        }
+        Ok(SMatrix::from_fn(|row, col| matrix.index(row, col)))
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> TryFrom<Matrix<T>> for SMatrix<R, C, T> {
+    type Error = MathError;
+
+    fn try_from(matrix: Matrix<T>) -> Result<Self, MathError> {
+        SMatrix::try_from(&matrix)
+    }
+}
+
+impl<'a, const N: usize, T: Scalar> From<&'a SVector<N, T>> for VectorView<'a, T> {
+    fn from(vector: &'a SVector<N, T>) -> Self {
+        vector.view()
+    }
+}
+
+impl<'a, const R: usize, const C: usize, T: Scalar> From<&'a SMatrix<R, C, T>>
+    for MatrixView<'a, T>
+{
+    fn from(matrix: &'a SMatrix<R, C, T>) -> Self {
+        matrix.view()
+    }
+}
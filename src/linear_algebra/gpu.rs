@@ -0,0 +1,467 @@
+use crate::linear_algebra::{MathError, Matrix, SMatrix, SVector, Vector};
+use crate::scalar::Scalar;
+use std::convert::TryInto;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the [memory layout] of a GLSL uniform or storage buffer
+///
+/// [memory layout]: https://www.khronos.org/opengl/wiki/Interface_Block_(GLSL)#Memory_layout
+///
+/// a `vec3` is aligned like a `vec4` in both layouts, `Std140` additionally rounds the alignment
+/// of arrays, matrix columns and structs up to 16 bytes
+pub enum BufferLayout {
+    /// the layout of uniform buffers
+    Std140,
+    /// the layout of storage buffers and push constants
+    Std430,
+}
+
+impl BufferLayout {
+    // the alignment of arrays and structs whose elements are aligned to `align`
+    fn aggregate_align(&self, align: usize) -> usize {
+        match self {
+            BufferLayout::Std140 => round_up(align, 16),
+            BufferLayout::Std430 => align,
+        }
+    }
+}
+
+/// a scalar that is stored as 4 little endian bytes in a GPU buffer
+pub trait GpuScalar: Scalar {
+    fn to_gpu_bytes(self) -> [u8; 4];
+    fn from_gpu_bytes(bytes: [u8; 4]) -> Self;
+}
+
+macro_rules! impl_gpu_scalar {
+    ($($t:ty)*) => ($(
+        impl GpuScalar for $t {
+            fn to_gpu_bytes(self) -> [u8; 4] {
+                self.to_le_bytes()
+            }
+
+            fn from_gpu_bytes(bytes: [u8; 4]) -> Self {
+                <$t>::from_le_bytes(bytes)
+            }
+        }
+    )*)
+}
+
+impl_gpu_scalar!(f32 i32 u32);
+
+/// a value that can be written in to and read from a GPU buffer
+///
+/// implement it for a struct of the shader by writing the fields with `BufferWriter::write_struct`
+/// and reading them with `BufferReader::read_struct`, then arrays of the struct work as well
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{
+///     BufferLayout, BufferReader, BufferWriter, GpuData, MathError, Vec3,
+/// };
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Light {
+///     position: Vec3,
+///     intensity: f32,
+/// }
+///
+/// impl GpuData for Light {
+///     fn write_to(&self, writer: &mut BufferWriter) {
+///         writer.write_struct(|writer| {
+///             writer.write(&self.position);
+///             writer.write(&self.intensity);
+///         });
+///     }
+///
+///     fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+///         reader.read_struct(|reader| {
+///             Ok(Light {
+///                 position: reader.read()?,
+///                 intensity: reader.read()?,
+///             })
+///         })
+///     }
+/// }
+///
+/// let lights = [
+///     Light { position: Vec3::new([1., 2., 3.]), intensity: 0.5 },
+///     Light { position: Vec3::new([4., 5., 6.]), intensity: 1. },
+/// ];
+/// let mut writer = BufferWriter::new(BufferLayout::Std140);
+/// writer.write(&1_u32);
+/// writer.write(&lights);
+/// let bytes = writer.into_bytes();
+/// assert_eq!(bytes.len(), 48);
+///
+/// let mut reader = BufferReader::new(&bytes, BufferLayout::Std140);
+/// assert_eq!(reader.read::<u32>(), Ok(1));
+/// assert_eq!(reader.read::<[Light; 2]>(), Ok(lights));
+/// ```
+pub trait GpuData: Sized {
+    /// writes the value at the next offset that is aligned for it
+    fn write_to(&self, writer: &mut BufferWriter);
+
+    /// reads the value from the next offset that is aligned for it
+    fn read_from(reader: &mut BufferReader) -> Result<Self, MathError>;
+}
+
+/// writes values in to a byte buffer with the padding of a `BufferLayout`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{BufferLayout, BufferWriter, Vec3};
+/// let mut writer = BufferWriter::new(BufferLayout::Std430);
+/// writer.write(&1_f32);
+/// writer.write(&Vec3::new([2., 3., 4.]));
+/// writer.write(&5_f32);
+/// let bytes = writer.into_bytes();
+/// assert_eq!(bytes.len(), 32);
+/// assert_eq!(&bytes[16..20], &2_f32.to_le_bytes());
+/// assert_eq!(&bytes[28..32], &5_f32.to_le_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferWriter {
+    bytes: Vec<u8>,
+    layout: BufferLayout,
+    max_align: usize,
+}
+
+impl BufferWriter {
+    /// creates an empty buffer
+    pub fn new(layout: BufferLayout) -> Self {
+        BufferWriter {
+            bytes: Vec::new(),
+            layout,
+            max_align: 1,
+        }
+    }
+
+    /// returns the layout of the buffer
+    pub fn layout(&self) -> BufferLayout {
+        self.layout
+    }
+
+    /// returns the number of bytes that are written so far
+    pub fn offset(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// writes the value at the next offset that is aligned for it
+    pub fn write<T: GpuData>(&mut self, value: &T) {
+        value.write_to(self);
+    }
+
+    /// writes the values as an array where every element starts at a multiple of the array stride
+    pub fn write_slice<T: GpuData>(&mut self, values: &[T]) {
+        for value in values {
+            let mut element = self.nested();
+            value.write_to(&mut element);
+            self.append_nested(element);
+        }
+    }
+
+    /// writes the fields of a struct, the struct is aligned to its largest field and padded to that alignment
+    pub fn write_struct(&mut self, fields: impl FnOnce(&mut BufferWriter)) {
+        let mut element = self.nested();
+        fields(&mut element);
+        self.append_nested(element);
+    }
+
+    /// writes a dynamic vector as a `vec2`, `vec3` or `vec4`
+    ///
+    /// note it panics if the len is not between 2 and 4
+    pub fn write_vector<T: GpuScalar>(&mut self, vector: &Vector<T>) {
+        self.try_write_vector(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `write_vector` but returns an error if the len is not between 2 and 4
+    pub fn try_write_vector<T: GpuScalar>(&mut self, vector: &Vector<T>) -> Result<(), MathError> {
+        let len = check_len(vector.len())?;
+        self.write_components(vector.vec().iter().copied(), vector_align(len));
+        Ok(())
+    }
+
+    /// writes a dynamic matrix as a column major `matCxR` where the math columns are `index(.., col)`
+    ///
+    /// this is the same as writing the `SMatrix` with `cols()` arrays of `rows()` components
+    ///
+    /// note it panics if `cols()` or `rows()` is not between 2 and 4
+    pub fn write_matrix<T: GpuScalar>(&mut self, matrix: &Matrix<T>) {
+        self.try_write_matrix(matrix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `write_matrix` but returns an error if `cols()` or `rows()` is not between 2 and 4
+    pub fn try_write_matrix<T: GpuScalar>(&mut self, matrix: &Matrix<T>) -> Result<(), MathError> {
+        let len = check_len(matrix.cols())?;
+        check_len(matrix.rows())?;
+        for col in 0..matrix.rows() {
+            let mut column = self.nested();
+            column.write_components(
+                (0..len).map(|row| matrix.index(row, col)),
+                vector_align(len),
+            );
+            self.append_nested(column);
+        }
+        Ok(())
+    }
+
+    /// returns the bytes padded to the alignment of the whole block like the end of a struct
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        let align = self.layout.aggregate_align(self.max_align);
+        self.bytes.resize(round_up(self.bytes.len(), align), 0);
+        self.bytes
+    }
+
+    // writes the components at the next offset that is a multiple of `align`
+    fn write_components<T: GpuScalar>(
+        &mut self,
+        components: impl Iterator<Item = T>,
+        align: usize,
+    ) {
+        self.pad(align);
+        for component in components {
+            self.bytes.extend_from_slice(&component.to_gpu_bytes());
+        }
+    }
+
+    fn pad(&mut self, align: usize) {
+        self.max_align = self.max_align.max(align);
+        self.bytes.resize(round_up(self.bytes.len(), align), 0);
+    }
+
+    // an array element or a struct is written to its own buffer so its alignment is known before it is appended
+    fn nested(&self) -> BufferWriter {
+        BufferWriter::new(self.layout)
+    }
+
+    fn append_nested(&mut self, nested: BufferWriter) {
+        let align = self.layout.aggregate_align(nested.max_align);
+        self.pad(align);
+        self.bytes.extend(nested.into_bytes());
+    }
+}
+
+/// reads values from a byte buffer with the padding of a `BufferLayout`
+///
+/// the values have to be read in the same order as they were written
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{BufferLayout, BufferReader, BufferWriter, Mat4, MathError};
+/// let mut writer = BufferWriter::new(BufferLayout::Std140);
+/// writer.write(&Mat4::<f32>::identity());
+/// let bytes = writer.into_bytes();
+///
+/// let mut reader = BufferReader::new(&bytes, BufferLayout::Std140);
+/// assert_eq!(reader.read::<Mat4>(), Ok(Mat4::identity()));
+/// assert_eq!(
+///     reader.read::<f32>(),
+///     Err(MathError::IndexOutOfBounds { index: 67, len: 64 })
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    layout: BufferLayout,
+    max_align: usize,
+    // a probe reads zeros without bounds to find the alignment of a struct before it is read
+    probe: bool,
+}
+
+impl<'a> BufferReader<'a> {
+    /// creates the reader at the start of the bytes
+    pub fn new(bytes: &'a [u8], layout: BufferLayout) -> Self {
+        BufferReader {
+            bytes,
+            offset: 0,
+            layout,
+            max_align: 1,
+            probe: false,
+        }
+    }
+
+    /// returns the layout of the buffer
+    pub fn layout(&self) -> BufferLayout {
+        self.layout
+    }
+
+    /// returns the number of bytes that are read so far including the padding
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// reads the value from the next offset that is aligned for it
+    pub fn read<T: GpuData>(&mut self) -> Result<T, MathError> {
+        T::read_from(self)
+    }
+
+    /// reads an array of `len` values
+    pub fn read_vec<T: GpuData>(&mut self, len: usize) -> Result<Vec<T>, MathError> {
+        (0..len).map(|_| self.read_struct(T::read_from)).collect()
+    }
+
+    /// reads the fields of a struct that was written with `BufferWriter::write_struct`
+    pub fn read_struct<T>(
+        &mut self,
+        mut fields: impl FnMut(&mut BufferReader<'a>) -> Result<T, MathError>,
+    ) -> Result<T, MathError> {
+        let mut probe = BufferReader {
+            probe: true,
+            ..BufferReader::new(&[], self.layout)
+        };
+        fields(&mut probe)?;
+        let align = self.layout.aggregate_align(probe.max_align);
+        self.pad(align);
+
+        // the start is aligned for every field so the fields can be aligned in the whole buffer
+        let mut nested = BufferReader {
+            offset: self.offset,
+            max_align: 1,
+            ..self.clone()
+        };
+        let value = fields(&mut nested)?;
+        self.offset = round_up(nested.offset, align);
+        Ok(value)
+    }
+
+    /// reads a `vec2`, `vec3` or `vec4` in to a dynamic vector
+    pub fn read_vector<T: GpuScalar>(&mut self, len: usize) -> Result<Vector<T>, MathError> {
+        check_len(len)?;
+        Ok(Vector::from_vec(
+            self.read_components(len, vector_align(len))?,
+        ))
+    }
+
+    /// reads a column major matrix in to a dynamic matrix with `cols` and `rows` like `BufferWriter::write_matrix`
+    pub fn read_matrix<T: GpuScalar>(
+        &mut self,
+        cols: usize,
+        rows: usize,
+    ) -> Result<Matrix<T>, MathError> {
+        check_len(cols)?;
+        check_len(rows)?;
+        let mut matrix = Matrix::zero(cols, rows);
+        for col in 0..rows {
+            let column =
+                self.read_struct(|reader| reader.read_components(cols, vector_align(cols)))?;
+            for (row, value) in column.into_iter().enumerate() {
+                matrix[(row, col)] = value;
+            }
+        }
+        Ok(matrix)
+    }
+
+    // reads `len` components from the next offset that is a multiple of `align`
+    fn read_components<T: GpuScalar>(
+        &mut self,
+        len: usize,
+        align: usize,
+    ) -> Result<Vec<T>, MathError> {
+        self.pad(align);
+        let end = self.offset + 4 * len;
+        if !self.probe && end > self.bytes.len() {
+            return Err(MathError::IndexOutOfBounds {
+                index: end - 1,
+                len: self.bytes.len(),
+            });
+        }
+        let components = (self.offset..end)
+            .step_by(4)
+            .map(|i| {
+                let mut bytes = [0; 4];
+                if !self.probe {
+                    bytes.copy_from_slice(&self.bytes[i..i + 4]);
+                }
+                T::from_gpu_bytes(bytes)
+            })
+            .collect();
+        self.offset = end;
+        Ok(components)
+    }
+
+    fn pad(&mut self, align: usize) {
+        self.max_align = self.max_align.max(align);
+        self.offset = round_up(self.offset, align);
+    }
+}
+
+impl<T: GpuScalar> GpuData for T {
+    fn write_to(&self, writer: &mut BufferWriter) {
+        writer.write_components(std::iter::once(*self), 4);
+    }
+
+    fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+        Ok(reader.read_components(1, 4)?[0])
+    }
+}
+
+/// a `vec2`, `vec3` or `vec4`, other lens do not compile
+impl<const N: usize, T: GpuScalar> GpuData for SVector<N, T> {
+    fn write_to(&self, writer: &mut BufferWriter) {
+        const { assert!(N >= 2 && N <= 4, "a GPU vector has 2 to 4 components") };
+        writer.write_components(self.as_slice().iter().copied(), vector_align(N));
+    }
+
+    fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+        const { assert!(N >= 2 && N <= 4, "a GPU vector has 2 to 4 components") };
+        let components = reader.read_components(N, vector_align(N))?;
+        Ok(SVector::from_fn(|i| components[i]))
+    }
+}
+
+/// a column major `matCxR` with `C` columns of `R` components, the arrays of the `SMatrix` are the math rows
+impl<const R: usize, const C: usize, T: GpuScalar> GpuData for SMatrix<R, C, T> {
+    fn write_to(&self, writer: &mut BufferWriter) {
+        let columns: [SVector<R, T>; C] =
+            std::array::from_fn(|col| SVector::from_fn(|row| self[(row, col)]));
+        writer.write_slice(&columns);
+    }
+
+    fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+        let columns: Vec<SVector<R, T>> = reader.read_vec(C)?;
+        Ok(SMatrix::from_fn(|row, col| columns[col][row]))
+    }
+}
+
+/// an array where every element starts at a multiple of the array stride
+impl<const N: usize, T: GpuData> GpuData for [T; N] {
+    fn write_to(&self, writer: &mut BufferWriter) {
+        writer.write_slice(self);
+    }
+
+    fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+        let values = reader.read_vec(N)?;
+        Ok(values
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("read_vec returns N values")))
+    }
+}
+
+fn round_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+fn vector_align(len: usize) -> usize {
+    if len == 2 {
+        8
+    } else {
+        16
+    }
+}
+
+fn check_len(len: usize) -> Result<usize, MathError> {
+    if (2..=4).contains(&len) {
+        Ok(len)
+    } else {
+        Err(MathError::ShapeMismatch {
+            expected: len.clamp(2, 4),
+            actual: len,
+        })
+    }
+}
@@ -0,0 +1,602 @@
+use crate::linear_algebra::view::check_same_len;
+use crate::linear_algebra::{MathError, Matrix, Vector};
+use crate::scalar::{Real, Scalar};
+
+/// a linear map that is only known through its product with a vector
+///
+/// the [Krylov] solvers `cg`, `gmres` and `bicgstab` never look at the entries of the operator
+/// so they work for dense and sparse matrices and for operators that are never stored at all
+///
+/// [Krylov]: https://en.wikipedia.org/wiki/Krylov_subspace
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{IterativeOptions, LinearOperator, Vector};
+/// // the second difference operator of the 1d poisson equation
+/// struct Laplace(usize);
+///
+/// impl LinearOperator<f64> for Laplace {
+///     fn cols(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn rows(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+///         let x = x.vec();
+///         let n = x.len();
+///         Vector::from_vec(
+///             (0..n)
+///                 .map(|i| {
+///                     let left = if i > 0 { x[i - 1] } else { 0. };
+///                     let right = if i + 1 < n { x[i + 1] } else { 0. };
+///                     2. * x[i] - left - right
+///                 })
+///                 .collect(),
+///         )
+///     }
+/// }
+///
+/// let b = Vector::from_vec(vec![1.; 50]);
+/// let solution = Laplace(50).cg(&b, &IterativeOptions::default());
+/// assert!(solution.converged());
+/// assert!(solution.iterations() <= 25);
+/// ```
+pub trait LinearOperator<T = f32> {
+    /// returns the len of the result of `apply`, for a matrix this is `cols()`
+    fn cols(&self) -> usize;
+
+    /// returns the len of the vectors `apply` takes, for a matrix this is `rows()`
+    fn rows(&self) -> usize;
+
+    /// returns the product of the operator with the vector
+    fn apply(&self, x: &Vector<T>) -> Vector<T>;
+
+    /// solves `A * x = b` for a symmetric positive definite operator with the
+    /// preconditioned [conjugate gradient method]
+    ///
+    /// [conjugate gradient method]: https://en.wikipedia.org/wiki/Conjugate_gradient_method
+    ///
+    /// the preconditioner has to be symmetric positive definite as well
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{IterativeOptions, LinearOperator, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 1.], vec![1., 3.]]);
+    /// let solution = matrix.cg(&Vector::new(vec![1., 2.]), &IterativeOptions::default());
+    /// assert!(solution.converged());
+    /// assert!((solution.solution().index(0) - 1. / 11.).abs() < 1e-3);
+    /// assert!((solution.solution().index(1) - 7. / 11.).abs() < 1e-3);
+    /// ```
+    /// note it panics if the operator is not square, b or the options have the wrong len
+    /// or the operator is not positive definite
+    fn cg(&self, b: &Vector<T>, options: &IterativeOptions<T>) -> IterativeSolution<T>
+    where
+        T: Real,
+    {
+        self.try_cg(b, options)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `cg` but returns an error if the operator is not square, b or the options have the wrong len
+    /// or a search direction shows that the operator is not positive definite
+    fn try_cg(
+        &self,
+        b: &Vector<T>,
+        options: &IterativeOptions<T>,
+    ) -> Result<IterativeSolution<T>, MathError>
+    where
+        T: Real,
+    {
+        cg(self, b, options)
+    }
+
+    /// solves `A * x = b` for a general square operator with the restarted [GMRES] method
+    ///
+    /// [GMRES]: https://en.wikipedia.org/wiki/Generalized_minimal_residual_method
+    ///
+    /// the preconditioner is applied from the right so the history holds the true residuals
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{IterativeOptions, LinearOperator, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 2., 0.], vec![0., 1., 3.], vec![1., 0., 1.]]);
+    /// let solution = matrix.gmres(&Vector::new(vec![3., 4., 2.]), &IterativeOptions::default());
+    /// assert!(solution.converged());
+    /// assert_eq!(solution.iterations(), 3);
+    /// ```
+    /// note it panics if the operator is not square or b or the options have the wrong len
+    fn gmres(&self, b: &Vector<T>, options: &IterativeOptions<T>) -> IterativeSolution<T>
+    where
+        T: Real,
+    {
+        self.try_gmres(b, options)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `gmres` but returns an error if the operator is not square or b or the options have the wrong len
+    fn try_gmres(
+        &self,
+        b: &Vector<T>,
+        options: &IterativeOptions<T>,
+    ) -> Result<IterativeSolution<T>, MathError>
+    where
+        T: Real,
+    {
+        gmres(self, b, options)
+    }
+
+    /// solves `A * x = b` for a general square operator with the [BiCGSTAB] method
+    ///
+    /// [BiCGSTAB]: https://en.wikipedia.org/wiki/Biconjugate_gradient_stabilized_method
+    ///
+    /// it needs less memory than `gmres` but can break down before it converges,
+    /// the solution then reports `converged() == false`
+    ///
+    /// note it panics if the operator is not square or b or the options have the wrong len
+    fn bicgstab(&self, b: &Vector<T>, options: &IterativeOptions<T>) -> IterativeSolution<T>
+    where
+        T: Real,
+    {
+        self.try_bicgstab(b, options)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `bicgstab` but returns an error if the operator is not square or b or the options have the wrong len
+    fn try_bicgstab(
+        &self,
+        b: &Vector<T>,
+        options: &IterativeOptions<T>,
+    ) -> Result<IterativeSolution<T>, MathError>
+    where
+        T: Real,
+    {
+        bicgstab(self, b, options)
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for Matrix<T> {
+    fn cols(&self) -> usize {
+        Matrix::cols(self)
+    }
+
+    fn rows(&self) -> usize {
+        Matrix::rows(self)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self.dot_vec(x)
+    }
+}
+
+#[derive(Clone, Copy)]
+/// the settings of the iterative solvers
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{IterativeOptions, Jacobi, Matrix};
+/// let matrix = Matrix::new(vec![vec![4., 1.], vec![1., 3.]]);
+/// let jacobi = Jacobi::new(&matrix);
+/// let options = IterativeOptions {
+///     tolerance: 1e-5,
+///     preconditioner: Some(&jacobi),
+///     ..IterativeOptions::default()
+/// };
+/// assert_eq!(options.max_iterations, 1000);
+/// ```
+pub struct IterativeOptions<'a, T = f32> {
+    /// the solver stops once `|b - A * x| <= tolerance * |b|`, the default is `sqrt(ε)`
+    pub tolerance: T,
+    /// the maximal number of iterations, the default is 1000
+    pub max_iterations: usize,
+    /// the number of iterations of `gmres` before it restarts, the default is 30
+    pub restart: usize,
+    /// an approximation of the inverse of the operator that speeds up the convergence, the default is none
+    pub preconditioner: Option<&'a dyn LinearOperator<T>>,
+    /// the start of the iteration, the default is the zero vector
+    pub initial_guess: Option<&'a Vector<T>>,
+}
+
+impl<'a, T: Real> Default for IterativeOptions<'a, T> {
+    fn default() -> Self {
+        IterativeOptions {
+            tolerance: T::epsilon().sqrt(),
+            max_iterations: 1000,
+            restart: 30,
+            preconditioner: None,
+            initial_guess: None,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the result of an iterative solver with the history of its convergence
+pub struct IterativeSolution<T = f32> {
+    solution: Vector<T>,
+    converged: bool,
+    history: Vec<T>,
+}
+
+impl<T: Real> IterativeSolution<T> {
+    /// returns the last iterate x
+    pub fn solution(&self) -> &Vector<T> {
+        &self.solution
+    }
+
+    /// returns the last iterate x and drops the history
+    pub fn into_solution(self) -> Vector<T> {
+        self.solution
+    }
+
+    /// returns true if the relative residual reached the tolerance
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// returns the number of iterations
+    pub fn iterations(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    /// returns the relative residual `|b - A * x| / |b|` of the initial guess and after every iteration
+    pub fn history(&self) -> &[T] {
+        &self.history
+    }
+
+    /// returns the relative residual of the solution
+    pub fn residual(&self) -> T {
+        self.history[self.history.len() - 1]
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [Jacobi preconditioner] that divides by the diagonal of the matrix
+///
+/// [Jacobi preconditioner]: https://en.wikipedia.org/wiki/Preconditioner#Jacobi_(or_diagonal)_preconditioner
+pub struct Jacobi<T = f32> {
+    inverse_diagonal: Vec<T>,
+}
+
+impl<T: Real> Jacobi<T> {
+    /// returns the preconditioner for the diagonal of the square matrix
+    ///
+    /// note it panics if the matrix is not square or has a zero on the diagonal
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not square or has a zero on the diagonal
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        if !matrix.is_square() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+        let diagonal = (0..matrix.rows()).map(|i| matrix.index(i, i)).collect();
+        Self::try_from_diagonal(&Vector::from_vec(diagonal))
+    }
+
+    /// returns the preconditioner for an operator with this diagonal
+    ///
+    /// note it panics if the diagonal has a zero
+    pub fn from_diagonal(diagonal: &Vector<T>) -> Self {
+        Self::try_from_diagonal(diagonal).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_diagonal` but returns an error if the diagonal has a zero
+    pub fn try_from_diagonal(diagonal: &Vector<T>) -> Result<Self, MathError> {
+        let diagonal = diagonal.vec();
+        if diagonal.iter().any(|&d| d == T::zero()) {
+            return Err(MathError::Singular);
+        }
+        Ok(Jacobi {
+            inverse_diagonal: diagonal.iter().map(|&d| T::one() / d).collect(),
+        })
+    }
+}
+
+impl<T: Real> LinearOperator<T> for Jacobi<T> {
+    fn cols(&self) -> usize {
+        self.inverse_diagonal.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.inverse_diagonal.len()
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        Vector::from_vec(
+            x.vec()
+                .iter()
+                .zip(self.inverse_diagonal.iter())
+                .map(|(&x, &d)| x * d)
+                .collect(),
+        )
+    }
+}
+
+// the state every solver shares, the vectors are kept as plain `Vec`s between products
+struct Krylov<'a, T, A: ?Sized> {
+    operator: &'a A,
+    options: &'a IterativeOptions<'a, T>,
+    b: Vec<T>,
+    b_norm: T,
+    history: Vec<T>,
+}
+
+impl<'a, T: Real, A: LinearOperator<T> + ?Sized> Krylov<'a, T, A> {
+    fn new(
+        operator: &'a A,
+        b: &Vector<T>,
+        options: &'a IterativeOptions<'a, T>,
+    ) -> Result<(Self, Vec<T>), MathError> {
+        let n = operator.rows();
+        if operator.cols() != n {
+            return Err(MathError::NotSquare {
+                cols: operator.cols(),
+                rows: n,
+            });
+        }
+        check_same_len(n, b.len())?;
+        if let Some(preconditioner) = options.preconditioner {
+            check_same_len(n, preconditioner.cols())?;
+            check_same_len(n, preconditioner.rows())?;
+        }
+        let x = match options.initial_guess {
+            Some(x) => {
+                check_same_len(n, x.len())?;
+                x.vec()
+            }
+            None => vec![T::zero(); n],
+        };
+
+        let b = b.vec();
+        let b_norm = norm(&b);
+        let krylov = Krylov {
+            operator,
+            options,
+            b,
+            b_norm,
+            history: Vec::new(),
+        };
+        Ok((krylov, x))
+    }
+
+    fn apply(&self, x: &[T]) -> Vec<T> {
+        self.operator.apply(&Vector::from_vec(x.to_vec())).vec()
+    }
+
+    fn precondition(&self, x: &[T]) -> Vec<T> {
+        match self.options.preconditioner {
+            Some(preconditioner) => preconditioner.apply(&Vector::from_vec(x.to_vec())).vec(),
+            None => x.to_vec(),
+        }
+    }
+
+    fn residual(&self, x: &[T]) -> Vec<T> {
+        let ax = self.apply(x);
+        self.b.iter().zip(ax).map(|(&b, ax)| b - ax).collect()
+    }
+
+    // records the norm of the residual and returns true if it reached the tolerance
+    fn record(&mut self, residual_norm: T) -> bool {
+        // every x solves a system with b = 0 exactly
+        let relative = if self.b_norm == T::zero() {
+            residual_norm
+        } else {
+            residual_norm / self.b_norm
+        };
+        self.history.push(relative);
+        relative <= self.options.tolerance
+    }
+
+    fn iterations(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    fn finish(self, x: Vec<T>, converged: bool) -> IterativeSolution<T> {
+        IterativeSolution {
+            solution: Vector::from_vec(x),
+            converged,
+            history: self.history,
+        }
+    }
+}
+
+fn cg<T: Real, A: LinearOperator<T> + ?Sized>(
+    operator: &A,
+    b: &Vector<T>,
+    options: &IterativeOptions<T>,
+) -> Result<IterativeSolution<T>, MathError> {
+    let (mut krylov, mut x) = Krylov::new(operator, b, options)?;
+    let mut r = krylov.residual(&x);
+    let mut converged = krylov.record(norm(&r));
+    let mut z = krylov.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    while !converged && krylov.iterations() < options.max_iterations {
+        let ap = krylov.apply(&p);
+        let pap = dot(&p, &ap);
+        if pap <= T::zero() {
+            return Err(MathError::NotPositiveDefinite);
+        }
+        let alpha = rz / pap;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        converged = krylov.record(norm(&r));
+
+        z = krylov.precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p, &z) in p.iter_mut().zip(z.iter()) {
+            *p = z + beta * *p;
+        }
+    }
+    Ok(krylov.finish(x, converged))
+}
+
+fn gmres<T: Real, A: LinearOperator<T> + ?Sized>(
+    operator: &A,
+    b: &Vector<T>,
+    options: &IterativeOptions<T>,
+) -> Result<IterativeSolution<T>, MathError> {
+    let (mut krylov, mut x) = Krylov::new(operator, b, options)?;
+    let restart = options.restart.max(1);
+    let mut r = krylov.residual(&x);
+    let mut beta = norm(&r);
+    let mut converged = krylov.record(beta);
+
+    while !converged && beta > T::zero() && krylov.iterations() < options.max_iterations {
+        // the arnoldi basis V and the hessenberg matrix H that is reduced to R with givens rotations
+        let mut basis = vec![r.iter().map(|&r| r / beta).collect::<Vec<T>>()];
+        let mut h: Vec<Vec<T>> = Vec::new();
+        let mut rotations: Vec<(T, T)> = Vec::new();
+        let mut g = vec![beta];
+
+        for j in 0..restart {
+            let mut w = krylov.apply(&krylov.precondition(&basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            for v in basis.iter() {
+                let h_ij = dot(&w, v);
+                axpy(-h_ij, v, &mut w);
+                column.push(h_ij);
+            }
+            let h_next = norm(&w);
+            column.push(h_next);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (a, b) = (column[i], column[i + 1]);
+                column[i] = c * a + s * b;
+                column[i + 1] = c * b - s * a;
+            }
+            let radius = column[j].hypot(column[j + 1]);
+            let (c, s) = if radius == T::zero() {
+                (T::one(), T::zero())
+            } else {
+                (column[j] / radius, column[j + 1] / radius)
+            };
+            column[j] = radius;
+            column[j + 1] = T::zero();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+            h.push(column);
+
+            converged = krylov.record(g[j + 1].abs());
+            // a vanishing h_next means the krylov space contains the solution
+            if converged || h_next == T::zero() || krylov.iterations() >= options.max_iterations {
+                break;
+            }
+            basis.push(w.iter().map(|&w| w / h_next).collect());
+        }
+
+        // back substitution with R for the coefficients y of x = x0 + M * V * y
+        let k = h.len();
+        let mut y = g[..k].to_vec();
+        for i in (0..k).rev() {
+            for j in (i + 1)..k {
+                let y_j = y[j];
+                y[i] -= h[j][i] * y_j;
+            }
+            y[i] = if h[i][i] == T::zero() {
+                T::zero()
+            } else {
+                y[i] / h[i][i]
+            };
+        }
+        let mut update = vec![T::zero(); x.len()];
+        for (v, &y) in basis.iter().zip(y.iter()) {
+            axpy(y, v, &mut update);
+        }
+        axpy(T::one(), &krylov.precondition(&update), &mut x);
+
+        // the rotations only estimate the residual so the cycle ends with the true one
+        r = krylov.residual(&x);
+        beta = norm(&r);
+        krylov.history.pop();
+        converged = krylov.record(beta);
+    }
+    Ok(krylov.finish(x, converged))
+}
+
+fn bicgstab<T: Real, A: LinearOperator<T> + ?Sized>(
+    operator: &A,
+    b: &Vector<T>,
+    options: &IterativeOptions<T>,
+) -> Result<IterativeSolution<T>, MathError> {
+    let (mut krylov, mut x) = Krylov::new(operator, b, options)?;
+    let mut r = krylov.residual(&x);
+    let mut converged = krylov.record(norm(&r));
+    let r_hat = r.clone();
+    let n = x.len();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut v = vec![T::zero(); n];
+    let mut p = vec![T::zero(); n];
+
+    while !converged && krylov.iterations() < options.max_iterations {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == T::zero() || omega == T::zero() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+
+        let p_hat = krylov.precondition(&p);
+        v = krylov.apply(&p_hat);
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == T::zero() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        axpy(alpha, &p_hat, &mut x);
+        let mut s = r.clone();
+        axpy(-alpha, &v, &mut s);
+        if krylov.record(norm(&s)) {
+            converged = true;
+            break;
+        }
+        // the half step only counts as an iteration if it already converged
+        krylov.history.pop();
+
+        let s_hat = krylov.precondition(&s);
+        let t = krylov.apply(&s_hat);
+        let tt = dot(&t, &t);
+        omega = if tt == T::zero() {
+            T::zero()
+        } else {
+            dot(&t, &s) / tt
+        };
+        axpy(omega, &s_hat, &mut x);
+        r = s;
+        axpy(-omega, &t, &mut r);
+        converged = krylov.record(norm(&r));
+    }
+    Ok(krylov.finish(x, converged))
+}
+
+fn dot<T: Real>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b.iter()).map(|(&a, &b)| a * b).sum()
+}
+
+fn norm<T: Real>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+// y += alpha * x
+fn axpy<T: Real>(alpha: T, x: &[T], y: &mut [T]) {
+    for (y, &x) in y.iter_mut().zip(x.iter()) {
+        *y += alpha * x;
+    }
+}
@@ -0,0 +1,73 @@
+use crate::linear_algebra::MathError;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the order in which the components of a `Matrix` are stored in its flat buffer
+///
+/// `index(row, col)` is stored at `row * strides.0 + col * strides.1`
+///
+/// `Matrix::new` stores the inner vecs one after the other which is `RowMajor`,
+/// `transpose()` only swaps the strides so a transposed `RowMajor` matrix is `ColMajor`
+pub enum Layout {
+    /// `index(row, col)` is at `row * rows() + col`, the strides are `(rows(), 1)`
+    RowMajor,
+    /// `index(row, col)` is at `row + col * cols()`, the strides are `(1, cols())`
+    ColMajor,
+    /// `index(row, col)` is at `row * strides.0 + col * strides.1`
+    ///
+    /// this can describe padded data like the aligned columns that BLAS calls the leading dimension
+    Strided(usize, usize),
+}
+
+impl Layout {
+    /// returns the strides of this layout for a matrix with `cols` and `rows`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Layout;
+    /// assert_eq!(Layout::RowMajor.strides(2, 3), (3, 1));
+    /// assert_eq!(Layout::ColMajor.strides(2, 3), (1, 2));
+    /// assert_eq!(Layout::Strided(4, 1).strides(2, 3), (4, 1));
+    /// ```
+    pub fn strides(&self, cols: usize, rows: usize) -> (usize, usize) {
+        match *self {
+            Layout::RowMajor => (rows, 1),
+            Layout::ColMajor => (1, cols),
+            Layout::Strided(row_stride, col_stride) => (row_stride, col_stride),
+        }
+    }
+}
+
+// returns the len of the buffer that is needed to store a matrix with the strides
+// or none if the len does not fit in to a usize
+pub(crate) fn buffer_len(cols: usize, rows: usize, strides: (usize, usize)) -> Option<usize> {
+    if cols == 0 || rows == 0 {
+        Some(0)
+    } else {
+        (cols - 1)
+            .checked_mul(strides.0)?
+            .checked_add((rows - 1).checked_mul(strides.1)?)?
+            .checked_add(1)
+    }
+}
+
+// checks that no two indices of a matrix with `cols` and `rows` share a position in the buffer
+//
+// that holds if one stride steps over the whole extent of the other one,
+// an extent that overflows can not be stepped over by any stride
+pub(crate) fn check_strides(
+    cols: usize,
+    rows: usize,
+    strides: (usize, usize),
+) -> Result<(), MathError> {
+    let (row_stride, col_stride) = strides;
+    let rows_outer = (rows <= 1 || col_stride > 0)
+        && (cols <= 1 || matches!(buffer_len(1, rows, strides), Some(len) if row_stride >= len));
+    let cols_outer = (cols <= 1 || row_stride > 0)
+        && (rows <= 1 || matches!(buffer_len(cols, 1, strides), Some(len) if col_stride >= len));
+    if rows_outer || cols_outer {
+        Ok(())
+    } else {
+        Err(MathError::OverlappingStrides { strides })
+    }
+}
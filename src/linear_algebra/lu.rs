@@ -0,0 +1,344 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::Real;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [LU decomposition] with partial pivoting of a square matrix `P * A = L * U`
+///
+/// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+///
+/// L is lower triangular with ones on the diagonal, U is upper triangular
+/// and P is the row permutation picked while pivoting
+pub struct LuDecomposition<T = f32> {
+    n: usize,
+    // L and U stored row by row in one n * n buffer, the ones on the diagonal of L are implicit
+    lu: Vec<T>,
+    permutation: Vec<usize>,
+    sign: T,
+    is_singular: bool,
+}
+
+impl<T: Real> LuDecomposition<T> {
+    /// decomposes the square matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// let lu = LuDecomposition::new(&matrix);
+    /// assert_eq!(lu.l(), Matrix::new(vec![vec![1., 0.], vec![0.5, 1.]]));
+    /// assert_eq!(lu.u(), Matrix::new(vec![vec![4., 5.], vec![0., -1.5]]));
+    /// assert_eq!(lu.permutation(), vec![1, 0]);
+    /// ```
+    /// note the matrix has to be a [square matrix]
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not a square matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, MathError, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1., 3.], vec![4., 5., 6.]]);
+    /// assert_eq!(
+    ///     LuDecomposition::try_new(&matrix),
+    ///     Err(MathError::NotSquare { cols: 2, rows: 3 })
+    /// );
+    /// ```
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        let tolerance = T::from_usize(matrix.rows()) * T::epsilon();
+        Self::try_new_with_tolerance(matrix, tolerance)
+    }
+
+    /// same as `new` but a pivot counts as zero for `is_singular` if it is at most
+    /// `tolerance` times the largest absolute entry of the matrix, `new` uses `n * ε`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![1., 1.], vec![1., 1.001]]);
+    /// assert_eq!(LuDecomposition::new(&matrix).is_singular(), false);
+    /// assert_eq!(LuDecomposition::new_with_tolerance(&matrix, 0.01).is_singular(), true);
+    /// ```
+    /// note the matrix has to be a [square matrix]
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    pub fn new_with_tolerance(matrix: &Matrix<T>, tolerance: T) -> Self {
+        Self::try_new_with_tolerance(matrix, tolerance).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new_with_tolerance` but returns an error if the matrix is not a square matrix
+    pub fn try_new_with_tolerance(matrix: &Matrix<T>, tolerance: T) -> Result<Self, MathError> {
+        if !matrix.is_square() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+
+        let n = matrix.rows();
+        let mut lu = matrix.matrix_flatt();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = T::one();
+
+        let max = lu.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = tolerance * max;
+        let mut is_singular = n == 0 || max == T::zero();
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&i, &j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))
+                .unwrap();
+
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[k * n + k];
+            if pivot.abs() <= tolerance {
+                is_singular = true;
+            }
+            if pivot == T::zero() {
+                continue;
+            }
+
+            for i in (k + 1)..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in (k + 1)..n {
+                    let u_kj = lu[k * n + j];
+                    lu[i * n + j] -= factor * u_kj;
+                }
+            }
+        }
+
+        Ok(Self {
+            n,
+            lu,
+            permutation,
+            sign,
+            is_singular,
+        })
+    }
+
+    /// returns the lower triangular matrix L
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.n;
+        let mut flatt = vec![T::zero(); n * n];
+        for i in 0..n {
+            flatt[i * n..i * n + i].copy_from_slice(&self.lu[i * n..i * n + i]);
+            flatt[i * n + i] = T::one();
+        }
+        Matrix::from_flatt(flatt, n, n)
+    }
+
+    /// returns the upper triangular matrix U
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.n;
+        let mut flatt = vec![T::zero(); n * n];
+        for i in 0..n {
+            flatt[i * n + i..(i + 1) * n].copy_from_slice(&self.lu[i * n + i..(i + 1) * n]);
+        }
+        Matrix::from_flatt(flatt, n, n)
+    }
+
+    /// returns the row permutation, row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> Vec<usize> {
+        self.permutation.clone()
+    }
+
+    /// returns the permutation as the matrix P
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.n;
+        let mut flatt = vec![T::zero(); n * n];
+        for (i, &j) in self.permutation.iter().enumerate() {
+            flatt[i * n + j] = T::one();
+        }
+        Matrix::from_flatt(flatt, n, n)
+    }
+
+    /// returns true if a pivot is zero up to rounding errors
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(LuDecomposition::new(&matrix).is_singular(), true);
+    /// ```
+    pub fn is_singular(&self) -> bool {
+        self.is_singular
+    }
+
+    /// returns the [determinant] of the decomposed matrix
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// assert_eq!(LuDecomposition::new(&matrix).det(), 6.);
+    /// ```
+    pub fn det(&self) -> T {
+        (0..self.n)
+            .map(|i| self.lu[i * self.n + i])
+            .fold(self.sign, |det, x| det * x)
+    }
+
+    /// solves the system `A * x = b` and returns x
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// let lu = LuDecomposition::new(&matrix);
+    /// assert_eq!(lu.solve(&Vector::new(vec![3., 9.])), Vector::new(vec![1., 1.]));
+    /// ```
+    /// note it panics if the matrix is singular or b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if the matrix is singular or b has the wrong len
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(
+    ///     matrix.lu().try_solve(&Vector::new(vec![1., 1.])),
+    ///     Err(MathError::Singular)
+    /// );
+    /// ```
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        if b.len() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.len(),
+            });
+        }
+        self.check_singular()?;
+
+        let b = b.vec();
+        let mut x: Vec<T> = self.permutation.iter().map(|&i| b[i]).collect();
+        self.substitute(&mut x, 1);
+        Ok(Vector::from_vec(x))
+    }
+
+    /// solves the system `A * X = B` for every column of B and returns X
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// let lu = LuDecomposition::new(&matrix);
+    /// assert_eq!(
+    ///     lu.solve_mat(&Matrix::new(vec![vec![3., 2.], vec![9., 4.]])),
+    ///     Matrix::new(vec![vec![1., 1.], vec![1., 0.]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is singular or B has the wrong shape
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.try_solve_mat(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat` but returns an error if the matrix is singular or B has the wrong shape
+    pub fn try_solve_mat(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if b.cols() != self.n {
+            return Err(MathError::ShapeMismatch {
+                expected: self.n,
+                actual: b.cols(),
+            });
+        }
+        self.check_singular()?;
+
+        let k = b.rows();
+        let b = b.matrix_flatt();
+        let mut x = Vec::with_capacity(self.n * k);
+        for &i in self.permutation.iter() {
+            x.extend_from_slice(&b[i * k..(i + 1) * k]);
+        }
+        self.substitute(&mut x, k);
+        Ok(Matrix::from_flatt(x, self.n, k))
+    }
+
+    /// returns the [inverse] of the decomposed matrix
+    ///
+    /// [inverse]: https://en.wikipedia.org/wiki/Invertible_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{LuDecomposition, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 4.]]);
+    /// assert_eq!(
+    ///     LuDecomposition::new(&matrix).inverse(),
+    ///     Matrix::new(vec![vec![1., -0.25], vec![-1., 0.5]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is singular
+    pub fn inverse(&self) -> Matrix<T> {
+        self.try_inverse()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `inverse` but returns an error if the matrix is singular
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MathError> {
+        let n = self.n;
+        let mut identity = vec![T::zero(); n * n];
+        for i in 0..n {
+            identity[i * n + i] = T::one();
+        }
+        self.try_solve_mat(&Matrix::from_flatt(identity, n, n))
+    }
+
+    fn check_singular(&self) -> Result<(), MathError> {
+        if self.is_singular {
+            return Err(MathError::Singular);
+        }
+        Ok(())
+    }
+
+    // forward substitution with L and back substitution with U on the k columns of x in place
+    fn substitute(&self, x: &mut [T], k: usize) {
+        let n = self.n;
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.lu[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= l_ij * x[j * k + c];
+                }
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let u_ij = self.lu[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= u_ij * x[j * k + c];
+                }
+            }
+            let u_ii = self.lu[i * n + i];
+            for c in 0..k {
+                x[i * k + c] /= u_ii;
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::linear_algebra::{FormatError, Matrix};
+use crate::scalar::Scalar;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the way the entries are listed in a [Matrix Market] file
+///
+/// [Matrix Market]: https://math.nist.gov/MatrixMarket/formats.html
+pub enum MarketFormat {
+    /// every entry is listed column by column
+    Array,
+    /// only the nonzero entries are listed as `row col value` with indices starting at 1
+    Coordinate,
+}
+
+/// a scalar that can be read from and written to a Matrix Market file
+///
+/// the `field` of the header is `real` for floats and `integer` for integers,
+/// floats can also read `integer` files and both can read `pattern` files whose entries are one
+pub trait MarketScalar: Scalar + FromStr + Display {
+    /// the field that is written in the header
+    const FIELD: &'static str;
+
+    /// returns the negated value for the upper triangle of a `skew-symmetric` file
+    /// or None if the type can not represent it
+    fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_market_scalar {
+    (integer, $($t:ty)*) => ($(
+        impl MarketScalar for $t {
+            const FIELD: &'static str = "integer";
+
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+        }
+    )*);
+    (real, $($t:ty)*) => ($(
+        impl MarketScalar for $t {
+            const FIELD: &'static str = "real";
+
+            fn checked_neg(self) -> Option<Self> {
+                Some(-self)
+            }
+        }
+    )*)
+}
+
+impl_market_scalar!(integer, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_market_scalar!(real, f32 f64);
+
+#[derive(PartialEq, Clone, Copy)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+impl<T: MarketScalar> Matrix<T> {
+    /// reads a [Matrix Market] file in the `array` or `coordinate` format
+    ///
+    /// [Matrix Market]: https://math.nist.gov/MatrixMarket/formats.html
+    ///
+    /// the field can be `real`, `integer` or `pattern` and the symmetry `general`, `symmetric` or `skew-symmetric`,
+    /// the rows of the file are the `cols()` of the matrix so entry `i j` is `index(i - 1, j - 1)`
+    ///
+    /// an entry that is listed twice is an error and a `skew-symmetric` file needs a signed type
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let file = "%%MatrixMarket matrix coordinate real symmetric
+    /// % a comment
+    /// 2 2 2
+    /// 1 1 4.5
+    /// 2 1 -1
+    /// ";
+    /// let matrix = Matrix::<f64>::from_matrix_market(file.as_bytes()).unwrap();
+    /// assert_eq!(matrix, Matrix::from_vec(vec![vec![4.5, -1.], vec![-1., 0.]]));
+    ///
+    /// let error = Matrix::<f64>::from_matrix_market("%%MatrixMarket matrix array real general\n2 1\n1\nx\n".as_bytes());
+    /// assert_eq!(error.unwrap_err().to_string(), "line 4: invalid value `x`");
+    /// ```
+    pub fn from_matrix_market(reader: impl Read) -> Result<Self, FormatError> {
+        let mut lines = Lines {
+            lines: BufReader::new(reader).lines(),
+            line: 0,
+        };
+
+        let header = match lines.next_line()? {
+            Some(header) => header,
+            None => {
+                lines.line = 1;
+                return Err(lines.error("the file is empty"));
+            }
+        };
+        let words: Vec<String> = header
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect();
+        if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+            return Err(lines.error(
+                "the header has to be `%%MatrixMarket matrix <format> <field> <symmetry>`",
+            ));
+        }
+        let format = match words[2].as_str() {
+            "array" => MarketFormat::Array,
+            "coordinate" => MarketFormat::Coordinate,
+            other => return Err(lines.error(&format!("unsupported format `{}`", other))),
+        };
+        let pattern = match words[3].as_str() {
+            "pattern" if format == MarketFormat::Coordinate => true,
+            "real" | "integer" => false,
+            other => return Err(lines.error(&format!("unsupported field `{}`", other))),
+        };
+        let symmetry = match words[4].as_str() {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            "skew-symmetric" => Symmetry::SkewSymmetric,
+            other => return Err(lines.error(&format!("unsupported symmetry `{}`", other))),
+        };
+
+        let size = lines.next_data()?;
+        let size_len = if format == MarketFormat::Array { 2 } else { 3 };
+        let size: Vec<usize> = lines.parse_all(&size, size_len)?;
+        let (rows, cols) = (size[0], size[1]);
+        if symmetry != Symmetry::General && rows != cols {
+            return Err(lines.error("a symmetric matrix has to be square"));
+        }
+
+        // the size line is checked before the dense matrix is allocated
+        let too_large = || lines.error(&format!("the size {} x {} is too large", rows, cols));
+        let len = rows
+            .checked_mul(cols)
+            .filter(|len| {
+                len.checked_mul(std::mem::size_of::<T>())
+                    .filter(|&bytes| bytes <= isize::MAX as usize)
+                    .is_some()
+            })
+            .ok_or_else(too_large)?;
+        let mut values = Vec::new();
+        values.try_reserve_exact(len).map_err(|_| too_large())?;
+        values.resize(len, T::zero());
+        let mut matrix = Matrix::from_flatt(values, rows, cols);
+        // returns None if the mirrored value of a skew-symmetric entry does not fit into the type
+        let mut set = |row: usize, col: usize, value: T| {
+            matrix[(row, col)] = value;
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => matrix[(col, row)] = value,
+                Symmetry::SkewSymmetric => matrix[(col, row)] = value.checked_neg()?,
+            }
+            Some(())
+        };
+        let negation_error = "skew-symmetric needs a signed type that can negate the value";
+
+        match format {
+            MarketFormat::Array => {
+                for col in 0..cols {
+                    let start = match symmetry {
+                        Symmetry::General => 0,
+                        Symmetry::Symmetric => col,
+                        Symmetry::SkewSymmetric => col + 1,
+                    };
+                    for row in start..rows {
+                        let line = lines.next_data()?;
+                        let value = lines.parse_all(&line, 1)?[0];
+                        set(row, col, value).ok_or_else(|| lines.error(negation_error))?;
+                    }
+                }
+            }
+            MarketFormat::Coordinate => {
+                let mut seen = HashSet::new();
+                for _ in 0..size[2] {
+                    let line = lines.next_data()?;
+                    let words: Vec<&str> = line.split_whitespace().collect();
+                    let expected = if pattern { 2 } else { 3 };
+                    if words.len() != expected {
+                        return Err(lines.error(&format!(
+                            "expected {} numbers, got {}",
+                            expected,
+                            words.len()
+                        )));
+                    }
+                    let row = lines.parse_index(words[0], rows)?;
+                    let col = lines.parse_index(words[1], cols)?;
+                    if symmetry != Symmetry::General && row < col {
+                        return Err(
+                            lines.error("a symmetric matrix only stores the lower triangle")
+                        );
+                    }
+                    if symmetry == Symmetry::SkewSymmetric && row == col {
+                        return Err(lines.error("a skew-symmetric matrix has no diagonal entries"));
+                    }
+                    if !seen.insert((row, col)) {
+                        return Err(lines.error(&format!(
+                            "duplicate entry `{} {}`",
+                            row + 1,
+                            col + 1
+                        )));
+                    }
+                    let value = if pattern {
+                        T::one()
+                    } else {
+                        lines.parse(words[2])?
+                    };
+                    set(row, col, value).ok_or_else(|| lines.error(negation_error))?;
+                }
+            }
+        }
+
+        if lines.next_line()?.is_some() {
+            return Err(lines.error("there are more entries than the size line declares"));
+        }
+        Ok(matrix)
+    }
+
+    /// writes the matrix as a `general` [Matrix Market] file
+    ///
+    /// [Matrix Market]: https://math.nist.gov/MatrixMarket/formats.html
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MarketFormat, Matrix};
+    /// let matrix = Matrix::from_vec(vec![vec![1, 0, 0], vec![0, 0, -2]]);
+    /// let mut file = Vec::new();
+    /// matrix.to_matrix_market(&mut file, MarketFormat::Coordinate).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(file).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate integer general\n2 3 2\n1 1 1\n2 3 -2\n"
+    /// );
+    /// ```
+    /// note the coordinate format only lists the entries that are not zero
+    pub fn to_matrix_market(
+        &self,
+        writer: impl Write,
+        format: MarketFormat,
+    ) -> std::io::Result<()> {
+        let mut writer = std::io::BufWriter::new(writer);
+        let (rows, cols) = (self.cols(), self.rows());
+        match format {
+            MarketFormat::Array => {
+                writeln!(writer, "%%MatrixMarket matrix array {} general", T::FIELD)?;
+                writeln!(writer, "{} {}", rows, cols)?;
+                for col in 0..cols {
+                    for row in 0..rows {
+                        writeln!(writer, "{}", self.index(row, col))?;
+                    }
+                }
+            }
+            MarketFormat::Coordinate => {
+                let entries: Vec<(usize, usize, T)> = (0..cols)
+                    .flat_map(|col| (0..rows).map(move |row| (row, col)))
+                    .map(|(row, col)| (row, col, self.index(row, col)))
+                    .filter(|&(_, _, value)| value != T::zero())
+                    .collect();
+                writeln!(
+                    writer,
+                    "%%MatrixMarket matrix coordinate {} general",
+                    T::FIELD
+                )?;
+                writeln!(writer, "{} {} {}", rows, cols, entries.len())?;
+                for (row, col, value) in entries {
+                    writeln!(writer, "{} {} {}", row + 1, col + 1, value)?;
+                }
+            }
+        }
+        writer.flush()
+    }
+}
+
+// the lines of the file with the number of the last line that was read
+struct Lines<R> {
+    lines: std::io::Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_line(&mut self) -> Result<Option<String>, FormatError> {
+        loop {
+            match self.lines.next() {
+                None => return Ok(None),
+                Some(line) => {
+                    let line = line?;
+                    self.line += 1;
+                    // comments and blank lines are skipped except the header in the first line
+                    if self.line == 1 || !(line.trim().is_empty() || line.starts_with('%')) {
+                        return Ok(Some(line));
+                    }
+                }
+            }
+        }
+    }
+
+    fn next_data(&mut self) -> Result<String, FormatError> {
+        match self.next_line()? {
+            Some(line) => Ok(line),
+            None => {
+                self.line += 1;
+                Err(self.error("the file ended before all entries were read"))
+            }
+        }
+    }
+
+    fn parse<T: FromStr>(&self, word: &str) -> Result<T, FormatError> {
+        word.parse()
+            .map_err(|_| self.error(&format!("invalid value `{}`", word)))
+    }
+
+    fn parse_all<T: FromStr>(&self, line: &str, len: usize) -> Result<Vec<T>, FormatError> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.len() != len {
+            return Err(self.error(&format!("expected {} numbers, got {}", len, words.len())));
+        }
+        words.iter().map(|word| self.parse(word)).collect()
+    }
+
+    fn parse_index(&self, word: &str, len: usize) -> Result<usize, FormatError> {
+        let index: usize = self.parse(word)?;
+        if index == 0 || index > len {
+            return Err(self.error(&format!(
+                "index {} is out of bounds for the dimension {}",
+                index, len
+            )));
+        }
+        Ok(index - 1)
+    }
+
+    fn error(&self, message: &str) -> FormatError {
+        FormatError::Parse {
+            line: self.line,
+            message: message.to_string(),
+        }
+    }
+}
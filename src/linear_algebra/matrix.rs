@@ -1,76 +1,267 @@
+use crate::complex::Complex;
+use crate::linear_algebra::layout::{buffer_len, check_strides};
+use crate::linear_algebra::view::check_vector;
+use crate::linear_algebra::CholeskyDecomposition;
+use crate::linear_algebra::Eigen;
+use crate::linear_algebra::HermitianEigen;
+use crate::linear_algebra::Layout;
+use crate::linear_algebra::LdltDecomposition;
+use crate::linear_algebra::LuDecomposition;
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::QrDecomposition;
+use crate::linear_algebra::Svd;
+use crate::linear_algebra::SymmetricEigen;
 use crate::linear_algebra::Vector;
+use crate::linear_algebra::{MatrixView, MatrixViewMut, SliceRange, VectorView, VectorViewMut};
 use crate::random;
+use crate::scalar::{Field, Real, Scalar};
 use std::mem;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-
-#[derive(PartialEq, Clone, Debug)]
-pub struct Matrix {
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Clone, Debug)]
+/// a matrix of the scalar type `T` which is `f32` by default
+///
+/// the decompositions and all functions that build on them are only implemented for `f32` and `f64`
+///
+/// the components are stored in a flat buffer in the order given by its `Layout`
+///
+/// `+`, `-` and `/` work component wise on owned and borrowed matrices, `*` is component wise
+/// only on owned matrices while `&a * &b` is the matrix product
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let b = Matrix::new(vec![vec![2., 1.], vec![1., 2.]]);
+/// assert_eq!(&a * &b, a.dot_mat(&b));
+/// assert_eq!(a.clone() * b.clone(), Matrix::new(vec![vec![2., 2.], vec![3., 8.]]));
+/// assert_eq!(&a / &b, Matrix::new(vec![vec![0.5, 2.], vec![3., 2.]]));
+/// ```
+pub struct Matrix<T = f32> {
     cols: usize,
     rows: usize,
-    matrix_flatt: Vec<f32>,
-    is_transpose: bool,
+    matrix_flatt: Vec<T>,
+    // `index(row, col)` is stored at `row * strides.0 + col * strides.1`
+    strides: (usize, usize),
 }
 
-impl Add for Matrix {
-    type Output = Self;
-    fn add(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.add_mat(&other);
-        result
+/// two matrices are equal if they have the same cols, rows and components no matter their layout
+impl<T: Scalar> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.view() == other.view()
     }
 }
 
-impl AddAssign for Matrix {
-    fn add_assign(&mut self, other: Self) {
-        self.add_mat(&other);
-    }
+// implements `op` and `op_assign` component wise for owned matrices and for scalars
+macro_rules! impl_matrix_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $mat:ident, $scalar:ident) => {
+        impl<T: Scalar> $op for Matrix<T> {
+            type Output = Self;
+
+            fn $method(mut self, other: Self) -> Self {
+                self.$mat(&other);
+                self
+            }
+        }
+
+        impl<T: Scalar> $op<T> for Matrix<T> {
+            type Output = Self;
+
+            fn $method(mut self, scalar: T) -> Self {
+                self.$scalar(&scalar);
+                self
+            }
+        }
+
+        impl<'a, T: Scalar> $op<T> for &'a Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, scalar: T) -> Matrix<T> {
+                self.clone().$method(scalar)
+            }
+        }
+
+        impl<T: Scalar> $op_assign for Matrix<T> {
+            fn $method_assign(&mut self, other: Self) {
+                self.$mat(&other);
+            }
+        }
+
+        impl<'a, T: Scalar> $op_assign<&'a Matrix<T>> for Matrix<T> {
+            fn $method_assign(&mut self, other: &'a Matrix<T>) {
+                self.$mat(other);
+            }
+        }
+
+        impl<T: Scalar> $op_assign<T> for Matrix<T> {
+            fn $method_assign(&mut self, scalar: T) {
+                self.$scalar(&scalar);
+            }
+        }
+    };
+}
+
+impl_matrix_op!(Add, add, AddAssign, add_assign, add_mat, add_scalar);
+impl_matrix_op!(Sub, sub, SubAssign, sub_assign, sub_mat, sub_scalar);
+impl_matrix_op!(Mul, mul, MulAssign, mul_assign, mul_mat, mul_scalar);
+impl_matrix_op!(Div, div, DivAssign, div_assign, div_mat, div_scalar);
+
+// implements `op` for borrowed matrices, this is done for `+`, `-` and `/` but not for `*`
+// because `&a * &b` is the matrix product
+macro_rules! impl_matrix_ref_op {
+    ($op:ident, $method:ident, $mat:ident) => {
+        impl<'a, T: Scalar> $op<&'a Matrix<T>> for Matrix<T> {
+            type Output = Self;
+
+            fn $method(mut self, other: &'a Matrix<T>) -> Self {
+                self.$mat(other);
+                self
+            }
+        }
+
+        impl<'a, T: Scalar> $op<Matrix<T>> for &'a Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, other: Matrix<T>) -> Matrix<T> {
+                self.clone().$method(&other)
+            }
+        }
+
+        impl<'a, 'b, T: Scalar> $op<&'b Matrix<T>> for &'a Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, other: &'b Matrix<T>) -> Matrix<T> {
+                self.clone().$method(other)
+            }
+        }
+    };
+}
+
+impl_matrix_ref_op!(Add, add, add_mat);
+impl_matrix_ref_op!(Sub, sub, sub_mat);
+impl_matrix_ref_op!(Div, div, div_mat);
+
+// implements `scalar op matrix` for the primitive scalars, it applies the scalar to each component
+macro_rules! impl_scalar_matrix_op {
+    ($t:ty, $($op:ident, $method:ident, $mat:ident);*) => {$(
+        impl $op<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn $method(self, matrix: Matrix<$t>) -> Matrix<$t> {
+                self.$method(&matrix)
+            }
+        }
+
+        impl<'a> $op<&'a Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn $method(self, matrix: &'a Matrix<$t>) -> Matrix<$t> {
+                let mut result = Matrix::zero(matrix.cols(), matrix.rows());
+                result.add_scalar(&self);
+                result.$mat(matrix);
+                result
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_scalar_matrix_ops {
+    ($($t:ty)*) => ($(
+        impl_scalar_matrix_op!($t, Add, add, add_mat; Sub, sub, sub_mat; Mul, mul, mul_mat; Div, div, div_mat);
+    )*)
 }
 
-impl Sub for Matrix {
+impl_scalar_matrix_ops!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// negates each component
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let a = Matrix::new(vec![vec![1., -2.], vec![0., 3.]]);
+/// let b = Matrix::new(vec![vec![1., 1.], vec![1., 1.]]);
+/// assert_eq!(-&a, Matrix::new(vec![vec![-1., 2.], vec![0., -3.]]));
+/// assert_eq!(&a * 2. - &b, Matrix::new(vec![vec![1., -5.], vec![-1., 5.]]));
+/// assert_eq!(2. * a, Matrix::new(vec![vec![2., -4.], vec![0., 6.]]));
+/// ```
+impl<T: Field> Neg for Matrix<T> {
     type Output = Self;
 
-    fn sub(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.sub_mat(&other);
-        result
+    fn neg(mut self) -> Self {
+        self.matrix_flatt.iter_mut().for_each(|x| *x = -*x);
+        self
     }
 }
 
-impl SubAssign for Matrix {
-    fn sub_assign(&mut self, other: Self) {
-        self.sub_mat(&other);
+impl<T: Field> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
     }
 }
 
-impl Mul for Matrix {
-    type Output = Self;
+/// the matrix product `&a * &b` same as `a.dot_mat(&b)`
+///
+/// note `a * b` on owned matrices multiplies component wise
+impl<T: Scalar> Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
 
-    fn mul(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.mul_mat(&other);
-        result
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.dot_mat(other)
     }
 }
 
-impl MulAssign for Matrix {
-    fn mul_assign(&mut self, other: Self) {
-        self.mul_mat(&other);
+/// the matrix vector product `&a * &v` same as `a.dot_vec(&v)`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Matrix, Vector};
+/// let matrix = Matrix::new(vec![vec![1., -1., 2.], vec![0., -3., 1.]]);
+/// let vector = Vector::new(vec![2., 1., 0.]);
+/// assert_eq!(&matrix * &vector, Vector::new(vec![1., -3.]));
+/// ```
+impl<T: Scalar> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, vector: &Vector<T>) -> Vector<T> {
+        self.dot_vec(vector)
     }
 }
 
-impl Div for Matrix {
-    type Output = Self;
-
-    fn div(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.div_mat(&other);
-        result
+/// `matrix[(row, col)]` is the same as `matrix.index(row, col)`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+/// matrix[(1, 2)] += matrix[(0, 1)];
+/// assert_eq!(matrix[(1, 2)], 8.);
+/// ```
+/// note it panics if the index is out of bounds
+impl<T: Scalar> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        let i = self
+            .flatt_index(row, col)
+            .unwrap_or_else(|error| panic!("{}", error));
+        &self.matrix_flatt[i]
     }
 }
 
-impl DivAssign for Matrix {
-    fn div_assign(&mut self, other: Self) {
-        self.div_mat(&other);
+impl<T: Scalar> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let i = self
+            .flatt_index(row, col)
+            .unwrap_or_else(|error| panic!("{}", error));
+        &mut self.matrix_flatt[i]
     }
 }
 
@@ -88,25 +279,23 @@ impl Matrix {
     /// [3.0, 2.0, 4.0]
     /// [4.0, 5.0, 6.0]
     ///
+    /// use `Matrix::from_vec` for other scalar types than `f32`
     pub fn new(vec: Vec<Vec<f32>>) -> Self {
-        let cols = vec.len();
-        let rows = vec[0].len();
-
-        let mut flatt: Vec<f32> = Vec::with_capacity(cols * rows);
-
-        vec.iter().for_each(|col| {
-            if col.len() != rows {
-                panic!("wrong row shape expected {}, got {}", rows, col.len())
-            }
-            col.iter().for_each(|&x| flatt.push(x))
-        });
+        Self::try_new(vec).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        Self {
-            cols: cols,
-            rows: rows,
-            matrix_flatt: flatt,
-            is_transpose: false,
-        }
+    /// same as `new` but returns an error if the vec is empty or the inner vecs have not the same len
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix};
+    /// let matrix = Matrix::try_new(vec![vec![3., 2., 4.], vec![4., 5.]]);
+    /// assert_eq!(matrix, Err(MathError::ShapeMismatch { expected: 3, actual: 2 }));
+    /// assert_eq!(Matrix::try_new(vec![]), Err(MathError::EmptyInput));
+    /// ```
+    pub fn try_new(vec: Vec<Vec<f32>>) -> Result<Self, MathError> {
+        Self::try_from_vec(vec)
     }
 
     /// generats a matrix from a 1D Vector
@@ -118,21 +307,28 @@ impl Matrix {
     /// let matrix = Matrix::new_flatt(vec![3., 2., 4., 4., 5., 6.], 2, 3);
     /// assert_eq!(matrix.matrix_flatt(), vec![3., 2., 4., 4., 5., 6.]);
     /// ```
+    /// use `Matrix::from_flatt` for other scalar types than `f32`
     pub fn new_flatt(matrix_flatt: Vec<f32>, cols: usize, rows: usize) -> Self {
-        if cols * rows != matrix_flatt.len() {
-            panic!(
-                "cols * rows = {} has to be the same len as the matrix_flatt = {}",
-                cols * rows,
-                matrix_flatt.len()
-            );
-        }
+        Self::try_new_flatt(matrix_flatt, cols, rows).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        Self {
-            cols,
-            rows,
-            matrix_flatt,
-            is_transpose: false,
-        }
+    /// same as `new_flatt` but returns an error if `cols * rows` is not the len of the matrix_flatt
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix};
+    /// assert_eq!(
+    ///     Matrix::try_new_flatt(vec![3., 2., 4., 4., 5.], 2, 3),
+    ///     Err(MathError::ShapeMismatch { expected: 6, actual: 5 })
+    /// );
+    /// ```
+    pub fn try_new_flatt(
+        matrix_flatt: Vec<f32>,
+        cols: usize,
+        rows: usize,
+    ) -> Result<Self, MathError> {
+        Self::try_from_flatt(matrix_flatt, cols, rows)
     }
 
     /// generates a matrix of size `cols` and `rows` with random values between 0 and 1
@@ -166,7 +362,7 @@ impl Matrix {
             cols,
             rows,
             matrix_flatt,
-            is_transpose: false,
+            strides: (rows, 1),
         }
     }
 
@@ -180,18 +376,7 @@ impl Matrix {
     /// assert_eq!(matrix.matrix_flatt(), vec![0., 0., 0., 0., 0., 0.]);
     /// ```
     pub fn new_zero(cols: usize, rows: usize) -> Self {
-        let mut matrix_flatt = Vec::with_capacity(cols * rows);
-        for _ in 0..cols {
-            for _ in 0..rows {
-                matrix_flatt.push(0.);
-            }
-        }
-        Self {
-            cols,
-            rows,
-            matrix_flatt,
-            is_transpose: false,
-        }
+        Self::zero(cols, rows)
     }
 
     /// this return a vector of bytes representing the matrix
@@ -208,9 +393,11 @@ impl Matrix {
     ///     vec![0, 0, 0, 64, 0, 0, 0, 64, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 224, 64, 0, 0, 128, 64]
     /// );
     /// ```
-    /// note the fist and seconde `f32` is the rows and cols of the matrix
+    /// note the fist and seconde `f32` is the rows and cols of the matrix,
+    /// use `BufferWriter` for the column major std140 and std430 layouts of shaders
+    /// and `to_writer` for a portable format that can be read back
     pub fn bytes(&self) -> Vec<u8> {
-        let size = (2 + self.matrix_flatt.len()) * mem::size_of::<f32>();
+        let size = (2 + self.cols * self.rows) * mem::size_of::<f32>();
         let mut bytes = Vec::<u8>::with_capacity(size);
 
         let push_f32_bytes = |num: f32, bytes: &mut Vec<u8>| {
@@ -227,6 +414,148 @@ impl Matrix {
             .for_each(|&val| push_f32_bytes(val, &mut bytes));
         bytes
     }
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// converts 2d vec of any scalar type in to matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_vec(vec![vec![1_i64, 2], vec![3, 4]]);
+    /// assert_eq!(matrix.index(1, 0), 3);
+    /// ```
+    pub fn from_vec(vec: Vec<Vec<T>>) -> Self {
+        Self::try_from_vec(vec).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_vec` but returns an error if the vec is empty or the inner vecs have not the same len
+    pub fn try_from_vec(vec: Vec<Vec<T>>) -> Result<Self, MathError> {
+        let cols = vec.len();
+        let rows = match vec.first() {
+            Some(first) => first.len(),
+            None => return Err(MathError::EmptyInput),
+        };
+
+        let mut flatt: Vec<T> = Vec::with_capacity(cols * rows);
+
+        for col in vec.iter() {
+            if col.len() != rows {
+                return Err(MathError::ShapeMismatch {
+                    expected: rows,
+                    actual: col.len(),
+                });
+            }
+            col.iter().for_each(|&x| flatt.push(x))
+        }
+
+        Ok(Self {
+            cols: cols,
+            rows: rows,
+            matrix_flatt: flatt,
+            strides: (rows, 1),
+        })
+    }
+
+    /// generats a matrix of any scalar type from a 1D Vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_flatt(vec![3_f64, 2., 4., 4., 5., 6.], 2, 3);
+    /// assert_eq!(matrix.matrix_flatt(), vec![3., 2., 4., 4., 5., 6.]);
+    /// ```
+    pub fn from_flatt(matrix_flatt: Vec<T>, cols: usize, rows: usize) -> Self {
+        Self::try_from_flatt(matrix_flatt, cols, rows).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_flatt` but returns an error if `cols * rows` is not the len of the matrix_flatt
+    pub fn try_from_flatt(
+        matrix_flatt: Vec<T>,
+        cols: usize,
+        rows: usize,
+    ) -> Result<Self, MathError> {
+        if cols * rows != matrix_flatt.len() {
+            return Err(MathError::ShapeMismatch {
+                expected: cols * rows,
+                actual: matrix_flatt.len(),
+            });
+        }
+
+        Ok(Self {
+            cols,
+            rows,
+            matrix_flatt,
+            strides: (rows, 1),
+        })
+    }
+
+    /// generats a matrix from a 1D Vector that is stored in the given layout
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Layout, Matrix};
+    /// let matrix = Matrix::from_layout(vec![3., 4., 2., 5., 4., 6.], 2, 3, Layout::ColMajor);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]));
+    ///
+    /// // each of the 2 vecs of len 3 is padded to 4 components
+    /// let padded = vec![3., 2., 4., 0., 4., 5., 6., 0.];
+    /// let matrix = Matrix::from_layout(padded, 2, 3, Layout::Strided(4, 1));
+    /// assert_eq!(matrix.matrix_flatt(), vec![3., 2., 4., 4., 5., 6.]);
+    /// ```
+    /// note it panics if the strides overlap or the matrix_flatt is to short
+    pub fn from_layout(matrix_flatt: Vec<T>, cols: usize, rows: usize, layout: Layout) -> Self {
+        Self::try_from_layout(matrix_flatt, cols, rows, layout)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_layout` but returns an error if the strides overlap or the matrix_flatt is to short
+    pub fn try_from_layout(
+        matrix_flatt: Vec<T>,
+        cols: usize,
+        rows: usize,
+        layout: Layout,
+    ) -> Result<Self, MathError> {
+        let strides = layout.strides(cols, rows);
+        check_strides(cols, rows, strides)?;
+        match buffer_len(cols, rows, strides) {
+            Some(len) if len <= matrix_flatt.len() => (),
+            len => {
+                return Err(MathError::ShapeMismatch {
+                    expected: len.unwrap_or(usize::MAX),
+                    actual: matrix_flatt.len(),
+                })
+            }
+        }
+
+        Ok(Self {
+            cols,
+            rows,
+            matrix_flatt,
+            strides,
+        })
+    }
+
+    /// generates a matrix of size `cols` and `rows` with all values being zero
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::<u8>::zero(2, 3);
+    /// assert_eq!(matrix.matrix_flatt(), vec![0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn zero(cols: usize, rows: usize) -> Self {
+        Self {
+            cols,
+            rows,
+            matrix_flatt: vec![T::zero(); cols * rows],
+            strides: (rows, 1),
+        }
+    }
 
     /// getter for the internal matrix_flatt representation
     ///
@@ -237,20 +566,93 @@ impl Matrix {
     /// let matrix = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
     /// assert_eq!(matrix.matrix_flatt(), vec![2., 3., 5., 7., 1., 4.]);
     /// ```
-    pub fn matrix_flatt(&self) -> Vec<f32> {
-        if self.is_transpose {
-            let mut matrix_flatt = Vec::with_capacity(self.cols * self.rows);
-            for i in 0..self.rows {
-                for val in self.col(i).vec() {
-                    matrix_flatt.push(val);
-                }
-            }
-            matrix_flatt
+    /// note the values are always returned in `RowMajor` order, use `as_slice` for the stored buffer
+    pub fn matrix_flatt(&self) -> Vec<T> {
+        self.view().contiguous().into_owned()
+    }
+
+    /// returns the layout in which the components are stored
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Layout, Matrix};
+    /// let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.layout(), Layout::RowMajor);
+    /// matrix.transpose();
+    /// assert_eq!(matrix.layout(), Layout::ColMajor);
+    /// ```
+    pub fn layout(&self) -> Layout {
+        if self.strides == Layout::RowMajor.strides(self.cols, self.rows) {
+            Layout::RowMajor
+        } else if self.strides == Layout::ColMajor.strides(self.cols, self.rows) {
+            Layout::ColMajor
         } else {
-            self.matrix_flatt.clone()
+            Layout::Strided(self.strides.0, self.strides.1)
         }
     }
 
+    /// returns the strides `(row, col)` so that `index(row, col)` is `as_slice()[row * strides.0 + col * strides.1]`
+    pub fn strides(&self) -> (usize, usize) {
+        self.strides
+    }
+
+    /// returns the stored buffer without copying it
+    ///
+    /// together with `strides()` this can be handed to code that expects BLAS style data
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Layout, Matrix};
+    /// let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// matrix.transpose();
+    /// assert_eq!(matrix.as_slice(), &[3., 2., 4., 4., 5., 6.]);
+    /// assert_eq!(matrix.to_layout(Layout::RowMajor).as_slice(), &[3., 4., 2., 5., 4., 6.]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.matrix_flatt
+    }
+
+    /// returns a copy of the matrix whose components are stored in the given layout
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Layout, Matrix};
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// let col_major = matrix.to_layout(Layout::ColMajor);
+    /// assert_eq!(col_major.as_slice(), &[3., 4., 2., 5., 4., 6.]);
+    /// assert_eq!(col_major, matrix);
+    /// ```
+    /// note it panics if the strides of a `Layout::Strided` overlap or step out of the addressable memory
+    pub fn to_layout(&self, layout: Layout) -> Matrix<T> {
+        self.try_to_layout(layout)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `to_layout` but returns an error if the strides of a `Layout::Strided` overlap
+    /// or step out of the addressable memory
+    pub fn try_to_layout(&self, layout: Layout) -> Result<Matrix<T>, MathError> {
+        let strides = layout.strides(self.cols, self.rows);
+        check_strides(self.cols, self.rows, strides)?;
+        let len = buffer_len(self.cols, self.rows, strides)
+            .ok_or(MathError::OverlappingStrides { strides })?;
+        let mut matrix_flatt = vec![T::zero(); len];
+        for row in 0..self.cols {
+            for col in 0..self.rows {
+                matrix_flatt[row * strides.0 + col * strides.1] = self.index(row, col);
+            }
+        }
+
+        Ok(Matrix {
+            cols: self.cols,
+            rows: self.rows,
+            matrix_flatt,
+            strides,
+        })
+    }
+
     /// return index(row, col) from matrix
     ///
     /// ## Example
@@ -260,21 +662,15 @@ impl Matrix {
     /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
     /// assert_eq!(matrix.index(0, 1), 2.);
     /// ```
-    pub fn index(&self, mut row: usize, mut col: usize) -> f32 {
-        if self.is_transpose {
-            let temp = row;
-            row = col;
-            col = temp;
-        }
-
-        if self.rows < row {
-            panic!("index out of bounds max row {}", self.rows - 1)
-        }
-        if self.cols < col {
-            panic!("index out of bounds max col {}", self.cols - 1)
-        }
+    pub fn index(&self, row: usize, col: usize) -> T {
+        self.try_index(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        self.matrix_flatt[row * self.rows + col]
+    /// same as `index` but returns an error if the index is out of bounds
+    pub fn try_index(&self, row: usize, col: usize) -> Result<T, MathError> {
+        let i = self.flatt_index(row, col)?;
+        Ok(self.matrix_flatt[i])
     }
 
     /// sets the value of the matrix at the specifide index row col
@@ -286,21 +682,16 @@ impl Matrix {
     /// matrix.set_index(0, 1, 10.);
     /// assert_eq!(matrix.matrix_flatt(), vec![2.0, 10.0, 5.0, 7.0, 1.0, 4.0]);
     /// ```
-    pub fn set_index(&mut self, mut row: usize, mut col: usize, val: f32) {
-        if self.is_transpose {
-            let temp = row;
-            row = col;
-            col = temp;
-        }
-
-        if self.rows < row + 1 {
-            panic!("index out of bounds max row {}", self.rows - 1)
-        }
-        if self.cols < col + 1 {
-            panic!("index out of bounds max col {}", self.cols - 1)
-        }
+    pub fn set_index(&mut self, row: usize, col: usize, val: T) {
+        self.try_set_index(row, col, val)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        self.matrix_flatt[row * self.rows + col] = val;
+    /// same as `set_index` but returns an error if the index is out of bounds
+    pub fn try_set_index(&mut self, row: usize, col: usize, val: T) -> Result<(), MathError> {
+        let i = self.flatt_index(row, col)?;
+        self.matrix_flatt[i] = val;
+        Ok(())
     }
 
     /// return the length of the columns
@@ -313,11 +704,7 @@ impl Matrix {
     /// assert_eq!(matrix.cols(), 2);
     /// ```
     pub fn cols(&self) -> usize {
-        if self.is_transpose {
-            self.rows
-        } else {
-            self.cols
-        }
+        self.cols
     }
 
     /// return the length of the rows
@@ -330,11 +717,7 @@ impl Matrix {
     /// assert_eq!(matrix.rows(), 3);
     /// ```
     pub fn rows(&self) -> usize {
-        if self.is_transpose {
-            self.cols
-        } else {
-            self.rows
-        }
+        self.rows
     }
 
     /// return column from matrix
@@ -347,12 +730,14 @@ impl Matrix {
     /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
     /// assert_eq!(matrix.col(0), Vector::new(vec![3., 2., 4.]));
     /// ```
-    pub fn col(&self, col: usize) -> Vector {
-        if self.is_transpose {
-            self.get_row(col)
-        } else {
-            self.get_col(col)
-        }
+    pub fn col(&self, col: usize) -> Vector<T> {
+        self.try_col(col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `col` but returns an error if the column is out of bounds
+    pub fn try_col(&self, col: usize) -> Result<Vector<T>, MathError> {
+        self.view().try_col(col).map(|view| view.to_vector())
     }
 
     /// return row from matrix
@@ -365,12 +750,14 @@ impl Matrix {
     /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
     /// assert_eq!(matrix.row(0), Vector::new(vec![3., 4.]));
     /// ```
-    pub fn row(&self, row: usize) -> Vector {
-        if self.is_transpose {
-            self.get_col(row)
-        } else {
-            self.get_row(row)
-        }
+    pub fn row(&self, row: usize) -> Vector<T> {
+        self.try_row(row)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `row` but returns an error if the row is out of bounds
+    pub fn try_row(&self, row: usize) -> Result<Vector<T>, MathError> {
+        self.view().try_row(row).map(|view| view.to_vector())
     }
 
     /// returns true if the matrix is a [square matrix]  
@@ -390,16 +777,265 @@ impl Matrix {
         self.cols() == self.rows()
     }
 
-    /// getter for the transpose
+    /// returns true if the matrix is stored in `Layout::ColMajor` like a transposed matrix of `Matrix::new`
     pub fn is_transpose(&self) -> bool {
-        self.is_transpose
+        self.layout() == Layout::ColMajor
+    }
+
+    /// [transposes] matrix flips rows and cols
+    ///
+    /// [transposes]: https://en.wikipedia.org/wiki/Transpose
+    ///
+    /// this does not copy the components it only swaps the strides, use `to_layout` to reorder them
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// matrix.transpose();
+    /// assert_eq!(matrix, Matrix::new(vec![vec![3., 4.], vec![2., 5.], vec![4., 6.]]));
+    /// ```
+    pub fn transpose(&mut self) {
+        mem::swap(&mut self.cols, &mut self.rows);
+        self.strides = (self.strides.1, self.strides.0);
+    }
+
+    /// returns a borrowed view of the whole matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// let view = matrix.view();
+    /// assert_eq!(view.index(1, 2), 6.);
+    /// assert_eq!(view.transpose().index(2, 1), 6.);
+    /// ```
+    pub fn view(&self) -> MatrixView<'_, T> {
+        let (cols, rows, strides) = self.view_layout();
+        MatrixView::from_parts(&self.matrix_flatt, 0, cols, rows, strides)
+    }
+
+    /// returns a mutable view of the whole matrix
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (cols, rows, strides) = self.view_layout();
+        MatrixViewMut::from_parts(&mut self.matrix_flatt, 0, cols, rows, strides)
+    }
+
+    /// returns the column like `col` as a view without copying
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.col_view(0).to_vector(), matrix.col(0));
+    /// ```
+    /// note it panics if the column is out of bounds
+    pub fn col_view(&self, col: usize) -> VectorView<'_, T> {
+        self.view().col(col)
+    }
+
+    /// returns the row like `row` as a view without copying
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.row_view(2).to_vector(), Vector::new(vec![4., 6.]));
+    /// ```
+    /// note it panics if the row is out of bounds
+    pub fn row_view(&self, row: usize) -> VectorView<'_, T> {
+        self.view().row(row)
+    }
+
+    /// returns the column like `col` as a mutable view
+    ///
+    /// note it panics if the column is out of bounds
+    pub fn col_view_mut(&mut self, col: usize) -> VectorViewMut<'_, T> {
+        let (cols, rows, strides) = self.view_layout();
+        if col >= cols {
+            panic!(
+                "{}",
+                MathError::IndexOutOfBounds {
+                    index: col,
+                    len: cols
+                }
+            );
+        }
+        VectorViewMut::from_parts(&mut self.matrix_flatt, col * strides.0, rows, strides.1)
+    }
+
+    /// returns the row like `row` as a mutable view
+    ///
+    /// note it panics if the row is out of bounds
+    pub fn row_view_mut(&mut self, row: usize) -> VectorViewMut<'_, T> {
+        let (cols, rows, strides) = self.view_layout();
+        if row >= rows {
+            panic!(
+                "{}",
+                MathError::IndexOutOfBounds {
+                    index: row,
+                    len: rows
+                }
+            );
+        }
+        VectorViewMut::from_parts(&mut self.matrix_flatt, row * strides.1, cols, strides.0)
+    }
+
+    /// returns the [main diagonal] `index(i, i)` as a view
+    ///
+    /// [main diagonal]: https://en.wikipedia.org/wiki/Main_diagonal
+    pub fn diagonal(&self) -> VectorView<'_, T> {
+        self.view().diagonal()
+    }
+
+    /// returns the main diagonal `index(i, i)` as a mutable view
+    pub fn diagonal_mut(&mut self) -> VectorViewMut<'_, T> {
+        let (cols, rows, strides) = self.view_layout();
+        VectorViewMut::from_parts(
+            &mut self.matrix_flatt,
+            0,
+            cols.min(rows),
+            strides.0 + strides.1,
+        )
+    }
+
+    /// returns the block with `cols` and `rows` whose `index(0, 0)` is `index(row, col)` of the matrix
+    ///
+    /// the block is borrowed so it can be a [minor] or any other submatrix without copying
+    ///
+    /// [minor]: https://en.wikipedia.org/wiki/Minor_(linear_algebra)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// let block = matrix.submatrix(0, 1, 2, 2);
+    /// assert_eq!(block.to_matrix(), Matrix::new(vec![vec![2., 3.], vec![5., 6.]]));
+    /// ```
+    /// note it panics if the block does not fit in to the matrix
+    pub fn submatrix(&self, row: usize, col: usize, cols: usize, rows: usize) -> MatrixView<'_, T> {
+        self.view().submatrix(row, col, cols, rows)
+    }
+
+    /// same as `submatrix` but returns an error if the block does not fit in to the matrix
+    pub fn try_submatrix(
+        &self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> Result<MatrixView<'_, T>, MathError> {
+        self.view().try_submatrix(row, col, cols, rows)
+    }
+
+    /// returns the mutable block with `cols` and `rows` whose `index(0, 0)` is `index(row, col)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.submatrix_mut(0, 1, 2, 2).mul_scalar(&2.);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 4., 6.], vec![4., 10., 12.]]));
+    /// ```
+    /// note it panics if the block does not fit in to the matrix
+    pub fn submatrix_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> MatrixViewMut<'_, T> {
+        self.try_submatrix_mut(row, col, cols, rows)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `submatrix_mut` but returns an error if the block does not fit in to the matrix
+    pub fn try_submatrix_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> Result<MatrixViewMut<'_, T>, MathError> {
+        self.view().try_submatrix(row, col, cols, rows)?;
+        let (_, _, strides) = self.view_layout();
+        Ok(MatrixViewMut::from_parts(
+            &mut self.matrix_flatt,
+            row * strides.0 + col * strides.1,
+            cols,
+            rows,
+            strides,
+        ))
+    }
+
+    /// returns the view of the ranges of `row` and `col` in `index(row, col)`
+    ///
+    /// this is the range version of `matrix[(row, col)]` which can not return a view
+    /// because `std::ops::Index` has to return a reference
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// assert_eq!(
+    ///     matrix.slice(1..3, ..2).to_matrix(),
+    ///     Matrix::new(vec![vec![4., 5.], vec![7., 8.]])
+    /// );
+    /// assert_eq!(matrix.slice(.., 2)[(1, 0)], 6.);
+    /// ```
+    /// note it panics if a range is out of bounds
+    pub fn slice(&self, row: impl SliceRange, col: impl SliceRange) -> MatrixView<'_, T> {
+        self.try_slice(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `slice` but returns an error if a range is out of bounds
+    pub fn try_slice(
+        &self,
+        row: impl SliceRange,
+        col: impl SliceRange,
+    ) -> Result<MatrixView<'_, T>, MathError> {
+        let (row, cols) = row.bounds(self.cols)?;
+        let (col, rows) = col.bounds(self.rows)?;
+        self.try_submatrix(row, col, cols, rows)
+    }
+
+    /// returns the mutable view of the ranges of `row` and `col` in `index(row, col)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.slice_mut(.., 1..).fill(0.);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 0., 0.], vec![4., 0., 0.]]));
+    /// ```
+    /// note it panics if a range is out of bounds
+    pub fn slice_mut(
+        &mut self,
+        row: impl SliceRange,
+        col: impl SliceRange,
+    ) -> MatrixViewMut<'_, T> {
+        self.try_slice_mut(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
-    /// [transposes] matrix flips rows and cols
-    ///
-    /// [transposes]: https://en.wikipedia.org/wiki/Transpose
-    pub fn transpose(&mut self) {
-        self.is_transpose = !self.is_transpose;
+    /// same as `slice_mut` but returns an error if a range is out of bounds
+    pub fn try_slice_mut(
+        &mut self,
+        row: impl SliceRange,
+        col: impl SliceRange,
+    ) -> Result<MatrixViewMut<'_, T>, MathError> {
+        let (row, cols) = row.bounds(self.cols)?;
+        let (col, rows) = col.bounds(self.rows)?;
+        self.try_submatrix_mut(row, col, cols, rows)
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -418,8 +1054,8 @@ impl Matrix {
     ///     ])
     /// );
     /// ```
-    pub fn mul_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x * scalar).collect();
+    pub fn mul_scalar(&mut self, scalar: &T) {
+        self.view_mut().mul_scalar(scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -438,8 +1074,8 @@ impl Matrix {
     ///     ])
     /// );
     /// ```
-    pub fn add_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x + scalar).collect();
+    pub fn add_scalar(&mut self, scalar: &T) {
+        self.view_mut().add_scalar(scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -458,8 +1094,8 @@ impl Matrix {
     ///     ])
     /// );
     /// ```
-    pub fn div_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x / scalar).collect();
+    pub fn div_scalar(&mut self, scalar: &T) {
+        self.view_mut().div_scalar(scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -478,8 +1114,8 @@ impl Matrix {
     ///     ])
     /// );
     /// ```
-    pub fn sub_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x - scalar).collect();
+    pub fn sub_scalar(&mut self, scalar: &T) {
+        self.view_mut().sub_scalar(scalar);
     }
 
     /// computes the dot product between the vector and this matrix
@@ -495,26 +1131,29 @@ impl Matrix {
     ///     Vector::new(vec![1., -3.])
     /// );
     /// ```
-    pub fn dot_vec(&self, vector: &Vector) -> Vector {
-        let vec = vector.vec();
-        check_vector(self, vector);
-
-        let mut result: Vec<f32> = Vec::with_capacity(self.cols());
-        for i in 0..self.cols() {
-            result.push(
-                self.col(i)
-                    .vec()
-                    .iter()
-                    .enumerate()
-                    .map(|(j, x)| vec[j] * x)
-                    .sum(),
-            );
-        }
-        Vector::new(result)
+    pub fn dot_vec<'b>(&self, vector: impl Into<VectorView<'b, T>>) -> Vector<T>
+    where
+        T: 'b,
+    {
+        self.try_dot_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the len of the vector is not the rows of the matrix
+    pub fn try_dot_vec<'b>(
+        &self,
+        vector: impl Into<VectorView<'b, T>>,
+    ) -> Result<Vector<T>, MathError>
+    where
+        T: 'b,
+    {
+        self.view().try_dot_vec(vector)
     }
 
     /// adds each component from the vector with the component of the other matrix and stors the result in this matrix   
     ///
+    /// the vector is applied to each `col(i)` so `index(row, col)` is combined with `vector.index(col)`
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -525,22 +1164,36 @@ impl Matrix {
     /// matrix.add_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![6.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, 1.0, 7.0], vec![4.0, 4.0, 5.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn add_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) + vector.index(row);
-                self.set_index(row, col, val);
-            }
+    pub fn add_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_add_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `add_vec` but returns an error if the vector has the wrong len
+    pub fn try_add_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let vector = vector.into();
+        check_vector(&self.view(), &vector)?;
+        let mut view = self.view_mut();
+        for col in 0..view.cols() {
+            view.col_mut(col).try_add_vec(vector)?;
         }
+        Ok(())
     }
 
     /// subtracts each component from the vector with the component of the other matrix and stors the result in this matrix   
     ///
+    /// the vector is applied to each `col(i)` so `index(row, col)` is combined with `vector.index(col)`
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -551,22 +1204,36 @@ impl Matrix {
     /// matrix.sub_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-2.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![0.0, -7.0, -5.0], vec![0.0, -4.0, -7.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn sub_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) - vector.index(row);
-                self.set_index(row, col, val);
-            }
+    pub fn sub_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_sub_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `sub_vec` but returns an error if the vector has the wrong len
+    pub fn try_sub_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let vector = vector.into();
+        check_vector(&self.view(), &vector)?;
+        let mut view = self.view_mut();
+        for col in 0..view.cols() {
+            view.col_mut(col).try_sub_vec(vector)?;
         }
+        Ok(())
     }
 
     /// multiplys each component from the vector with the component of the other matrix and stors the result in this matrix   
     ///
+    /// the vector is applied to each `col(i)` so `index(row, col)` is combined with `vector.index(col)`
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -577,22 +1244,36 @@ impl Matrix {
     /// matrix.mul_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![8.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, -12.0, 6.0], vec![4.0, 0.0, -6.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn mul_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) * vector.index(row);
-                self.set_index(row, col, val);
-            }
+    pub fn mul_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_mul_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `mul_vec` but returns an error if the vector has the wrong len
+    pub fn try_mul_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let vector = vector.into();
+        check_vector(&self.view(), &vector)?;
+        let mut view = self.view_mut();
+        for col in 0..view.cols() {
+            view.col_mut(col).try_mul_vec(vector)?;
         }
+        Ok(())
     }
 
     /// divides each component from the vector with the component of the other matrix and stors the result in this matrix   
     ///
+    /// the vector is applied to each `col(i)` so `index(row, col)` is combined with `vector.index(col)`
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -603,18 +1284,30 @@ impl Matrix {
     /// matrix.div_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.5, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![1.0, -0.75, 1. / 6.], vec![1.0, 0.0, -1. / 6.]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn div_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) / vector.index(row);
-                self.set_index(row, col, val);
-            }
+    pub fn div_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_div_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `div_vec` but returns an error if the vector has the wrong len
+    pub fn try_div_vec<'b>(&mut self, vector: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let vector = vector.into();
+        check_vector(&self.view(), &vector)?;
+        let mut view = self.view_mut();
+        for col in 0..view.cols() {
+            view.col_mut(col).try_div_vec(vector)?;
         }
+        Ok(())
     }
 
     /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
@@ -629,18 +1322,24 @@ impl Matrix {
     /// matrix1.add_mat(&matrix2);
     /// assert_eq!(
     ///     matrix1,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn add_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) + other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+    pub fn add_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_add_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `add_mat` but returns an error if the matrices have not the same rows and cols
+    pub fn try_add_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_add_mat(other)
     }
 
     /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
@@ -655,21 +1354,29 @@ impl Matrix {
     /// matrix1.sub_mat(&matrix2);
     /// assert_eq!(
     ///   matrix1,
-    ///   Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+    ///   Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn sub_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) - other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+    pub fn sub_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_sub_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
-    /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
+    /// same as `sub_mat` but returns an error if the matrices have not the same rows and cols
+    pub fn try_sub_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_sub_mat(other)
+    }
+
+    /// divides each component from the matrix by the component of the other matrix and stores the result in this matrix
+    ///
+    /// this is not `A * B^-1`, see `inverse` and `solve_mat` for that
     ///
     /// ## Example
     ///
@@ -681,18 +1388,24 @@ impl Matrix {
     /// matrix1.div_mat(&matrix2);
     /// assert_eq!(
     ///     matrix1,
-    ///     Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![2. / 7., 0.0, -0.25]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn div_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) / other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+    pub fn div_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_div_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `div_mat` but returns an error if the matrices have not the same rows and cols
+    pub fn try_div_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_div_mat(other)
     }
 
     /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
@@ -707,23 +1420,220 @@ impl Matrix {
     /// matrix1.mul_mat(&matrix2);
     /// assert_eq!(
     ///   matrix1,
-    ///   Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+    ///   Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
-    pub fn mul_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) * other.index(row, col);
-                self.set_index(row, col, val);
-            }
+    pub fn mul_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_mul_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `mul_mat` but returns an error if the matrices have not the same rows and cols
+    pub fn try_mul_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_mul_mat(other)
+    }
+
+    /// computes the [matrix product] between this matrix and the other matrix
+    ///
+    /// [matrix product]: https://en.wikipedia.org/wiki/Matrix_multiplication
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix1 = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// let matrix2 = Matrix::new(vec![vec![7., 8.], vec![9., 10.], vec![11., 12.]]);
+    /// assert_eq!(
+    ///     matrix1.dot_mat(&matrix2),
+    ///     Matrix::new(vec![vec![58., 64.], vec![139., 154.]])
+    /// );
+    /// ```
+    /// note it panics if the rows of this matrix are not the same as the cols of the other matrix
+    pub fn dot_mat<'b>(&self, other: impl Into<MatrixView<'b, T>>) -> Matrix<T>
+    where
+        T: 'b,
+    {
+        self.try_dot_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_mat` but returns an error if the rows of this matrix are not the same
+    /// as the cols of the other matrix
+    pub fn try_dot_mat<'b>(
+        &self,
+        other: impl Into<MatrixView<'b, T>>,
+    ) -> Result<Matrix<T>, MathError>
+    where
+        T: 'b,
+    {
+        self.view().try_dot_mat(other)
+    }
+
+    // returns the cols, rows and strides of `index(row, col)` in matrix_flatt
+    fn view_layout(&self) -> (usize, usize, (usize, usize)) {
+        (self.cols, self.rows, self.strides)
+    }
+
+    // maps the index to the position in matrix_flatt
+    fn flatt_index(&self, row: usize, col: usize) -> Result<usize, MathError> {
+        if row >= self.cols {
+            return Err(MathError::IndexOutOfBounds {
+                index: row,
+                len: self.cols,
+            });
+        }
+        if col >= self.rows {
+            return Err(MathError::IndexOutOfBounds {
+                index: col,
+                len: self.rows,
+            });
+        }
+        Ok(row * self.strides.0 + col * self.strides.1)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// returns true if the matrix is a [symmetric matrix] up to rounding errors
+    ///
+    /// that means it is equal to its transpose
+    ///
+    /// [symmetric matrix]:https://en.wikipedia.org/wiki/Symmetric_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![3., 2.], vec![2., 5.]]);
+    /// assert_eq!(matrix.is_symmetric(), true);
+    /// let matrix = Matrix::new(vec![vec![3., 2.], vec![4., 5.]]);
+    /// assert_eq!(matrix.is_symmetric(), false);
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
         }
+
+        let n = self.rows();
+        let flatt = self.matrix_flatt();
+        let max = flatt.iter().fold(T::zero(), |max, x| max.max(x.abs()));
+        let tolerance = T::from_usize(n) * T::epsilon() * max;
+        (0..n).all(|i| (0..i).all(|j| (flatt[i * n + j] - flatt[j * n + i]).abs() <= tolerance))
+    }
+
+    /// returns the [LU decomposition] with partial pivoting of this matrix
+    ///
+    /// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// let lu = matrix.lu();
+    /// assert_eq!(lu.solve(&Vector::new(vec![3., 9.])), Vector::new(vec![1., 1.]));
+    /// ```
+    /// note the matrix has to be a [square matrix]
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    pub fn lu(&self) -> LuDecomposition<T> {
+        LuDecomposition::new(self)
+    }
+
+    /// same as `lu` but returns an error instead of panicking, see `LuDecomposition::try_new`
+    pub fn try_lu(&self) -> Result<LuDecomposition<T>, MathError> {
+        LuDecomposition::try_new(self)
+    }
+
+    /// returns the [QR decomposition] of this matrix computed with householder reflections
+    ///
+    /// [QR decomposition]: https://en.wikipedia.org/wiki/QR_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![3., 0.], vec![4., 5.], vec![0., 4.]]);
+    /// let qr = matrix.qr();
+    /// assert_eq!(qr.r(), Matrix::new(vec![vec![-5., -4.], vec![0., -5.]]));
+    /// ```
+    /// note the matrix has to have at least as many cols as rows
+    pub fn qr(&self) -> QrDecomposition<T> {
+        QrDecomposition::new(self)
+    }
+
+    /// same as `qr` but returns an error instead of panicking, see `QrDecomposition::try_new`
+    pub fn try_qr(&self) -> Result<QrDecomposition<T>, MathError> {
+        QrDecomposition::try_new(self)
+    }
+
+    /// returns the [Cholesky decomposition] of this symmetric positive definite matrix
+    ///
+    /// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 5.]]);
+    /// assert_eq!(
+    ///     matrix.cholesky().l(),
+    ///     Matrix::new(vec![vec![2., 0.], vec![1., 2.]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is not symmetric or not positive definite
+    pub fn cholesky(&self) -> CholeskyDecomposition<T> {
+        CholeskyDecomposition::new(self)
+    }
+
+    /// same as `cholesky` but returns an error instead of panicking, see `CholeskyDecomposition::try_new`
+    pub fn try_cholesky(&self) -> Result<CholeskyDecomposition<T>, MathError> {
+        CholeskyDecomposition::try_new(self)
+    }
+
+    /// returns the LDL^T decomposition of this symmetric positive semidefinite matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 1.]]);
+    /// assert_eq!(matrix.ldlt().d(), Vector::new(vec![4., 0.]));
+    /// ```
+    /// note it panics if the matrix is not symmetric or has a negative eigenvalue
+    pub fn ldlt(&self) -> LdltDecomposition<T> {
+        LdltDecomposition::new(self)
+    }
+
+    /// same as `ldlt` but returns an error instead of panicking, see `LdltDecomposition::try_new`
+    pub fn try_ldlt(&self) -> Result<LdltDecomposition<T>, MathError> {
+        LdltDecomposition::try_new(self)
+    }
+
+    /// returns the [singular value decomposition] of this matrix
+    ///
+    /// [singular value decomposition]: https://en.wikipedia.org/wiki/Singular_value_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![0., 2.], vec![1., 0.], vec![0., 0.]]);
+    /// assert_eq!(matrix.svd().singular_values(), Vector::new(vec![2., 1.]));
+    /// ```
+    pub fn svd(&self) -> Svd<T> {
+        Svd::new(self)
     }
 
-    pub fn dot_mat(&self, other: &Matrix) {
-        check_matrix(self, other);
-        todo!();
+    /// same as `svd` but returns an error instead of panicking, see `Svd::try_new`
+    pub fn try_svd(&self) -> Result<Svd<T>, MathError> {
+        Svd::try_new(self)
     }
 
     /// returns the [determinant] of this matrix
@@ -734,119 +1644,343 @@ impl Matrix {
     ///
     /// ```rust
     /// use math::linear_algebra::Matrix;
-    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
-    /// assert_eq!(matrix.det(), -5.);
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+    /// assert_eq!(matrix.det(), 6.);
     /// ```
     ///  note the matrix has to be a [square matrix]
     ///
     /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
-    pub fn det(&self) -> f32 {
-        check_square(self);
-        if self.rows() == 2 {
-            self.index(0, 0) * self.index(1, 1) - self.index(1, 0) * self.index(1, 0)
-        } else {
-            let mut sign = 1.;
-            let mut sum = 0.;
+    pub fn det(&self) -> T {
+        self.try_det().unwrap_or_else(|error| panic!("{}", error))
+    }
 
-            for col in 0..self.cols() {
-                let sub = self.finde_sub(0, col);
-                sum += sub.det() * sign * self.index(0, col);
-                sign *= -1.;
-            }
+    /// same as `det` but returns an error if the matrix is not a square matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix};
+    /// let matrix = Matrix::new(vec![vec![2., 1., 3.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.try_det(), Err(MathError::NotSquare { cols: 2, rows: 3 }));
+    /// ```
+    pub fn try_det(&self) -> Result<T, MathError> {
+        check_square(self)?;
+        Ok(self.try_lu()?.det())
+    }
 
-            sum
-        }
+    /// returns the [inverse] of this matrix computed with the LU decomposition
+    ///
+    /// [inverse]: https://en.wikipedia.org/wiki/Invertible_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 4.]]);
+    /// assert_eq!(
+    ///     matrix.inverse(),
+    ///     Matrix::new(vec![vec![1., -0.25], vec![-1., 0.5]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is not a [square matrix] or singular,
+    /// use `solve_mat` to compute `A^-1 * B` without forming the inverse
+    ///
+    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
+    pub fn inverse(&self) -> Matrix<T> {
+        self.try_inverse()
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
-    // finds the sub matrix is user for the determinant
-    fn finde_sub(&self, row: usize, col: usize) -> Self {
-        let mut flatt = Vec::with_capacity((self.cols() - 1) * (self.rows() - 1));
+    /// same as `inverse` but returns an error if the matrix is not a square matrix or singular
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(matrix.try_inverse(), Err(MathError::Singular));
+    /// ```
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MathError> {
+        self.try_lu()?.try_inverse()
+    }
 
-        for i in 0..self.cols() {
-            for j in 0..self.rows() {
-                if !(i == col || j == row) {
-                    flatt.push(self.index(i, j));
-                }
-            }
-        }
-        Self::new_flatt(flatt, self.cols() - 1, self.rows() - 1)
+    /// same as `inverse` but the matrix counts as singular if a pivot of the LU decomposition
+    /// is at most `tolerance` times the largest absolute entry, `inverse` uses `n * ε`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix};
+    /// let matrix = Matrix::new(vec![vec![1., 1.], vec![1., 1.001]]);
+    /// assert!(matrix.try_inverse().is_ok());
+    /// assert_eq!(matrix.try_inverse_with_tolerance(0.01), Err(MathError::Singular));
+    /// ```
+    /// note it panics if the matrix is not a square matrix or singular
+    pub fn inverse_with_tolerance(&self, tolerance: T) -> Matrix<T> {
+        self.try_inverse_with_tolerance(tolerance)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `inverse_with_tolerance` but returns an error if the matrix is not a square matrix or singular
+    pub fn try_inverse_with_tolerance(&self, tolerance: T) -> Result<Matrix<T>, MathError> {
+        LuDecomposition::try_new_with_tolerance(self, tolerance)?.try_inverse()
+    }
+
+    /// returns the [Moore–Penrose inverse] of this matrix which also exists for rectangular
+    /// and rank deficient matrices
+    ///
+    /// [Moore–Penrose inverse]: https://en.wikipedia.org/wiki/Moore%E2%80%93Penrose_inverse
+    ///
+    /// singular values up to `tolerance` are treated as zero,
+    /// `svd().pinv()` picks the tolerance from the size and norm of the matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 0.], vec![0., 0.], vec![0., 1e-6]]);
+    /// assert_eq!(
+    ///     matrix.pinv(1e-3),
+    ///     Matrix::new(vec![vec![0.5, 0., 0.], vec![0., 0., 0.]])
+    /// );
+    /// ```
+    pub fn pinv(&self, tolerance: T) -> Matrix<T> {
+        self.try_pinv(tolerance)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
-    /// this returns the [eigenvalues] of this matrix
+    /// same as `pinv` but returns an error instead of panicking, see `Svd::try_new`
+    pub fn try_pinv(&self, tolerance: T) -> Result<Matrix<T>, MathError> {
+        Ok(self.try_svd()?.pinv_with_tolerance(tolerance))
+    }
+
+    /// returns the [eigendecomposition] of this symmetric matrix with all eigenvalues and eigenvectors
     ///
-    /// [eigenvalues]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    /// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
     ///
     /// ## Example
     ///
     /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 0., 0.], vec![0., 3., 4.], vec![0., 4., 9.]]);
+    /// let values = matrix.symmetric_eigen().eigenvalues();
+    /// assert!((values.index(0) - 11.).abs() < 1e-5);
+    /// assert!((values.index(1) - 2.).abs() < 1e-5);
+    /// assert!((values.index(2) - 1.).abs() < 1e-5);
+    /// ```
+    /// note it panics if the matrix is not symmetric
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T> {
+        SymmetricEigen::new(self)
+    }
+
+    /// same as `symmetric_eigen` but returns an error instead of panicking, see `SymmetricEigen::try_new`
+    pub fn try_symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MathError> {
+        SymmetricEigen::try_new(self)
+    }
+
+    /// returns the [eigendecomposition] of this matrix with the possibly complex eigenvalues and eigenvectors
     ///
+    /// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., -2.], vec![2., 1.]]);
+    /// let eigen = matrix.eigen();
+    /// assert_eq!(eigen.eigenvalue(0), (1., 2.));
+    /// assert_eq!(eigen.eigenvalue(1), (1., -2.));
     /// ```
     /// note the matrix has to be a [square matrix]
     ///
     /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
-    pub fn eigen_val(&self) -> f32 {
-        check_square(self);
-        todo!();
+    pub fn eigen(&self) -> Eigen<T> {
+        Eigen::new(self)
     }
 
-    pub fn eigen_vec(&self) -> Vector {
-        check_square(self);
-        todo!();
+    /// same as `eigen` but returns an error instead of panicking, see `Eigen::try_new`
+    pub fn try_eigen(&self) -> Result<Eigen<T>, MathError> {
+        Eigen::try_new(self)
     }
 
-    fn get_row(&self, row: usize) -> Vector {
-        if self.rows < row + 1 {
-            panic!("index out of bounds max row {}", self.rows - 1)
-        }
+    /// this returns the dominant [eigenvalue] of this symmetric matrix which has the largest absolute value
+    ///
+    /// [eigenvalue]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 2.]]);
+    /// assert!((matrix.eigen_val() - 3.).abs() < 1e-5);
+    /// ```
+    /// note the matrix has to be a [symmetric matrix] use `symmetric_eigen()` to get all eigenvalues
+    ///
+    /// [symmetric matrix]: https://en.wikipedia.org/wiki/Symmetric_matrix
+    pub fn eigen_val(&self) -> T {
+        self.try_eigen_val().unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        let mut result: Vec<f32> = Vec::with_capacity(self.cols);
-        for i in 0..self.cols {
-            result.push(self.matrix_flatt[i * self.rows + row].clone());
-        }
+    /// same as `eigen_val` but returns an error instead of panicking, see `SymmetricEigen::try_new`
+    pub fn try_eigen_val(&self) -> Result<T, MathError> {
+        Ok(self.try_symmetric_eigen()?.dominant().0)
+    }
 
-        Vector::new(result)
+    /// this returns the normalized [eigenvector] of the dominant eigenvalue of this symmetric matrix
+    ///
+    /// [eigenvector]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 0.], vec![0., 3.]]);
+    /// assert_eq!(matrix.eigen_vec(), Vector::new(vec![0., 1.]));
+    /// ```
+    /// note the matrix has to be a [symmetric matrix] use `symmetric_eigen()` to get all eigenvectors
+    ///
+    /// [symmetric matrix]: https://en.wikipedia.org/wiki/Symmetric_matrix
+    pub fn eigen_vec(&self) -> Vector<T> {
+        self.try_eigen_vec().unwrap_or_else(|error| panic!("{}", error))
     }
 
-    fn get_col(&self, col: usize) -> Vector {
-        if self.cols < col + 1 {
-            panic!("index out of bounds max col {}", self.cols - 1)
-        }
+    /// same as `eigen_vec` but returns an error instead of panicking, see `SymmetricEigen::try_new`
+    pub fn try_eigen_vec(&self) -> Result<Vector<T>, MathError> {
+        Ok(self.try_symmetric_eigen()?.dominant().1)
+    }
 
-        let mut result: Vec<f32> = Vec::with_capacity(self.rows);
-        for i in (col * self.rows)..((1 + col) * self.rows) {
-            result.push(self.matrix_flatt[i].clone());
+    /// converts the matrix to a complex matrix with zero imaginary parts
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]).to_complex();
+    /// assert_eq!(matrix.index(1, 0), Complex::new(3., 0.));
+    /// ```
+    pub fn to_complex(&self) -> Matrix<Complex<T>> {
+        Matrix {
+            cols: self.cols,
+            rows: self.rows,
+            matrix_flatt: self
+                .matrix_flatt
+                .iter()
+                .map(|&x| Complex::from(x))
+                .collect(),
+            strides: self.strides,
         }
-
-        Vector::new(result)
     }
 }
 
-fn check_square(mat: &Matrix) {
-    if !mat.is_square() {
-        panic!("the matrix has to be a square matrix");
+impl<T: Real> Matrix<Complex<T>> {
+    /// conjugates each component of the matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::from_vec(vec![vec![Complex::new(1., 2.)]]);
+    /// matrix.conj();
+    /// assert_eq!(matrix.index(0, 0), Complex::new(1., -2.));
+    /// ```
+    pub fn conj(&mut self) {
+        self.matrix_flatt.iter_mut().for_each(|x| *x = x.conj());
     }
 
-    if mat.rows() == 1 {
-        panic!("the matrix has to have more then one row");
+    /// [conjugate transposes] the matrix, it flips rows and cols and conjugates each component
+    ///
+    /// [conjugate transposes]: https://en.wikipedia.org/wiki/Conjugate_transpose
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::from_vec(vec![
+    ///     vec![Complex::new(1., 0.), Complex::new(2., 1.)],
+    ///     vec![Complex::new(0., 3.), Complex::new(4., 0.)],
+    /// ]);
+    /// matrix.conjugate_transpose();
+    /// assert_eq!(matrix.index(0, 1), Complex::new(0., -3.));
+    /// assert_eq!(matrix.index(1, 0), Complex::new(2., -1.));
+    /// ```
+    pub fn conjugate_transpose(&mut self) {
+        self.transpose();
+        self.conj();
     }
-}
 
-fn check_vector(mat: &Matrix, vec: &Vector) {
-    if vec.len() != mat.rows() {
-        panic!(
-            "wrong vector shape expected {}, got {}",
-            mat.rows,
-            vec.len()
-        )
+    /// returns true if the matrix is a [Hermitian matrix] up to rounding errors
+    ///
+    /// that means it is equal to its conjugate transpose so the diagonal is real
+    ///
+    /// [Hermitian matrix]: https://en.wikipedia.org/wiki/Hermitian_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_vec(vec![
+    ///     vec![Complex::new(2., 0.), Complex::new(0., -1.)],
+    ///     vec![Complex::new(0., 1.), Complex::new(3., 0.)],
+    /// ]);
+    /// assert!(matrix.is_hermitian());
+    /// let matrix = Matrix::from_vec(vec![
+    ///     vec![Complex::new(2., 0.), Complex::new(0., 1.)],
+    ///     vec![Complex::new(0., 1.), Complex::new(3., 0.)],
+    /// ]);
+    /// assert!(!matrix.is_hermitian());
+    /// ```
+    pub fn is_hermitian(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let n = self.rows();
+        let flatt = self.matrix_flatt();
+        let max = flatt.iter().fold(T::zero(), |max, x| max.max(x.abs()));
+        let tolerance = T::from_usize(n) * T::epsilon() * max;
+        (0..n).all(|i| {
+            (0..=i).all(|j| (flatt[i * n + j] - flatt[j * n + i].conj()).abs() <= tolerance)
+        })
+    }
+
+    /// returns the [eigendecomposition] of this Hermitian matrix which has real eigenvalues
+    ///
+    /// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_vec(vec![
+    ///     vec![Complex::new(2_f32, 0.), Complex::new(0., -1.)],
+    ///     vec![Complex::new(0., 1.), Complex::new(2., 0.)],
+    /// ]);
+    /// let values = matrix.hermitian_eigen().eigenvalues();
+    /// assert!((values.index(0) - 3.).abs() < 1e-5);
+    /// assert!((values.index(1) - 1.).abs() < 1e-5);
+    /// ```
+    /// note it panics if the matrix is not Hermitian
+    pub fn hermitian_eigen(&self) -> HermitianEigen<T> {
+        HermitianEigen::new(self)
     }
-}
 
-fn check_matrix(mat1: &Matrix, mat2: &Matrix) {
-    if mat1.rows() != mat2.rows() {
-        panic!("wrong row shape expected {}, got {}", mat1.rows, mat2.rows)
+    /// same as `hermitian_eigen` but returns an error instead of panicking, see `HermitianEigen::try_new`
+    pub fn try_hermitian_eigen(&self) -> Result<HermitianEigen<T>, MathError> {
+        HermitianEigen::try_new(self)
     }
+}
 
-    if mat1.cols() != mat2.cols() {
-        panic!("wrong col shape expected {}, got {}", mat1.cols, mat2.cols)
+fn check_square<T: Scalar>(mat: &Matrix<T>) -> Result<(), MathError> {
+    if !mat.is_square() {
+        return Err(MathError::NotSquare {
+            cols: mat.cols(),
+            rows: mat.rows(),
+        });
     }
+    Ok(())
 }
@@ -0,0 +1,204 @@
+use crate::linear_algebra::{CscMatrix, MathError};
+use crate::scalar::Scalar;
+use std::collections::{BTreeSet, VecDeque};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the symmetric permutation that a sparse factorization applies before it eliminates
+///
+/// a good ordering keeps the factors sparse, the [fill in] of the natural order can make them dense
+///
+/// [fill in]: https://en.wikipedia.org/wiki/Sparse_matrix#Reducing_fill-in
+pub enum FillOrdering {
+    /// keeps the order of the matrix
+    Natural,
+    /// the [minimum degree] ordering that always eliminates the node with the fewest neighbours next
+    ///
+    /// [minimum degree]: https://en.wikipedia.org/wiki/Minimum_degree_algorithm
+    ///
+    /// the degrees are exact instead of the bounds of the approximate minimum degree ordering,
+    /// it usually gives the least fill in
+    MinimumDegree,
+    /// the [reverse Cuthill–McKee] ordering that reduces the bandwidth
+    ///
+    /// [reverse Cuthill–McKee]: https://en.wikipedia.org/wiki/Cuthill%E2%80%93McKee_algorithm
+    ///
+    /// it is cheaper to compute than `MinimumDegree` and works well for meshes that are long and thin
+    ReverseCuthillMcKee,
+}
+
+impl FillOrdering {
+    /// returns the permutation p for the nonzero pattern of `A + A^T`,
+    /// row and col `k` of the permuted matrix are row and col `p[k]` of the matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CscMatrix, FillOrdering, Matrix};
+    /// // an arrow matrix whose first row and col are full
+    /// let matrix = CscMatrix::from_dense(&Matrix::new(vec![
+    ///     vec![4., 1., 1., 1.],
+    ///     vec![1., 4., 0., 0.],
+    ///     vec![1., 0., 4., 0.],
+    ///     vec![1., 0., 0., 4.],
+    /// ]));
+    /// assert_eq!(FillOrdering::Natural.permutation(&matrix), vec![0, 1, 2, 3]);
+    /// // the full row is only eliminated once it has a single neighbour left so there is no fill in
+    /// assert_eq!(FillOrdering::MinimumDegree.permutation(&matrix), vec![1, 2, 0, 3]);
+    /// ```
+    /// note it panics if the matrix is not a square matrix
+    pub fn permutation<T: Scalar>(self, matrix: &CscMatrix<T>) -> Vec<usize> {
+        self.try_permutation(matrix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `permutation` but returns an error if the matrix is not a square matrix
+    pub fn try_permutation<T: Scalar>(
+        self,
+        matrix: &CscMatrix<T>,
+    ) -> Result<Vec<usize>, MathError> {
+        if matrix.cols() != matrix.rows() {
+            return Err(MathError::NotSquare {
+                cols: matrix.cols(),
+                rows: matrix.rows(),
+            });
+        }
+        Ok(match self {
+            FillOrdering::Natural => (0..matrix.rows()).collect(),
+            FillOrdering::MinimumDegree => minimum_degree(adjacency(matrix)),
+            FillOrdering::ReverseCuthillMcKee => reverse_cuthill_mckee(adjacency(matrix)),
+        })
+    }
+}
+
+// the sorted neighbours of every node in the graph of A + A^T without the diagonal
+fn adjacency<T: Scalar>(matrix: &CscMatrix<T>) -> Vec<Vec<usize>> {
+    let n = matrix.rows();
+    let mut graph = vec![Vec::new(); n];
+    for col in 0..n {
+        for &row in &matrix.indices()[matrix.offsets()[col]..matrix.offsets()[col + 1]] {
+            if row != col {
+                graph[row].push(col);
+                graph[col].push(row);
+            }
+        }
+    }
+    for neighbours in graph.iter_mut() {
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    }
+    graph
+}
+
+// eliminates the nodes on the explicit elimination graph where the neighbours
+// of an eliminated node become a clique, ties are broken by the smaller index
+fn minimum_degree(graph: Vec<Vec<usize>>) -> Vec<usize> {
+    let mut graph: Vec<BTreeSet<usize>> = graph
+        .into_iter()
+        .map(|neighbours| neighbours.into_iter().collect())
+        .collect();
+    let mut queue: BTreeSet<(usize, usize)> = graph
+        .iter()
+        .enumerate()
+        .map(|(node, neighbours)| (neighbours.len(), node))
+        .collect();
+    let mut order = Vec::with_capacity(graph.len());
+
+    while let Some(&(degree, node)) = queue.iter().next() {
+        queue.remove(&(degree, node));
+        order.push(node);
+        let neighbours: Vec<usize> = std::mem::take(&mut graph[node]).into_iter().collect();
+        for &a in neighbours.iter() {
+            queue.remove(&(graph[a].len(), a));
+            graph[a].remove(&node);
+        }
+        for &a in neighbours.iter() {
+            graph[a].extend(neighbours.iter().copied().filter(|&b| b != a));
+        }
+        for &a in neighbours.iter() {
+            queue.insert((graph[a].len(), a));
+        }
+    }
+    order
+}
+
+// runs a breadth first search from a pseudo peripheral node of every connected component
+// that visits the neighbours by increasing degree and reverses the order
+fn reverse_cuthill_mckee(graph: Vec<Vec<usize>>) -> Vec<usize> {
+    let n = graph.len();
+    let mut by_degree: Vec<usize> = (0..n).collect();
+    by_degree.sort_by_key(|&node| graph[node].len());
+    let mut visited = vec![false; n];
+    let mut marks = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    for &start in by_degree.iter() {
+        if visited[start] {
+            continue;
+        }
+        let root = pseudo_peripheral(&graph, start, &mut marks);
+        visited[root] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            let mut next: Vec<usize> = graph[node]
+                .iter()
+                .copied()
+                .filter(|&neighbour| !visited[neighbour])
+                .collect();
+            next.sort_by_key(|&neighbour| graph[neighbour].len());
+            for neighbour in next {
+                visited[neighbour] = true;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+// the heuristic of George and Liu that moves to a node of minimal degree in the last
+// level of the breadth first search as long as the number of levels grows
+fn pseudo_peripheral(graph: &[Vec<usize>], start: usize, marks: &mut [bool]) -> usize {
+    let mut root = start;
+    let (mut depth, mut last) = levels(graph, root, marks);
+    loop {
+        let candidate = *last.iter().min_by_key(|&&node| graph[node].len()).unwrap();
+        let (candidate_depth, candidate_last) = levels(graph, candidate, marks);
+        if candidate_depth <= depth {
+            return root;
+        }
+        root = candidate;
+        depth = candidate_depth;
+        last = candidate_last;
+    }
+}
+
+// returns the number of levels of the breadth first search from the root and the nodes of the last level,
+// the marks are false before and after so the search only costs the size of the component
+fn levels(graph: &[Vec<usize>], root: usize, marks: &mut [bool]) -> (usize, Vec<usize>) {
+    marks[root] = true;
+    let mut seen = vec![root];
+    let mut current = vec![root];
+    let mut depth = 0;
+    loop {
+        let mut next = Vec::new();
+        for &node in current.iter() {
+            for &neighbour in graph[node].iter() {
+                if !marks[neighbour] {
+                    marks[neighbour] = true;
+                    next.push(neighbour);
+                }
+            }
+        }
+        if next.is_empty() {
+            for node in seen {
+                marks[node] = false;
+            }
+            return (depth, current);
+        }
+        seen.extend_from_slice(&next);
+        current = next;
+        depth += 1;
+    }
+}
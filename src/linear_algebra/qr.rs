@@ -0,0 +1,249 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::Real;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [QR decomposition] `A = Q * R` of a m * n matrix with m >= n computed with [Householder reflections]
+///
+/// [QR decomposition]: https://en.wikipedia.org/wiki/QR_decomposition
+/// [Householder reflections]: https://en.wikipedia.org/wiki/Householder_transformation
+///
+/// Q has orthonormal columns and R is upper triangular,
+/// the thin variant has a m * n Q and a n * n R, the full variant a m * m Q and a m * n R
+pub struct QrDecomposition<T = f32> {
+    m: usize,
+    n: usize,
+    // the householder vectors stored row by row below and on the diagonal, the rest of R above it
+    qr: Vec<T>,
+    r_diag: Vec<T>,
+}
+
+impl<T: Real> QrDecomposition<T> {
+    /// decomposes the matrix where A has to have at least as many `cols()` as `rows()`
+    /// so `Matrix::new` has to get at least as many vecs as each vec is long
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, QrDecomposition};
+    /// let matrix = Matrix::new(vec![vec![3., 0.], vec![4., 5.], vec![0., 4.]]);
+    /// let qr = QrDecomposition::new(&matrix);
+    /// assert_eq!(qr.r(), Matrix::new(vec![vec![-5., -4.], vec![0., -5.]]));
+    /// ```
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix has less `cols()` than `rows()`,
+    /// the error expects at least `rows()` cols
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        let (m, n) = (matrix.cols(), matrix.rows());
+        if m < n {
+            return Err(MathError::ShapeMismatch {
+                expected: n,
+                actual: m,
+            });
+        }
+
+        let mut qr = matrix.matrix_flatt();
+        let mut r_diag = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let mut norm = (k..m).fold(T::zero(), |norm: T, i| norm.hypot(qr[i * n + k]));
+
+            if norm != T::zero() {
+                if qr[k * n + k] < T::zero() {
+                    norm = -norm;
+                }
+                for i in k..m {
+                    qr[i * n + k] /= norm;
+                }
+                qr[k * n + k] += T::one();
+
+                for j in (k + 1)..n {
+                    let s: T = (k..m).map(|i| qr[i * n + k] * qr[i * n + j]).sum();
+                    let s = -s / qr[k * n + k];
+                    for i in k..m {
+                        let v_ik = qr[i * n + k];
+                        qr[i * n + j] += s * v_ik;
+                    }
+                }
+            }
+            r_diag.push(-norm);
+        }
+
+        Ok(Self { m, n, qr, r_diag })
+    }
+
+    /// returns the thin m * n matrix Q with orthonormal columns
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![3., 0.], vec![4., 5.], vec![0., 4.]]);
+    /// let qr = matrix.qr();
+    /// assert_eq!(qr.q().cols(), 3);
+    /// assert_eq!(qr.q().rows(), 2);
+    /// ```
+    pub fn q(&self) -> Matrix<T> {
+        self.q_with_cols(self.n)
+    }
+
+    /// returns the full m * m orthogonal matrix Q
+    pub fn q_full(&self) -> Matrix<T> {
+        self.q_with_cols(self.m)
+    }
+
+    /// returns the thin n * n upper triangular matrix R
+    pub fn r(&self) -> Matrix<T> {
+        self.r_with_rows(self.n)
+    }
+
+    /// returns the full m * n upper triangular matrix R which is the thin R padded with zeros
+    pub fn r_full(&self) -> Matrix<T> {
+        self.r_with_rows(self.m)
+    }
+
+    /// returns true if no diagonal element of R is zero up to rounding errors
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+    /// assert_eq!(matrix.qr().is_full_rank(), false);
+    /// ```
+    pub fn is_full_rank(&self) -> bool {
+        let max = self
+            .r_diag
+            .iter()
+            .fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = T::from_usize(self.m) * T::epsilon() * max;
+        max != T::zero() && self.r_diag.iter().all(|x| x.abs() > tolerance)
+    }
+
+    /// returns the [least squares] solution x that minimizes `|A * x - b|`
+    ///
+    /// [least squares]: https://en.wikipedia.org/wiki/Least_squares
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// // fits the line y = a + b * x through the points (0, 1), (1, 3) and (2, 5)
+    /// let matrix = Matrix::new(vec![vec![1., 0.], vec![1., 1.], vec![1., 2.]]);
+    /// let x = matrix.qr().solve(&Vector::new(vec![1., 3., 5.]));
+    /// assert!((x.index(0) - 1.).abs() < 1e-5);
+    /// assert!((x.index(1) - 2.).abs() < 1e-5);
+    /// ```
+    /// note it panics if the matrix does not have full rank or b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if the matrix does not have full rank or b has the wrong len
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        if b.len() != self.m {
+            return Err(MathError::ShapeMismatch {
+                expected: self.m,
+                actual: b.len(),
+            });
+        }
+        let mut x = b.vec();
+        self.solve_in_place(&mut x, 1)?;
+        x.truncate(self.n);
+        Ok(Vector::from_vec(x))
+    }
+
+    /// returns the least squares solution X that minimizes `|A * X - B|` for every column of B
+    ///
+    /// note it panics if the matrix does not have full rank or B has the wrong shape
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.try_solve_mat(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat` but returns an error if the matrix does not have full rank or B has the wrong shape
+    pub fn try_solve_mat(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if b.cols() != self.m {
+            return Err(MathError::ShapeMismatch {
+                expected: self.m,
+                actual: b.cols(),
+            });
+        }
+        let k = b.rows();
+        let mut x = b.matrix_flatt();
+        self.solve_in_place(&mut x, k)?;
+        x.truncate(self.n * k);
+        Ok(Matrix::from_flatt(x, self.n, k))
+    }
+
+    // computes Q^T * B and solves the upper n * n part of R in place for the k columns of x
+    fn solve_in_place(&self, x: &mut [T], k: usize) -> Result<(), MathError> {
+        if !self.is_full_rank() {
+            return Err(MathError::Singular);
+        }
+
+        let (m, n) = (self.m, self.n);
+        for j in 0..n {
+            let v_jj = self.qr[j * n + j];
+            for c in 0..k {
+                let s: T = (j..m).map(|i| self.qr[i * n + j] * x[i * k + c]).sum();
+                let s = -s / v_jj;
+                for i in j..m {
+                    x[i * k + c] += s * self.qr[i * n + j];
+                }
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let r_ij = self.qr[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= r_ij * x[j * k + c];
+                }
+            }
+            for c in 0..k {
+                x[i * k + c] /= self.r_diag[i];
+            }
+        }
+        Ok(())
+    }
+
+    // applies the householder reflections in reverse order to the first p columns of the identity
+    fn q_with_cols(&self, p: usize) -> Matrix<T> {
+        let (m, n) = (self.m, self.n);
+        let mut q = vec![T::zero(); m * p];
+        for i in 0..p {
+            q[i * p + i] = T::one();
+        }
+
+        for k in (0..n).rev() {
+            let v_kk = self.qr[k * n + k];
+            if v_kk == T::zero() {
+                continue;
+            }
+            for j in 0..p {
+                let s: T = (k..m).map(|i| self.qr[i * n + k] * q[i * p + j]).sum();
+                let s = -s / v_kk;
+                for i in k..m {
+                    q[i * p + j] += s * self.qr[i * n + k];
+                }
+            }
+        }
+        Matrix::from_flatt(q, m, p)
+    }
+
+    fn r_with_rows(&self, p: usize) -> Matrix<T> {
+        let n = self.n;
+        let mut r = vec![T::zero(); p * n];
+        for i in 0..n {
+            r[i * n + i] = self.r_diag[i];
+            r[i * n + i + 1..(i + 1) * n].copy_from_slice(&self.qr[i * n + i + 1..(i + 1) * n]);
+        }
+        Matrix::from_flatt(r, p, n)
+    }
+}
@@ -0,0 +1,567 @@
+use crate::linear_algebra::{Mat3, MathError, Matrix, Vec3, Vector, VectorView};
+use crate::scalar::{Field, Real};
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// a [quaternion] `w + x * i + y * j + z * k` where `T` is `f32` by default
+///
+/// [quaternion]: https://en.wikipedia.org/wiki/Quaternion
+///
+/// a unit quaternion describes a rotation in 3D, `a * b` is the rotation `b` followed by `a`
+///
+/// note the functions that rotate or return a rotation matrix expect a unit quaternion,
+/// use `normalize()` after a lot of products to remove the rounding errors
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Quaternion, Vector};
+/// let rotation = Quaternion::from_axis_angle(&Vector::new(vec![0., 0., 1.]), std::f32::consts::FRAC_PI_2);
+/// let rotated = rotation.rotate_vec(&Vector::new(vec![1., 0., 0.]));
+/// assert!(rotated.dist(&Vector::new(vec![0., 1., 0.])) < 1e-6);
+/// ```
+pub struct Quaternion<T = f32> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the order of the axes of [Euler angles]
+///
+/// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+///
+/// the rotations are intrinsic, `XYZ` rotates about x then about the new y then about the new z
+/// which is the rotation matrix `Rx * Ry * Rz`, the angles are given in the order of the axes
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    // returns the indices of the axes and 1 if they are a cyclic permutation of xyz otherwise -1
+    fn axes(&self) -> (usize, usize, usize, f64) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2, 1.),
+            EulerOrder::XZY => (0, 2, 1, -1.),
+            EulerOrder::YXZ => (1, 0, 2, -1.),
+            EulerOrder::YZX => (1, 2, 0, 1.),
+            EulerOrder::ZXY => (2, 0, 1, 1.),
+            EulerOrder::ZYX => (2, 1, 0, -1.),
+        }
+    }
+}
+
+impl<T: Field> Quaternion<T> {
+    /// creates the quaternion `w + x * i + y * j + z * k`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Quaternion;
+    /// let i = Quaternion::new(0., 1., 0., 0.);
+    /// let j = Quaternion::new(0., 0., 1., 0.);
+    /// assert_eq!(i * j, Quaternion::new(0., 0., 0., 1.));
+    /// assert_eq!(j * i, Quaternion::new(0., 0., 0., -1.));
+    /// ```
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// the quaternion `1` which is the rotation that does nothing
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// returns the [conjugate] `w - x * i - y * j - z * k` which is the inverse rotation of a unit quaternion
+    ///
+    /// [conjugate]: https://en.wikipedia.org/wiki/Quaternion#Conjugation,_the_norm,_and_reciprocal
+    pub fn conj(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// returns the dot product of the 4 components
+    pub fn dot(&self, other: Quaternion<T>) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// returns the squared norm `w^2 + x^2 + y^2 + z^2` which needs no square root
+    pub fn norm_sqr(&self) -> T {
+        self.dot(*self)
+    }
+
+    /// multiplies all components with the scalar
+    pub fn scale(&self, scalar: T) -> Self {
+        Quaternion::new(
+            self.w * scalar,
+            self.x * scalar,
+            self.y * scalar,
+            self.z * scalar,
+        )
+    }
+
+    /// returns the multiplicative inverse `conj() / norm_sqr()`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Quaternion;
+    /// let q = Quaternion::new(1., 2., -2., 4.);
+    /// assert_eq!(q * q.inv(), Quaternion::identity());
+    /// ```
+    /// note for zero the components are infinite or NaN like the division of floats
+    pub fn inv(&self) -> Self {
+        let norm_sqr = self.norm_sqr();
+        let conj = self.conj();
+        Quaternion::new(
+            conj.w / norm_sqr,
+            conj.x / norm_sqr,
+            conj.y / norm_sqr,
+            conj.z / norm_sqr,
+        )
+    }
+}
+
+impl<T: Real> Quaternion<T> {
+    /// returns the norm also called length
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// returns the quaternion with the same direction but the norm 1
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// creates the rotation by `angle` in radians around the `axis` which does not have to be normalized
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Quaternion, Vec3};
+    /// let q = Quaternion::from_axis_angle(&Vec3::new([0., 2., 0.]), std::f32::consts::PI);
+    /// assert!((q.w).abs() < 1e-6);
+    /// assert_eq!(q.y, 1.);
+    /// ```
+    /// note it panics if the axis is not 3 dimensional or zero
+    pub fn from_axis_angle<'a>(axis: impl Into<VectorView<'a, T>>, angle: T) -> Self
+    where
+        T: 'a,
+    {
+        Quaternion::try_from_axis_angle(axis, angle).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_axis_angle` but returns an error if the axis is not 3 dimensional or zero
+    pub fn try_from_axis_angle<'a>(
+        axis: impl Into<VectorView<'a, T>>,
+        angle: T,
+    ) -> Result<Self, MathError>
+    where
+        T: 'a,
+    {
+        let axis = vec3(axis.into())?;
+        let mag = axis.mag();
+        if mag == T::zero() {
+            return Err(MathError::Singular);
+        }
+        let half = angle / T::from_f64(2.);
+        let axis = axis * (half.sin() / mag);
+        Ok(Quaternion::new(half.cos(), axis[0], axis[1], axis[2]))
+    }
+
+    /// returns the normalized axis and the angle in radians between 0 and 2 pi of the rotation
+    ///
+    /// the axis of the identity is `[1, 0, 0]`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Quaternion, Vector};
+    /// let axis = Vector::new(vec![0., 0., 1.]);
+    /// let (result, angle) = Quaternion::from_axis_angle(&axis, 1.5).to_axis_angle();
+    /// assert_eq!(result, axis);
+    /// assert!((angle - 1.5).abs() < 1e-6);
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vector<T>, T) {
+        let vector = Vec3::new([self.x, self.y, self.z]);
+        let sin = vector.mag();
+        let angle = T::from_f64(2.) * sin.atan2(self.w);
+        if sin == T::zero() {
+            (
+                Vector::from_vec(vec![T::one(), T::zero(), T::zero()]),
+                angle,
+            )
+        } else {
+            ((vector / sin).to_vector(), angle)
+        }
+    }
+
+    /// rotates the 3 dimensional vector `q * v * q^-1`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Quaternion, Vector};
+    /// let q = Quaternion::from_axis_angle(&Vector::new(vec![1., 0., 0.]), std::f32::consts::FRAC_PI_2);
+    /// let rotated = q.rotate_vec(&Vector::new(vec![0., 1., 0.]));
+    /// assert!(rotated.dist(&Vector::new(vec![0., 0., 1.])) < 1e-6);
+    /// ```
+    /// note this only works with 3 dimensional vectors
+    pub fn rotate_vec<'a>(&self, vector: impl Into<VectorView<'a, T>>) -> Vector<T>
+    where
+        T: 'a,
+    {
+        self.try_rotate_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `rotate_vec` but returns an error if the vector is not 3 dimensional
+    pub fn try_rotate_vec<'a>(
+        &self,
+        vector: impl Into<VectorView<'a, T>>,
+    ) -> Result<Vector<T>, MathError>
+    where
+        T: 'a,
+    {
+        Ok((*self * vec3(vector.into())?).to_vector())
+    }
+
+    /// returns the 3x3 [rotation matrix] `r` so that `r.dot_vec(v)` is `rotate_vec(v)`
+    ///
+    /// [rotation matrix]: https://en.wikipedia.org/wiki/Rotation_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Quaternion, Vector};
+    /// let q = Quaternion::new(0., 0., 0., 1.);
+    /// assert_eq!(
+    ///     q.to_rotation_matrix(),
+    ///     Matrix::new(vec![vec![-1., 0., 0.], vec![0., -1., 0.], vec![0., 0., 1.]])
+    /// );
+    /// ```
+    pub fn to_rotation_matrix(&self) -> Matrix<T> {
+        self.to_mat3().into()
+    }
+
+    /// same as `to_rotation_matrix` but returns a `Mat3` that is stored on the stack
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let Quaternion { w, x, y, z } = *self;
+        let one = T::one();
+        let two = T::from_f64(2.);
+        Mat3::new([
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// creates the unit quaternion from a 3x3 rotation matrix like it is returned by `to_rotation_matrix`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Quaternion, Vector};
+    /// let q = Quaternion::from_axis_angle(&Vector::new(vec![1., 1., 0.]), 2.);
+    /// let result = Quaternion::from_rotation_matrix(&q.to_rotation_matrix());
+    /// assert!((result - q).norm() < 1e-6);
+    /// ```
+    /// note the matrix has to be 3x3
+    pub fn from_rotation_matrix(matrix: &Matrix<T>) -> Self {
+        Quaternion::try_from_rotation_matrix(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `from_rotation_matrix` but returns an error if the matrix is not 3x3
+    pub fn try_from_rotation_matrix(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        Ok(Quaternion::from_mat3(Mat3::try_from(matrix)?))
+    }
+
+    /// same as `from_rotation_matrix` for a `Mat3`
+    ///
+    /// it uses the largest of the diagonal and the trace for the square root so it is stable for all rotations
+    pub fn from_mat3(m: Mat3<T>) -> Self {
+        let one = T::one();
+        let two = T::from_f64(2.);
+        let quarter = T::from_f64(0.25);
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                quarter * s,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = (one + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * two;
+            Quaternion::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                quarter * s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (one + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * two;
+            Quaternion::new(
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                quarter * s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = (one + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * two;
+            Quaternion::new(
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                quarter * s,
+            )
+        }
+    }
+
+    /// creates the rotation from the [Euler angles] in radians which are given in the order of the axes
+    ///
+    /// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{EulerOrder, Quaternion, Vector};
+    /// let q = Quaternion::from_euler([0.1, 0.2, 0.3], EulerOrder::ZYX);
+    /// let z = Quaternion::from_axis_angle(&Vector::new(vec![0., 0., 1.]), 0.1);
+    /// let y = Quaternion::from_axis_angle(&Vector::new(vec![0., 1., 0.]), 0.2);
+    /// let x = Quaternion::from_axis_angle(&Vector::new(vec![1., 0., 0.]), 0.3);
+    /// assert!((q - z * y * x).norm() < 1e-6);
+    /// ```
+    pub fn from_euler(angles: [T; 3], order: EulerOrder) -> Self {
+        let (i, j, k, _) = order.axes();
+        axis_rotation(i, angles[0]) * axis_rotation(j, angles[1]) * axis_rotation(k, angles[2])
+    }
+
+    /// returns the [Euler angles] in radians in the order of the axes
+    ///
+    /// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+    ///
+    /// the middle angle is between -pi/2 and pi/2 the others between -pi and pi,
+    /// in the gimbal lock where the middle angle is +-pi/2 the last angle is 0
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{EulerOrder, Quaternion};
+    /// let angles: [f32; 3] =
+    ///     Quaternion::from_euler([0.1, -0.2, 0.3], EulerOrder::YXZ).to_euler(EulerOrder::YXZ);
+    /// assert!((angles[0] - 0.1).abs() < 1e-6);
+    /// assert!((angles[1] + 0.2).abs() < 1e-6);
+    /// assert!((angles[2] - 0.3).abs() < 1e-6);
+    /// ```
+    pub fn to_euler(&self, order: EulerOrder) -> [T; 3] {
+        let (i, j, k, sign) = order.axes();
+        let sign = T::from_f64(sign);
+        let m = self.to_mat3();
+        let cos = m[(i, i)].hypot(m[(i, j)]);
+        let middle = (sign * m[(i, k)]).atan2(cos);
+        if cos > T::epsilon().sqrt() {
+            [
+                (-sign * m[(j, k)]).atan2(m[(k, k)]),
+                middle,
+                (-sign * m[(i, j)]).atan2(m[(i, i)]),
+            ]
+        } else {
+            [(sign * m[(k, j)]).atan2(m[(j, j)]), middle, T::zero()]
+        }
+    }
+
+    /// [spherical linear interpolation] between the unit quaternions, `t = 0` is `self` and `t = 1` is `other`
+    ///
+    /// [spherical linear interpolation]: https://en.wikipedia.org/wiki/Slerp
+    ///
+    /// it rotates with a constant angular velocity on the shorter path
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Quaternion, Vector};
+    /// let axis = Vector::new(vec![0., 1., 0.]);
+    /// let a = Quaternion::from_axis_angle(&axis, 0.2);
+    /// let b = Quaternion::from_axis_angle(&axis, 1.);
+    /// let result = a.slerp(b, 0.25);
+    /// assert!((result - Quaternion::from_axis_angle(&axis, 0.4)).norm() < 1e-6);
+    /// ```
+    pub fn slerp(&self, other: Quaternion<T>, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+        if cos < T::zero() {
+            other = -other;
+            cos = -cos;
+        }
+        // for almost equal rotations the sine is close to 0 and nlerp is more accurate
+        if cos > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(other, t);
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((T::one() - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        self.scale(a) + other.scale(b)
+    }
+
+    /// normalized linear interpolation, it is faster than `slerp` but the angular velocity is not constant
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Quaternion;
+    /// let a = Quaternion::identity();
+    /// let b = Quaternion::new(0., 0., 0., 1.);
+    /// let result = a.nlerp(b, 0.5);
+    /// assert!((result - a.slerp(b, 0.5)).norm() < 1e-6);
+    /// ```
+    pub fn nlerp(&self, other: Quaternion<T>, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        (self.scale(T::one() - t) + other.scale(t)).normalize()
+    }
+}
+
+// converts the view in to a `Vec3` or returns an error if it is not 3 dimensional
+fn vec3<T: Real>(vector: VectorView<T>) -> Result<Vec3<T>, MathError> {
+    if vector.len() != 3 {
+        return Err(MathError::ShapeMismatch {
+            expected: 3,
+            actual: vector.len(),
+        });
+    }
+    Ok(Vec3::from_fn(|i| vector.index(i)))
+}
+
+// returns the rotation by `angle` around the x, y or z axis
+fn axis_rotation<T: Real>(axis: usize, angle: T) -> Quaternion<T> {
+    let half = angle / T::from_f64(2.);
+    let mut vector = Vec3::zero();
+    vector[axis] = half.sin();
+    Quaternion::new(half.cos(), vector[0], vector[1], vector[2])
+}
+
+impl<T: Field> Add for Quaternion<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Quaternion::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+impl<T: Field> Sub for Quaternion<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Quaternion::new(
+            self.w - other.w,
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+        )
+    }
+}
+
+/// the [Hamilton product]
+///
+/// [Hamilton product]: https://en.wikipedia.org/wiki/Quaternion#Hamilton_product
+impl<T: Field> Mul for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Quaternion::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+}
+
+/// rotates the vector same as `rotate_vec`
+impl<T: Real> Mul<Vec3<T>> for Quaternion<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, vector: Vec3<T>) -> Vec3<T> {
+        // v + 2 * w * (u x v) + 2 * u x (u x v) with u = (x, y, z)
+        let u = Vec3::new([self.x, self.y, self.z]);
+        let t = u.cross_vec(vector) * T::from_f64(2.);
+        vector + t * self.w + u.cross_vec(t)
+    }
+}
+
+impl<T: Field> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Field> Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        self.scale(other)
+    }
+}
+
+impl<T: Field> Div<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        Quaternion::new(
+            self.w / other,
+            self.x / other,
+            self.y / other,
+            self.z / other,
+        )
+    }
+}
+
+impl<T: Field> AddAssign for Quaternion<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Field> SubAssign for Quaternion<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Field> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
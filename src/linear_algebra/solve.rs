@@ -0,0 +1,500 @@
+use crate::linear_algebra::{
+    CholeskyDecomposition, LuDecomposition, MathError, Matrix, QrDecomposition, Vector,
+};
+use crate::scalar::Real;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// the method that `Matrix::solve` picked for the structure of the matrix
+pub enum SolveMethod {
+    /// forward substitution for a square lower triangular matrix
+    LowerTriangular,
+    /// back substitution for a square upper triangular or diagonal matrix
+    UpperTriangular,
+    /// LU decomposition with partial pivoting that stays inside the band of a square banded matrix
+    Banded {
+        /// the number of nonzero diagonals below the main diagonal
+        lower: usize,
+        /// the number of nonzero diagonals above the main diagonal
+        upper: usize,
+    },
+    /// [Cholesky decomposition] of a symmetric positive definite matrix
+    ///
+    /// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+    Cholesky,
+    /// [LU decomposition] with partial pivoting of a general square matrix
+    ///
+    /// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+    Lu,
+    /// QR decomposition for the least squares solution of a system with more equations than unknowns
+    LeastSquares,
+    /// QR decomposition of the transpose for the minimum norm solution of a system with fewer equations than unknowns
+    MinimumNorm,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the solution of `Matrix::solve_refined` or `Matrix::solve_mat_refined` with the method and error estimates
+pub struct Solution<S, T = f32> {
+    solution: S,
+    method: SolveMethod,
+    residual: T,
+    backward_error: T,
+}
+
+impl<S, T: Real> Solution<S, T> {
+    /// returns the solution x
+    pub fn solution(&self) -> &S {
+        &self.solution
+    }
+
+    /// returns the solution x and drops the estimates
+    pub fn into_solution(self) -> S {
+        self.solution
+    }
+
+    /// returns the method that solved the system
+    pub fn method(&self) -> SolveMethod {
+        self.method
+    }
+
+    /// returns the euclidean norm of the residual `b - A * x`, for a matrix B it is the frobenius norm
+    pub fn residual(&self) -> T {
+        self.residual
+    }
+
+    /// returns the normwise [backward error] `|b - A * x| / (|A| * |x| + |b|)` in the infinity norm
+    ///
+    /// [backward error]: https://en.wikipedia.org/wiki/Numerical_stability#Forward,_backward,_and_mixed_stability
+    ///
+    /// it is the smallest relative change of A and b for which x is the exact solution,
+    /// for a matrix B it is the largest error of all columns
+    ///
+    /// note a least squares solution does not solve the system exactly so its backward error is not small
+    pub fn backward_error(&self) -> T {
+        self.backward_error
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// solves the linear system `A * x = b` with a method that fits the structure of the matrix
+    ///
+    /// - triangular matrices are solved by substitution
+    /// - banded matrices with a narrow band use an LU decomposition that stays inside the band
+    /// - symmetric positive definite matrices use the Cholesky decomposition
+    /// - other square matrices use the LU decomposition with partial pivoting
+    /// - matrices with more cols than rows get the least squares solution
+    /// - matrices with fewer cols than rows get the solution with the smallest norm
+    ///
+    /// this is faster and more accurate than multiplying with the inverse
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 1.], vec![1., 3.]]);
+    /// let x = matrix.solve(&Vector::new(vec![1., 2.]));
+    /// assert!((x.index(0) - 1. / 11.).abs() < 1e-6);
+    /// assert!((x.index(1) - 7. / 11.).abs() < 1e-6);
+    /// ```
+    /// note it panics if the matrix is singular, does not have full rank or b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if the matrix is singular, does not have full rank or b has the wrong len
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(matrix.try_solve(&Vector::new(vec![1., 2.])), Err(MathError::Singular));
+    /// ```
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        let x = self.try_solve_mat(&column(b))?;
+        Ok(Vector::from_vec(x.matrix_flatt()))
+    }
+
+    /// solves `A * X = B` for every column of B with the method that `solve` picks
+    ///
+    /// note it panics if the matrix is singular, does not have full rank or B has the wrong shape
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.try_solve_mat(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat` but returns an error if the matrix is singular, does not have full rank or B has the wrong shape
+    pub fn try_solve_mat(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        self.check_rhs(b)?;
+        Factorization::new(self)?.solve(b)
+    }
+
+    /// same as `solve` but improves x with up to `steps` rounds of [iterative refinement]
+    /// and returns it with the method and estimates of its error
+    ///
+    /// [iterative refinement]: https://en.wikipedia.org/wiki/Iterative_refinement
+    ///
+    /// every round solves `A * d = b - A * x` with the same decomposition and adds d to x,
+    /// it stops early once the residual does not get smaller
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, SolveMethod, Vector};
+    /// let matrix = Matrix::new(vec![vec![4., 1.], vec![1., 3.]]);
+    /// let solution = matrix.solve_refined(&Vector::new(vec![1., 2.]), 2);
+    /// assert_eq!(solution.method(), SolveMethod::Cholesky);
+    /// assert!(solution.backward_error() < 1e-6);
+    /// ```
+    /// note it panics if the matrix is singular, does not have full rank or b has the wrong len
+    pub fn solve_refined(&self, b: &Vector<T>, steps: usize) -> Solution<Vector<T>, T> {
+        self.try_solve_refined(b, steps)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_refined` but returns an error if the matrix is singular, does not have full rank or b has the wrong len
+    pub fn try_solve_refined(
+        &self,
+        b: &Vector<T>,
+        steps: usize,
+    ) -> Result<Solution<Vector<T>, T>, MathError> {
+        let solution = self.try_solve_mat_refined(&column(b), steps)?;
+        Ok(Solution {
+            solution: Vector::from_vec(solution.solution.matrix_flatt()),
+            method: solution.method,
+            residual: solution.residual,
+            backward_error: solution.backward_error,
+        })
+    }
+
+    /// same as `solve_mat` but improves X with up to `steps` rounds of iterative refinement,
+    /// see `solve_refined`
+    ///
+    /// note it panics if the matrix is singular, does not have full rank or B has the wrong shape
+    pub fn solve_mat_refined(&self, b: &Matrix<T>, steps: usize) -> Solution<Matrix<T>, T> {
+        self.try_solve_mat_refined(b, steps)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve_mat_refined` but returns an error if the matrix is singular, does not have full rank or B has the wrong shape
+    pub fn try_solve_mat_refined(
+        &self,
+        b: &Matrix<T>,
+        steps: usize,
+    ) -> Result<Solution<Matrix<T>, T>, MathError> {
+        self.check_rhs(b)?;
+        let factorization = Factorization::new(self)?;
+        let mut x = factorization.solve(b)?;
+        let mut r = self.residual(&x, b);
+        let mut norm = frobenius(&r);
+
+        for _ in 0..steps {
+            if norm == T::zero() {
+                break;
+            }
+            let d = factorization.solve(&Matrix::from_flatt(r.clone(), b.cols(), b.rows()))?;
+            let refined = Matrix::from_flatt(
+                x.matrix_flatt()
+                    .into_iter()
+                    .zip(d.matrix_flatt())
+                    .map(|(x, d)| x + d)
+                    .collect(),
+                x.cols(),
+                x.rows(),
+            );
+            let refined_r = self.residual(&refined, b);
+            let refined_norm = frobenius(&refined_r);
+            if refined_norm >= norm || refined_norm.is_nan() {
+                break;
+            }
+            x = refined;
+            r = refined_r;
+            norm = refined_norm;
+        }
+
+        let backward_error = self.backward_error(&x, b, &r);
+        Ok(Solution {
+            solution: x,
+            method: factorization.method(),
+            residual: norm,
+            backward_error,
+        })
+    }
+
+    fn check_rhs(&self, b: &Matrix<T>) -> Result<(), MathError> {
+        if b.cols() != self.cols() {
+            return Err(MathError::ShapeMismatch {
+                expected: self.cols(),
+                actual: b.cols(),
+            });
+        }
+        Ok(())
+    }
+
+    // returns `B - A * X` row by row
+    fn residual(&self, x: &Matrix<T>, b: &Matrix<T>) -> Vec<T> {
+        b.matrix_flatt()
+            .into_iter()
+            .zip(self.dot_mat(x).matrix_flatt())
+            .map(|(b, ax)| b - ax)
+            .collect()
+    }
+
+    // the largest normwise backward error of all columns in the infinity norm
+    fn backward_error(&self, x: &Matrix<T>, b: &Matrix<T>, r: &[T]) -> T {
+        let (m, n, k) = (self.cols(), self.rows(), b.rows());
+        let a = self.matrix_flatt();
+        let a_norm = (0..m)
+            .map(|i| a[i * n..(i + 1) * n].iter().map(|a| a.abs()).sum())
+            .fold(T::zero(), T::max);
+        let (x, b) = (x.matrix_flatt(), b.matrix_flatt());
+        let column_max = |values: &[T], len: usize, c: usize| {
+            (0..len)
+                .map(|i| values[i * k + c].abs())
+                .fold(T::zero(), T::max)
+        };
+
+        (0..k)
+            .map(|c| {
+                let scale = a_norm * column_max(&x, n, c) + column_max(&b, m, c);
+                if scale == T::zero() {
+                    T::zero()
+                } else {
+                    column_max(r, m, c) / scale
+                }
+            })
+            .fold(T::zero(), T::max)
+    }
+}
+
+// the vector as a matrix with a single col
+fn column<T: Real>(b: &Vector<T>) -> Matrix<T> {
+    Matrix::from_flatt(b.vec(), b.len(), 1)
+}
+
+fn frobenius<T: Real>(values: &[T]) -> T {
+    values.iter().map(|&x| x * x).sum::<T>().sqrt()
+}
+
+// the decomposition that `solve` picked, it is computed once and reused by the refinement
+enum Factorization<T> {
+    Triangular { a: Vec<T>, n: usize, lower: bool },
+    Banded(BandedLu<T>),
+    Cholesky(CholeskyDecomposition<T>),
+    Lu(LuDecomposition<T>),
+    LeastSquares(QrDecomposition<T>),
+    MinimumNorm(QrDecomposition<T>),
+}
+
+impl<T: Real> Factorization<T> {
+    fn new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        let (m, n) = (matrix.cols(), matrix.rows());
+        if m > n {
+            return Ok(Factorization::LeastSquares(matrix.try_qr()?));
+        }
+        if m < n {
+            let mut transpose = matrix.clone();
+            transpose.transpose();
+            return Ok(Factorization::MinimumNorm(transpose.try_qr()?));
+        }
+
+        let a = matrix.matrix_flatt();
+        let (lower, upper) = bandwidth(&a, n);
+        if lower == 0 || upper == 0 {
+            check_diagonal(&a, n)?;
+            return Ok(Factorization::Triangular {
+                a,
+                n,
+                lower: lower != 0,
+            });
+        }
+        // the band pays off once it covers less than half of every row
+        if 2 * (lower + upper + 1) <= n {
+            return Ok(Factorization::Banded(BandedLu::new(a, n, lower, upper)?));
+        }
+        if (0..n).all(|i| a[i * n + i] > T::zero()) && matrix.is_symmetric() {
+            // a symmetric matrix with a positive diagonal can still be indefinite
+            if let Ok(cholesky) = matrix.try_cholesky() {
+                return Ok(Factorization::Cholesky(cholesky));
+            }
+        }
+        Ok(Factorization::Lu(matrix.try_lu()?))
+    }
+
+    fn method(&self) -> SolveMethod {
+        match self {
+            Factorization::Triangular { lower: true, .. } => SolveMethod::LowerTriangular,
+            Factorization::Triangular { lower: false, .. } => SolveMethod::UpperTriangular,
+            Factorization::Banded(lu) => SolveMethod::Banded {
+                lower: lu.lower,
+                upper: lu.upper,
+            },
+            Factorization::Cholesky(_) => SolveMethod::Cholesky,
+            Factorization::Lu(_) => SolveMethod::Lu,
+            Factorization::LeastSquares(_) => SolveMethod::LeastSquares,
+            Factorization::MinimumNorm(_) => SolveMethod::MinimumNorm,
+        }
+    }
+
+    fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        match self {
+            Factorization::Triangular { a, n, lower } => {
+                let k = b.rows();
+                let mut x = b.matrix_flatt();
+                substitute(a, *n, *lower, &mut x, k);
+                Ok(Matrix::from_flatt(x, *n, k))
+            }
+            Factorization::Banded(lu) => Ok(lu.solve(b)),
+            Factorization::Cholesky(cholesky) => cholesky.try_solve_mat(b),
+            Factorization::Lu(lu) => lu.try_solve_mat(b),
+            Factorization::LeastSquares(qr) => qr.try_solve_mat(b),
+            Factorization::MinimumNorm(qr) => {
+                // A = R^T * Q^T so x = Q * R^-T * b is the solution in the row space of A
+                if !qr.is_full_rank() {
+                    return Err(MathError::Singular);
+                }
+                let r = qr.r().matrix_flatt();
+                let m = b.cols();
+                let r_transpose: Vec<T> = (0..m * m).map(|i| r[(i % m) * m + i / m]).collect();
+                let k = b.rows();
+                let mut y = b.matrix_flatt();
+                substitute(&r_transpose, m, true, &mut y, k);
+                Ok(qr.q().dot_mat(&Matrix::from_flatt(y, m, k)))
+            }
+        }
+    }
+}
+
+// the number of nonzero diagonals below and above the main diagonal
+fn bandwidth<T: Real>(a: &[T], n: usize) -> (usize, usize) {
+    let mut band = (0, 0);
+    for i in 0..n {
+        for j in 0..n {
+            if a[i * n + j] != T::zero() {
+                if i > j {
+                    band.0 = band.0.max(i - j);
+                } else {
+                    band.1 = band.1.max(j - i);
+                }
+            }
+        }
+    }
+    band
+}
+
+// a triangular matrix is singular if one entry of the diagonal vanishes
+fn check_diagonal<T: Real>(a: &[T], n: usize) -> Result<(), MathError> {
+    let max = a.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+    let tolerance = T::from_usize(n) * T::epsilon() * max;
+    if (0..n).any(|i| a[i * n + i].abs() <= tolerance) {
+        return Err(MathError::Singular);
+    }
+    Ok(())
+}
+
+// solves the triangular n * n matrix a in place for the k columns of x
+fn substitute<T: Real>(a: &[T], n: usize, lower: bool, x: &mut [T], k: usize) {
+    for step in 0..n {
+        let i = if lower { step } else { n - 1 - step };
+        let others = if lower { 0..i } else { (i + 1)..n };
+        for j in others {
+            let a_ij = a[i * n + j];
+            for c in 0..k {
+                let x_jc = x[j * k + c];
+                x[i * k + c] -= a_ij * x_jc;
+            }
+        }
+        let a_ii = a[i * n + i];
+        for c in 0..k {
+            x[i * k + c] /= a_ii;
+        }
+    }
+}
+
+// LU decomposition with partial pivoting of a banded matrix,
+// the pivoting widens the upper band of U to `lower + upper`
+struct BandedLu<T> {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    // L and U stored row by row in one n * n buffer, only the band is touched
+    lu: Vec<T>,
+    // the row that was swapped with row k in step k
+    pivots: Vec<usize>,
+}
+
+impl<T: Real> BandedLu<T> {
+    fn new(mut lu: Vec<T>, n: usize, lower: usize, upper: usize) -> Result<Self, MathError> {
+        let max = lu.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = T::from_usize(n) * T::epsilon() * max;
+        let mut pivots = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let last = (k + lower + 1).min(n);
+            let end = (k + lower + upper + 1).min(n);
+            let pivot_row = (k..last)
+                .max_by(|&i, &j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))
+                .unwrap();
+            pivots.push(pivot_row);
+            if pivot_row != k {
+                for j in k..end {
+                    lu.swap(k * n + j, pivot_row * n + j);
+                }
+            }
+
+            let pivot = lu[k * n + k];
+            if pivot.abs() <= tolerance {
+                return Err(MathError::Singular);
+            }
+            for i in (k + 1)..last {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in (k + 1)..end {
+                    let u_kj = lu[k * n + j];
+                    lu[i * n + j] -= factor * u_kj;
+                }
+            }
+        }
+
+        Ok(Self {
+            n,
+            lower,
+            upper,
+            lu,
+            pivots,
+        })
+    }
+
+    fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        let (n, k) = (self.n, b.rows());
+        let mut x = b.matrix_flatt();
+        for (step, &pivot_row) in self.pivots.iter().enumerate() {
+            if pivot_row != step {
+                for c in 0..k {
+                    x.swap(step * k + c, pivot_row * k + c);
+                }
+            }
+            for i in (step + 1)..(step + self.lower + 1).min(n) {
+                let l_ij = self.lu[i * n + step];
+                for c in 0..k {
+                    let x_step = x[step * k + c];
+                    x[i * k + c] -= l_ij * x_step;
+                }
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..(i + self.lower + self.upper + 1).min(n) {
+                let u_ij = self.lu[i * n + j];
+                for c in 0..k {
+                    let x_jc = x[j * k + c];
+                    x[i * k + c] -= u_ij * x_jc;
+                }
+            }
+            let u_ii = self.lu[i * n + i];
+            for c in 0..k {
+                x[i * k + c] /= u_ii;
+            }
+        }
+        Matrix::from_flatt(x, n, k)
+    }
+}
@@ -0,0 +1,705 @@
+use crate::linear_algebra::view::{check_index, check_same_len};
+use crate::linear_algebra::{LinearOperator, MathError, Matrix, Vector};
+use crate::scalar::Scalar;
+use std::ops::{Add, Mul, Range};
+
+#[derive(PartialEq, Clone, Debug)]
+/// a sparse matrix in the [coordinate format] that collects `(row, col, value)` triplets
+///
+/// [coordinate format]: https://en.wikipedia.org/wiki/Sparse_matrix#Coordinate_list_(COO)
+///
+/// it is cheap to build but slow to compute with, so it is meant to be converted
+/// with `to_csr` or `to_csc` which sum the values of duplicate positions
+///
+/// like for `Matrix` `cols()` is the number of rows `index` takes and `rows()` the number of cols
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{CooMatrix, Matrix};
+/// let mut coo = CooMatrix::new(2, 3);
+/// coo.push(0, 0, 1.);
+/// coo.push(1, 2, 2.);
+/// coo.push(0, 0, 3.);
+/// assert_eq!(coo.nnz(), 3);
+/// let csr = coo.to_csr();
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(
+///     csr.to_dense(),
+///     Matrix::new(vec![vec![4., 0., 0.], vec![0., 0., 2.]])
+/// );
+/// ```
+pub struct CooMatrix<T = f32> {
+    cols: usize,
+    rows: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    /// returns an empty sparse matrix with the shape of `Matrix::zero(cols, rows)`
+    pub fn new(cols: usize, rows: usize) -> Self {
+        CooMatrix {
+            cols,
+            rows,
+            entries: Vec::new(),
+        }
+    }
+
+    /// returns the number of rows that `push` takes
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// returns the number of cols that `push` takes
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the number of triplets including duplicates
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// returns the triplets `(row, col, value)` in the order they were pushed
+    pub fn entries(&self) -> &[(usize, usize, T)] {
+        &self.entries
+    }
+
+    /// adds the value at the position, values at the same position are summed up
+    ///
+    /// note it panics if the position is out of bounds
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        self.try_push(row, col, value)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `push` but returns an error if the position is out of bounds
+    pub fn try_push(&mut self, row: usize, col: usize, value: T) -> Result<(), MathError> {
+        check_index(row, self.cols)?;
+        check_index(col, self.rows)?;
+        self.entries.push((row, col, value));
+        Ok(())
+    }
+
+    /// returns the matrix in the CSR format
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            storage: Compressed::from_triplets(self.cols, self.rows, self.entries.iter().copied()),
+        }
+    }
+
+    /// returns the matrix in the CSC format
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|&(row, col, value)| (col, row, value));
+        CscMatrix {
+            storage: Compressed::from_triplets(self.rows, self.cols, entries),
+        }
+    }
+
+    /// returns the dense matrix
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut matrix = Matrix::zero(self.cols, self.rows);
+        for &(row, col, value) in self.entries.iter() {
+            matrix[(row, col)] += value;
+        }
+        matrix
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// a sparse matrix in the [compressed sparse row] format
+///
+/// [compressed sparse row]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
+///
+/// the cols of the nonzero values of row `i` are `indices()[offsets()[i]..offsets()[i + 1]]` in increasing order,
+/// it is the fast format for products with a vector
+///
+/// like for `Matrix` `cols()` is the number of rows `index` takes and `rows()` the number of cols
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{CsrMatrix, Matrix};
+/// let matrix = Matrix::new(vec![vec![1., 0., 2.], vec![0., 0., 3.]]);
+/// let csr = CsrMatrix::from_dense(&matrix);
+/// assert_eq!(csr.offsets(), &[0, 2, 3]);
+/// assert_eq!(csr.indices(), &[0, 2, 2]);
+/// assert_eq!(csr.values(), &[1., 2., 3.]);
+/// assert_eq!(csr.to_dense(), matrix);
+/// ```
+pub struct CsrMatrix<T = f32> {
+    storage: Compressed<T>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// a sparse matrix in the [compressed sparse column] format
+///
+/// [compressed sparse column]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_column_(CSC_or_CCS)
+///
+/// the rows of the nonzero values of col `j` are `indices()[offsets()[j]..offsets()[j + 1]]` in increasing order,
+/// it is the usual input of sparse factorizations
+///
+/// like for `Matrix` `cols()` is the number of rows `index` takes and `rows()` the number of cols
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{CscMatrix, Matrix};
+/// let matrix = Matrix::new(vec![vec![1., 0., 2.], vec![0., 0., 3.]]);
+/// let csc = CscMatrix::from_dense(&matrix);
+/// assert_eq!(csc.offsets(), &[0, 1, 1, 3]);
+/// assert_eq!(csc.indices(), &[0, 0, 1]);
+/// assert_eq!(csc.values(), &[1., 2., 3.]);
+/// ```
+pub struct CscMatrix<T = f32> {
+    storage: Compressed<T>,
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    // the storage is compressed along the first index
+    fn order(row: usize, col: usize) -> (usize, usize) {
+        (row, col)
+    }
+
+    /// returns the product of the matrix with the vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CsrMatrix, Matrix, Vector};
+    /// let csr = CsrMatrix::from_dense(&Matrix::new(vec![vec![1., 0., 2.], vec![0., 0., 3.]]));
+    /// assert_eq!(csr.dot_vec(&Vector::new(vec![1., 5., 1.])), Vector::new(vec![3., 3.]));
+    /// ```
+    /// note it panics if the len of the vector is not `rows()`
+    pub fn dot_vec(&self, vector: &Vector<T>) -> Vector<T> {
+        self.try_dot_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the len of the vector is not `rows()`
+    pub fn try_dot_vec(&self, vector: &Vector<T>) -> Result<Vector<T>, MathError> {
+        check_same_len(self.rows(), vector.len())?;
+        let x = vector.vec();
+        let storage = &self.storage;
+        Ok(Vector::from_vec(
+            (0..storage.outer)
+                .map(|row| {
+                    storage
+                        .range(row)
+                        .map(|k| storage.values[k] * x[storage.indices[k]])
+                        .sum()
+                })
+                .collect(),
+        ))
+    }
+
+    /// returns the sparse product of this matrix with the other matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CsrMatrix, Matrix};
+    /// let a = Matrix::new(vec![vec![1., 0.], vec![2., 3.]]);
+    /// let b = Matrix::new(vec![vec![0., 4.], vec![5., 0.]]);
+    /// let product = CsrMatrix::from_dense(&a).dot_mat(&CsrMatrix::from_dense(&b));
+    /// assert_eq!(product.to_dense(), a.dot_mat(&b));
+    /// ```
+    /// note it panics if the cols of the other matrix are not the rows of this matrix
+    pub fn dot_mat(&self, other: &Self) -> Self {
+        self.try_dot_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_mat` but returns an error if the cols of the other matrix are not the rows of this matrix
+    pub fn try_dot_mat(&self, other: &Self) -> Result<Self, MathError> {
+        check_same_len(self.rows(), other.cols())?;
+        Ok(CsrMatrix {
+            storage: self.storage.product(&other.storage),
+        })
+    }
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    // the storage is compressed along the second index
+    fn order(row: usize, col: usize) -> (usize, usize) {
+        (col, row)
+    }
+
+    /// returns the product of the matrix with the vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CscMatrix, Matrix, Vector};
+    /// let csc = CscMatrix::from_dense(&Matrix::new(vec![vec![1., 0., 2.], vec![0., 0., 3.]]));
+    /// assert_eq!(csc.dot_vec(&Vector::new(vec![1., 5., 1.])), Vector::new(vec![3., 3.]));
+    /// ```
+    /// note it panics if the len of the vector is not `rows()`
+    pub fn dot_vec(&self, vector: &Vector<T>) -> Vector<T> {
+        self.try_dot_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the len of the vector is not `rows()`
+    pub fn try_dot_vec(&self, vector: &Vector<T>) -> Result<Vector<T>, MathError> {
+        check_same_len(self.rows(), vector.len())?;
+        let x = vector.vec();
+        let storage = &self.storage;
+        let mut result = vec![T::zero(); storage.inner];
+        for (col, &x) in x.iter().enumerate() {
+            for k in storage.range(col) {
+                result[storage.indices[k]] += storage.values[k] * x;
+            }
+        }
+        Ok(Vector::from_vec(result))
+    }
+
+    /// returns the sparse product of this matrix with the other matrix
+    ///
+    /// note it panics if the cols of the other matrix are not the rows of this matrix
+    pub fn dot_mat(&self, other: &Self) -> Self {
+        self.try_dot_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_mat` but returns an error if the cols of the other matrix are not the rows of this matrix
+    pub fn try_dot_mat(&self, other: &Self) -> Result<Self, MathError> {
+        check_same_len(self.rows(), other.cols())?;
+        // the CSC storage of A * B is the CSR storage of B^T * A^T
+        Ok(CscMatrix {
+            storage: other.storage.product(&self.storage),
+        })
+    }
+}
+
+// implements the methods that only differ in the order of the indices for CSR and CSC
+macro_rules! impl_compressed {
+    ($name:ident, $other:ident, $convert:ident) => {
+        impl<T: Scalar> $name<T> {
+            /// returns the sparse matrix without nonzero values with the shape of `Matrix::zero(cols, rows)`
+            pub fn zero(cols: usize, rows: usize) -> Self {
+                let (outer, inner) = Self::order(cols, rows);
+                $name {
+                    storage: Compressed::zero(outer, inner),
+                }
+            }
+
+            /// builds the matrix from its raw arrays, see the description of the format
+            ///
+            /// the indices of one slice may be unsorted and duplicates are summed up
+            ///
+            /// note it panics if the arrays do not fit together or an index is out of bounds
+            pub fn from_parts(
+                cols: usize,
+                rows: usize,
+                offsets: Vec<usize>,
+                indices: Vec<usize>,
+                values: Vec<T>,
+            ) -> Self {
+                Self::try_from_parts(cols, rows, offsets, indices, values)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
+
+            /// same as `from_parts` but returns an error if the arrays do not fit together
+            /// or an index is out of bounds
+            pub fn try_from_parts(
+                cols: usize,
+                rows: usize,
+                offsets: Vec<usize>,
+                indices: Vec<usize>,
+                values: Vec<T>,
+            ) -> Result<Self, MathError> {
+                let (outer, inner) = Self::order(cols, rows);
+                Ok($name {
+                    storage: Compressed::try_from_parts(outer, inner, offsets, indices, values)?,
+                })
+            }
+
+            /// returns the sparse matrix with the nonzero values of the dense matrix
+            pub fn from_dense(matrix: &Matrix<T>) -> Self {
+                let (cols, rows) = (matrix.cols(), matrix.rows());
+                let (outer, inner) = Self::order(cols, rows);
+                let entries = (0..outer).flat_map(|o| (0..inner).map(move |i| (o, i)));
+                let entries = entries
+                    .map(|(o, i)| {
+                        let (row, col) = Self::order(o, i);
+                        (o, i, matrix.index(row, col))
+                    })
+                    .filter(|&(_, _, value)| value != T::zero());
+                $name {
+                    storage: Compressed::from_triplets(outer, inner, entries),
+                }
+            }
+
+            /// returns the dense matrix
+            pub fn to_dense(&self) -> Matrix<T> {
+                let mut matrix = Matrix::zero(self.cols(), self.rows());
+                let storage = &self.storage;
+                for o in 0..storage.outer {
+                    for k in storage.range(o) {
+                        matrix[Self::order(o, storage.indices[k])] = storage.values[k];
+                    }
+                }
+                matrix
+            }
+
+            /// returns the number of rows that `index` takes
+            pub fn cols(&self) -> usize {
+                Self::order(self.storage.outer, self.storage.inner).0
+            }
+
+            /// returns the number of cols that `index` takes
+            pub fn rows(&self) -> usize {
+                Self::order(self.storage.outer, self.storage.inner).1
+            }
+
+            /// returns the number of stored values
+            pub fn nnz(&self) -> usize {
+                self.storage.values.len()
+            }
+
+            /// returns where the slices of the compressed dimension start in `indices` and `values`
+            pub fn offsets(&self) -> &[usize] {
+                &self.storage.offsets
+            }
+
+            /// returns the uncompressed index of every stored value
+            pub fn indices(&self) -> &[usize] {
+                &self.storage.indices
+            }
+
+            /// returns the stored values
+            pub fn values(&self) -> &[T] {
+                &self.storage.values
+            }
+
+            /// returns the value at the position which is zero if it is not stored
+            ///
+            /// note it panics if the position is out of bounds
+            pub fn index(&self, row: usize, col: usize) -> T {
+                self.try_index(row, col)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
+
+            /// same as `index` but returns an error if the position is out of bounds
+            pub fn try_index(&self, row: usize, col: usize) -> Result<T, MathError> {
+                check_index(row, self.cols())?;
+                check_index(col, self.rows())?;
+                let (o, i) = Self::order(row, col);
+                Ok(self.storage.get(o, i))
+            }
+
+            /// returns the diagonal `index(i, i)` of the matrix
+            pub fn diagonal(&self) -> Vector<T> {
+                let len = self.storage.outer.min(self.storage.inner);
+                Vector::from_vec((0..len).map(|i| self.storage.get(i, i)).collect())
+            }
+
+            /// returns the transposed matrix
+            pub fn transpose(&self) -> Self {
+                $name {
+                    storage: self.storage.transpose(),
+                }
+            }
+
+            /// returns the same matrix in the other compressed format
+            pub fn $convert(&self) -> $other<T> {
+                $other {
+                    storage: self.storage.transpose(),
+                }
+            }
+
+            /// adds each component of the other matrix to this matrix
+            ///
+            /// note it panics if the matrices have not the same rows and cols
+            pub fn add_mat(&mut self, other: &Self) {
+                self.try_add_mat(other)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
+
+            /// same as `add_mat` but returns an error if the matrices have not the same rows and cols
+            pub fn try_add_mat(&mut self, other: &Self) -> Result<(), MathError> {
+                check_same_len(self.cols(), other.cols())?;
+                check_same_len(self.rows(), other.rows())?;
+                self.storage = self.storage.add(&other.storage);
+                Ok(())
+            }
+
+            /// multiplies each stored value with the scalar
+            pub fn mul_scalar(&mut self, scalar: &T) {
+                for value in self.storage.values.iter_mut() {
+                    *value *= *scalar;
+                }
+            }
+        }
+
+        impl<T: Scalar> Add for $name<T> {
+            type Output = Self;
+
+            fn add(mut self, other: Self) -> Self {
+                self.add_mat(&other);
+                self
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(mut self, scalar: T) -> Self {
+                self.mul_scalar(&scalar);
+                self
+            }
+        }
+
+        impl<T: Scalar> From<&Matrix<T>> for $name<T> {
+            fn from(matrix: &Matrix<T>) -> Self {
+                $name::from_dense(matrix)
+            }
+        }
+
+        impl<T: Scalar> From<&$name<T>> for Matrix<T> {
+            fn from(matrix: &$name<T>) -> Self {
+                matrix.to_dense()
+            }
+        }
+
+        impl<T: Scalar> LinearOperator<T> for $name<T> {
+            fn cols(&self) -> usize {
+                $name::cols(self)
+            }
+
+            fn rows(&self) -> usize {
+                $name::rows(self)
+            }
+
+            fn apply(&self, x: &Vector<T>) -> Vector<T> {
+                self.dot_vec(x)
+            }
+        }
+    };
+}
+
+impl_compressed!(CsrMatrix, CscMatrix, to_csc);
+impl_compressed!(CscMatrix, CsrMatrix, to_csr);
+
+impl<T: Scalar> From<&CooMatrix<T>> for CsrMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csr()
+    }
+}
+
+impl<T: Scalar> From<&CooMatrix<T>> for CscMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csc()
+    }
+}
+
+// the storage that CSR and CSC share, the values of every outer index are one slice
+// and their inner indices are sorted without duplicates
+#[derive(PartialEq, Clone, Debug)]
+struct Compressed<T> {
+    outer: usize,
+    inner: usize,
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> Compressed<T> {
+    fn zero(outer: usize, inner: usize) -> Self {
+        Compressed {
+            outer,
+            inner,
+            offsets: vec![0; outer + 1],
+            indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    // sorts the triplets `(outer, inner, value)` into slices and sums up duplicates,
+    // the indices have to be in bounds
+    fn from_triplets(
+        outer: usize,
+        inner: usize,
+        triplets: impl Iterator<Item = (usize, usize, T)>,
+    ) -> Self {
+        let triplets: Vec<(usize, usize, T)> = triplets.collect();
+        let mut starts = vec![0; outer + 1];
+        for &(o, _, _) in triplets.iter() {
+            starts[o + 1] += 1;
+        }
+        for o in 0..outer {
+            starts[o + 1] += starts[o];
+        }
+        let mut next = starts.clone();
+        let mut slots = vec![(0, T::zero()); triplets.len()];
+        for (o, i, value) in triplets {
+            slots[next[o]] = (i, value);
+            next[o] += 1;
+        }
+
+        let mut storage = Compressed::zero(outer, inner);
+        storage.offsets.truncate(1);
+        for o in 0..outer {
+            let slice = &mut slots[starts[o]..starts[o + 1]];
+            slice.sort_by_key(|&(i, _)| i);
+            let start = storage.indices.len();
+            for &(i, value) in slice.iter() {
+                if storage.indices.len() > start && storage.indices.last() == Some(&i) {
+                    *storage.values.last_mut().unwrap() += value;
+                } else {
+                    storage.indices.push(i);
+                    storage.values.push(value);
+                }
+            }
+            storage.offsets.push(storage.indices.len());
+        }
+        storage
+    }
+
+    fn try_from_parts(
+        outer: usize,
+        inner: usize,
+        offsets: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self, MathError> {
+        check_same_len(outer + 1, offsets.len())?;
+        check_same_len(indices.len(), values.len())?;
+        check_same_len(indices.len(), offsets[outer])?;
+        let mut start = 0;
+        for &end in offsets.iter() {
+            // an offset that is smaller than the one before points outside of its slice
+            if end < start {
+                return Err(MathError::IndexOutOfBounds {
+                    index: start,
+                    len: end,
+                });
+            }
+            start = end;
+        }
+        for &i in indices.iter() {
+            check_index(i, inner)?;
+        }
+
+        let triplets = (0..outer).flat_map(|o| (offsets[o]..offsets[o + 1]).map(move |k| (o, k)));
+        Ok(Compressed::from_triplets(
+            outer,
+            inner,
+            triplets.map(|(o, k)| (o, indices[k], values[k])),
+        ))
+    }
+
+    fn range(&self, o: usize) -> Range<usize> {
+        self.offsets[o]..self.offsets[o + 1]
+    }
+
+    fn get(&self, o: usize, i: usize) -> T {
+        let range = self.range(o);
+        match self.indices[range.clone()].binary_search(&i) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    // the storage of the transpose which is the same matrix compressed along the other dimension
+    fn transpose(&self) -> Self {
+        let mut offsets = vec![0; self.inner + 1];
+        for &i in self.indices.iter() {
+            offsets[i + 1] += 1;
+        }
+        for i in 0..self.inner {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![T::zero(); self.values.len()];
+        // walking the outer indices in order keeps the new slices sorted
+        for o in 0..self.outer {
+            for k in self.range(o) {
+                let i = self.indices[k];
+                indices[next[i]] = o;
+                values[next[i]] = self.values[k];
+                next[i] += 1;
+            }
+        }
+        Compressed {
+            outer: self.inner,
+            inner: self.outer,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    // the product of the outer * inner matrix with the other inner * other.inner matrix
+    // in the order of CSR with gustavson's algorithm
+    fn product(&self, other: &Self) -> Self {
+        let mut storage = Compressed::zero(self.outer, other.inner);
+        storage.offsets.truncate(1);
+        let mut work = vec![T::zero(); other.inner];
+        let mut last_outer = vec![usize::MAX; other.inner];
+
+        for o in 0..self.outer {
+            let start = storage.indices.len();
+            for k in self.range(o) {
+                let a = self.values[k];
+                let j = self.indices[k];
+                for l in other.range(j) {
+                    let i = other.indices[l];
+                    if last_outer[i] != o {
+                        last_outer[i] = o;
+                        work[i] = a * other.values[l];
+                        storage.indices.push(i);
+                    } else {
+                        work[i] += a * other.values[l];
+                    }
+                }
+            }
+            storage.indices[start..].sort_unstable();
+            for k in start..storage.indices.len() {
+                storage.values.push(work[storage.indices[k]]);
+            }
+            storage.offsets.push(storage.indices.len());
+        }
+        storage
+    }
+
+    // the componentwise sum which merges the sorted slices
+    fn add(&self, other: &Self) -> Self {
+        let mut storage = Compressed::zero(self.outer, self.inner);
+        storage.offsets.truncate(1);
+        for o in 0..self.outer {
+            let (mut a, mut b) = (self.range(o).peekable(), other.range(o).peekable());
+            loop {
+                let (i, value) = match (a.peek(), b.peek()) {
+                    (Some(&k), Some(&l)) if self.indices[k] == other.indices[l] => {
+                        a.next();
+                        b.next();
+                        (self.indices[k], self.values[k] + other.values[l])
+                    }
+                    (Some(&k), Some(&l)) if self.indices[k] < other.indices[l] => {
+                        a.next();
+                        (self.indices[k], self.values[k])
+                    }
+                    (_, Some(&l)) => {
+                        b.next();
+                        (other.indices[l], other.values[l])
+                    }
+                    (Some(&k), None) => {
+                        a.next();
+                        (self.indices[k], self.values[k])
+                    }
+                    (None, None) => break,
+                };
+                storage.indices.push(i);
+                storage.values.push(value);
+            }
+            storage.offsets.push(storage.indices.len());
+        }
+        storage
+    }
+}
@@ -0,0 +1,351 @@
+use crate::linear_algebra::{CscMatrix, FillOrdering, MathError, Vector};
+use crate::scalar::{Real, Scalar};
+
+// marks a node without a parent in the elimination tree
+const NONE: usize = usize::MAX;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the symbolic analysis of a sparse [Cholesky decomposition] that only depends on the nonzero pattern
+///
+/// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+///
+/// it computes the fill reducing ordering, the elimination tree and the pattern of L once,
+/// `factor` then decomposes every matrix with the same pattern without repeating that work
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{CscMatrix, FillOrdering, Matrix, SymbolicCholesky, Vector};
+/// let matrix = Matrix::new(vec![vec![4., 1., 0.], vec![1., 4., 1.], vec![0., 1., 4.]]);
+/// let symbolic = SymbolicCholesky::new(&CscMatrix::from_dense(&matrix), FillOrdering::MinimumDegree);
+/// for scale in 1..4 {
+///     let cholesky = symbolic.factor(&CscMatrix::from_dense(&(matrix.clone() * scale as f32)));
+///     let x = cholesky.solve(&Vector::new(vec![5., 6., 5.]));
+///     assert!((x.index(1) - 1. / scale as f32).abs() < 1e-5);
+/// }
+/// ```
+pub struct SymbolicCholesky {
+    n: usize,
+    permutation: Vec<usize>,
+    // the pattern of the analyzed matrix
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    // the position of the transposed entry for every stored value
+    mirror: Vec<usize>,
+    // the upper triangle of the permuted matrix `C = P * A * P^T` by cols
+    // and the position in it of every stored value of the matrix or NONE for the lower triangle
+    upper_offsets: Vec<usize>,
+    upper_indices: Vec<usize>,
+    upper_map: Vec<usize>,
+    parent: Vec<usize>,
+    l_offsets: Vec<usize>,
+}
+
+impl SymbolicCholesky {
+    /// analyzes the nonzero pattern of the symmetric matrix with the fill reducing ordering
+    ///
+    /// note it panics if the matrix is not square or its pattern is not symmetric
+    pub fn new<T: Scalar>(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Self {
+        Self::try_new(matrix, ordering).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not square or its pattern is not symmetric
+    pub fn try_new<T: Scalar>(
+        matrix: &CscMatrix<T>,
+        ordering: FillOrdering,
+    ) -> Result<Self, MathError> {
+        let permutation = ordering.try_permutation(matrix)?;
+        let n = permutation.len();
+        let (offsets, indices) = (matrix.offsets(), matrix.indices());
+
+        let mut mirror = vec![0; indices.len()];
+        for col in 0..n {
+            for k in offsets[col]..offsets[col + 1] {
+                let row = indices[k];
+                let range = offsets[row]..offsets[row + 1];
+                match indices[range.clone()].binary_search(&col) {
+                    Ok(position) => mirror[k] = range.start + position,
+                    Err(_) => return Err(MathError::NotSymmetric),
+                }
+            }
+        }
+
+        let mut inverse = vec![0; n];
+        for (k, &i) in permutation.iter().enumerate() {
+            inverse[i] = k;
+        }
+        let mut upper_cols = vec![Vec::new(); n];
+        let mut upper_map = vec![NONE; indices.len()];
+        for col in 0..n {
+            for k in offsets[col]..offsets[col + 1] {
+                let (i, j) = (inverse[indices[k]], inverse[col]);
+                if i <= j {
+                    upper_cols[j].push((i, k));
+                }
+            }
+        }
+        let mut upper_offsets = vec![0];
+        let mut upper_indices = Vec::new();
+        for col in upper_cols.iter_mut() {
+            col.sort_unstable();
+            for &(i, k) in col.iter() {
+                upper_map[k] = upper_indices.len();
+                upper_indices.push(i);
+            }
+            upper_offsets.push(upper_indices.len());
+        }
+
+        let mut symbolic = SymbolicCholesky {
+            n,
+            permutation,
+            offsets: offsets.to_vec(),
+            indices: indices.to_vec(),
+            mirror,
+            upper_offsets,
+            upper_indices,
+            upper_map,
+            parent: Vec::new(),
+            l_offsets: Vec::new(),
+        };
+        symbolic.parent = symbolic.elimination_tree();
+
+        // the row patterns of L give the number of values in every col
+        let mut counts = vec![1; n];
+        let mut marks = vec![NONE; n];
+        for k in 0..n {
+            for i in symbolic.row_pattern(k, &mut marks) {
+                counts[i] += 1;
+            }
+        }
+        symbolic.l_offsets = vec![0];
+        for count in counts {
+            let last = symbolic.l_offsets[symbolic.l_offsets.len() - 1];
+            symbolic.l_offsets.push(last + count);
+        }
+        Ok(symbolic)
+    }
+
+    /// returns the fill reducing permutation p, row and col `k` of the decomposed matrix are row and col `p[k]`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// returns the number of stored values of L including the diagonal
+    pub fn nnz(&self) -> usize {
+        self.l_offsets[self.n]
+    }
+
+    /// decomposes the symmetric positive definite matrix which has to have the analyzed pattern
+    ///
+    /// note it panics if the pattern differs, the matrix is not symmetric or not positive definite
+    pub fn factor<T: Real>(&self, matrix: &CscMatrix<T>) -> SparseCholesky<T> {
+        self.try_factor(matrix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `factor` but returns an error if the pattern differs,
+    /// the matrix is not symmetric or not positive definite
+    pub fn try_factor<T: Real>(
+        &self,
+        matrix: &CscMatrix<T>,
+    ) -> Result<SparseCholesky<T>, MathError> {
+        if matrix.cols() != self.n
+            || matrix.offsets() != &self.offsets[..]
+            || matrix.indices() != &self.indices[..]
+        {
+            return Err(MathError::PatternMismatch);
+        }
+        let values = matrix.values();
+        let max = values.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = T::from_usize(self.n) * T::epsilon() * max;
+        if (0..values.len()).any(|k| (values[k] - values[self.mirror[k]]).abs() > tolerance) {
+            return Err(MathError::NotSymmetric);
+        }
+        let mut upper_values = vec![T::zero(); self.upper_indices.len()];
+        for (k, &position) in self.upper_map.iter().enumerate() {
+            if position != NONE {
+                upper_values[position] = values[k];
+            }
+        }
+
+        // the up looking algorithm computes row k of L from the rows before it
+        let n = self.n;
+        let mut next = self.l_offsets[..n].to_vec();
+        let mut l_indices = vec![0; self.nnz()];
+        let mut l_values = vec![T::zero(); self.nnz()];
+        let mut x = vec![T::zero(); n];
+        let mut marks = vec![NONE; n];
+        for k in 0..n {
+            let pattern = self.row_pattern(k, &mut marks);
+            for p in self.upper_offsets[k]..self.upper_offsets[k + 1] {
+                x[self.upper_indices[p]] = upper_values[p];
+            }
+            let mut d = x[k];
+            x[k] = T::zero();
+            for i in pattern {
+                let l_ki = x[i] / l_values[self.l_offsets[i]];
+                x[i] = T::zero();
+                for p in (self.l_offsets[i] + 1)..next[i] {
+                    x[l_indices[p]] -= l_values[p] * l_ki;
+                }
+                d -= l_ki * l_ki;
+                l_indices[next[i]] = k;
+                l_values[next[i]] = l_ki;
+                next[i] += 1;
+            }
+            if d <= T::zero() {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            l_indices[next[k]] = k;
+            l_values[next[k]] = d.sqrt();
+            next[k] += 1;
+        }
+
+        Ok(SparseCholesky {
+            permutation: self.permutation.clone(),
+            l: CscMatrix::from_parts(n, n, self.l_offsets.clone(), l_indices, l_values),
+        })
+    }
+
+    // the parent of every col of L in the elimination tree computed from the upper triangle of C
+    fn elimination_tree(&self) -> Vec<usize> {
+        let mut parent = vec![NONE; self.n];
+        let mut ancestor = vec![NONE; self.n];
+        for k in 0..self.n {
+            for p in self.upper_offsets[k]..self.upper_offsets[k + 1] {
+                // follows the path to the root and compresses it to k
+                let mut i = self.upper_indices[p];
+                while i != NONE && i < k {
+                    let next = ancestor[i];
+                    ancestor[i] = k;
+                    if next == NONE {
+                        parent[i] = k;
+                    }
+                    i = next;
+                }
+            }
+        }
+        parent
+    }
+
+    // the cols of the nonzero values of row k of L left of the diagonal in an order
+    // where every col comes after the cols it depends on, the marks have to be NONE or smaller than k
+    fn row_pattern(&self, k: usize, marks: &mut [usize]) -> Vec<usize> {
+        marks[k] = k;
+        let mut paths: Vec<Vec<usize>> = Vec::new();
+        for p in self.upper_offsets[k]..self.upper_offsets[k + 1] {
+            // every col in the row is a descendant of k so the path up the tree ends at k
+            let mut i = self.upper_indices[p];
+            let mut path = Vec::new();
+            while marks[i] != k {
+                path.push(i);
+                marks[i] = k;
+                i = self.parent[i];
+            }
+            paths.push(path);
+        }
+        paths.into_iter().rev().flatten().collect()
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the sparse [Cholesky decomposition] `P * A * P^T = L * L^T` of a symmetric positive definite matrix
+///
+/// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+///
+/// P is the fill reducing permutation of the `SymbolicCholesky` and L is lower triangular
+pub struct SparseCholesky<T = f32> {
+    permutation: Vec<usize>,
+    l: CscMatrix<T>,
+}
+
+impl<T: Real> SparseCholesky<T> {
+    /// analyzes and decomposes the symmetric positive definite matrix,
+    /// use `SymbolicCholesky` to decompose several matrices with the same pattern
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CscMatrix, FillOrdering, Matrix, SparseCholesky};
+    /// let matrix = CscMatrix::from_dense(&Matrix::new(vec![vec![4., 2.], vec![2., 5.]]));
+    /// let cholesky = SparseCholesky::new(&matrix, FillOrdering::Natural);
+    /// assert_eq!(
+    ///     cholesky.l().to_dense(),
+    ///     Matrix::new(vec![vec![2., 0.], vec![1., 2.]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is not symmetric or not positive definite
+    pub fn new(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Self {
+        Self::try_new(matrix, ordering).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not symmetric or not positive definite
+    pub fn try_new(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Result<Self, MathError> {
+        SymbolicCholesky::try_new(matrix, ordering)?.try_factor(matrix)
+    }
+
+    /// returns the lower triangular matrix L of the permuted matrix
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    /// returns the fill reducing permutation p, row and col `k` of the decomposed matrix are row and col `p[k]`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// solves `A * x = b` with forward and back substitution
+    ///
+    /// note it panics if b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if b has the wrong len
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        let n = self.permutation.len();
+        if b.len() != n {
+            return Err(MathError::ShapeMismatch {
+                expected: n,
+                actual: b.len(),
+            });
+        }
+        let (offsets, indices, values) = (self.l.offsets(), self.l.indices(), self.l.values());
+        let b = b.vec();
+        let mut y: Vec<T> = self.permutation.iter().map(|&i| b[i]).collect();
+        // the diagonal is the first value of every col
+        for j in 0..n {
+            y[j] /= values[offsets[j]];
+            let y_j = y[j];
+            for p in (offsets[j] + 1)..offsets[j + 1] {
+                y[indices[p]] -= values[p] * y_j;
+            }
+        }
+        for j in (0..n).rev() {
+            for p in (offsets[j] + 1)..offsets[j + 1] {
+                let y_i = y[indices[p]];
+                y[j] -= values[p] * y_i;
+            }
+            y[j] /= values[offsets[j]];
+        }
+        let mut x = vec![T::zero(); n];
+        for (k, &i) in self.permutation.iter().enumerate() {
+            x[i] = y[k];
+        }
+        Ok(Vector::from_vec(x))
+    }
+}
+
+impl<T: Real> CscMatrix<T> {
+    /// returns the sparse Cholesky decomposition of this symmetric positive definite matrix,
+    /// see `SparseCholesky::new`
+    pub fn cholesky(&self, ordering: FillOrdering) -> SparseCholesky<T> {
+        SparseCholesky::new(self, ordering)
+    }
+
+    /// same as `cholesky` but returns an error instead of panicking, see `SparseCholesky::try_new`
+    pub fn try_cholesky(&self, ordering: FillOrdering) -> Result<SparseCholesky<T>, MathError> {
+        SparseCholesky::try_new(self, ordering)
+    }
+}
@@ -0,0 +1,323 @@
+use crate::linear_algebra::{CscMatrix, FillOrdering, MathError, Vector};
+use crate::scalar::{Real, Scalar};
+
+// marks a row that is not a pivot row yet
+const NONE: usize = usize::MAX;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the symbolic analysis of a sparse [LU decomposition] that only depends on the nonzero pattern
+///
+/// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+///
+/// it computes the fill reducing col ordering once, the ordering is applied to the pattern of `A + A^T`
+/// so it works best if the diagonal is nonzero and the rows do not need to be exchanged much,
+/// `factor` then decomposes every matrix with the same pattern
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{CscMatrix, FillOrdering, Matrix, SymbolicLu, Vector};
+/// let matrix = Matrix::new(vec![vec![0., 2., 0.], vec![1., 0., 3.], vec![0., 4., 1.]]);
+/// let symbolic = SymbolicLu::new(&CscMatrix::from_dense(&matrix), FillOrdering::Natural);
+/// for scale in 1..4 {
+///     let lu = symbolic.factor(&CscMatrix::from_dense(&(matrix.clone() * scale as f32)));
+///     let x = lu.solve(&Vector::new(vec![2., 4., 5.]));
+///     assert!((x.index(1) - 1. / scale as f32).abs() < 1e-5);
+/// }
+/// ```
+pub struct SymbolicLu {
+    n: usize,
+    cols: Vec<usize>,
+    // the pattern of the analyzed matrix
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+}
+
+impl SymbolicLu {
+    /// analyzes the nonzero pattern of the square matrix with the fill reducing ordering
+    ///
+    /// note it panics if the matrix is not square
+    pub fn new<T: Scalar>(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Self {
+        Self::try_new(matrix, ordering).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not square
+    pub fn try_new<T: Scalar>(
+        matrix: &CscMatrix<T>,
+        ordering: FillOrdering,
+    ) -> Result<Self, MathError> {
+        let cols = ordering.try_permutation(matrix)?;
+        Ok(SymbolicLu {
+            n: cols.len(),
+            cols,
+            offsets: matrix.offsets().to_vec(),
+            indices: matrix.indices().to_vec(),
+        })
+    }
+
+    /// returns the col permutation q, col `k` of the decomposed matrix is col `q[k]`
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.cols
+    }
+
+    /// decomposes the matrix which has to have the analyzed pattern with partial pivoting
+    ///
+    /// note it panics if the pattern differs or the matrix is singular
+    pub fn factor<T: Real>(&self, matrix: &CscMatrix<T>) -> SparseLu<T> {
+        self.try_factor(matrix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `factor` but returns an error if the pattern differs or the matrix is singular
+    pub fn try_factor<T: Real>(&self, matrix: &CscMatrix<T>) -> Result<SparseLu<T>, MathError> {
+        if matrix.cols() != self.n
+            || matrix.offsets() != &self.offsets[..]
+            || matrix.indices() != &self.indices[..]
+        {
+            return Err(MathError::PatternMismatch);
+        }
+        let n = self.n;
+        let values = matrix.values();
+        let max = values.iter().fold(T::zero(), |max: T, x| max.max(x.abs()));
+        let tolerance = T::from_usize(n) * T::epsilon() * max;
+
+        // the left looking algorithm of Gilbert and Peierls solves `L * x = A(:, q[k])`
+        // for every col and only touches the values that are reachable in the graph of L
+        let mut pinv = vec![NONE; n];
+        let (mut l_offsets, mut l_indices, mut l_values) = (vec![0], Vec::new(), Vec::new());
+        let (mut u_offsets, mut u_indices, mut u_values) = (vec![0], Vec::new(), Vec::new());
+        let mut x = vec![T::zero(); n];
+        let mut marks = vec![NONE; n];
+        for k in 0..n {
+            let col = self.cols[k];
+            let range = self.offsets[col]..self.offsets[col + 1];
+            let reach = reach(
+                &self.indices[range.clone()],
+                k,
+                &pinv,
+                &l_offsets,
+                &l_indices,
+                &mut marks,
+            );
+            for p in range {
+                x[self.indices[p]] = values[p];
+            }
+
+            // the rows that are already pivot rows go into U, the others are the candidates for the pivot
+            // where the partial pivoting keeps the diagonal if it is as large as the largest candidate
+            let mut pivot = NONE;
+            let mut largest = T::zero();
+            for &i in reach.iter() {
+                let j = pinv[i];
+                if j == NONE {
+                    let value = x[i].abs();
+                    if value > largest || (i == col && value >= largest) {
+                        pivot = i;
+                        largest = value;
+                    }
+                    continue;
+                }
+                let x_i = x[i];
+                // the unit diagonal is the first value of every col of L
+                for p in (l_offsets[j] + 1)..l_offsets[j + 1] {
+                    x[l_indices[p]] -= l_values[p] * x_i;
+                }
+                u_indices.push(j);
+                u_values.push(x_i);
+            }
+            if pivot == NONE || largest <= tolerance {
+                return Err(MathError::Singular);
+            }
+            let value = x[pivot];
+            pinv[pivot] = k;
+            u_indices.push(k);
+            u_values.push(value);
+            u_offsets.push(u_indices.len());
+            l_indices.push(pivot);
+            l_values.push(T::one());
+            for &i in reach.iter() {
+                if pinv[i] == NONE {
+                    l_indices.push(i);
+                    l_values.push(x[i] / value);
+                }
+                x[i] = T::zero();
+            }
+            l_offsets.push(l_indices.len());
+        }
+
+        // the rows of L are renumbered to the pivot order at the end
+        for i in l_indices.iter_mut() {
+            *i = pinv[*i];
+        }
+        let mut rows = vec![0; n];
+        for (i, &k) in pinv.iter().enumerate() {
+            rows[k] = i;
+        }
+        Ok(SparseLu {
+            rows,
+            cols: self.cols.clone(),
+            l: CscMatrix::from_parts(n, n, l_offsets, l_indices, l_values),
+            u: CscMatrix::from_parts(n, n, u_offsets, u_indices, u_values),
+        })
+    }
+}
+
+// the rows of the nonzero values of `L^-1 * b` for the rows of b in topological order,
+// a depth first search in the graph of L where a pivot row points to the rows of its col,
+// the marks have to be NONE or smaller than k
+fn reach(
+    rows: &[usize],
+    k: usize,
+    pinv: &[usize],
+    l_offsets: &[usize],
+    l_indices: &[usize],
+    marks: &mut [usize],
+) -> Vec<usize> {
+    let mut postorder = Vec::new();
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    for &row in rows {
+        if marks[row] == k {
+            continue;
+        }
+        marks[row] = k;
+        stack.push((row, 0));
+        while let Some(&mut (i, ref mut next)) = stack.last_mut() {
+            let j = pinv[i];
+            let children = if j == NONE {
+                &l_indices[0..0]
+            } else {
+                &l_indices[(l_offsets[j] + 1)..l_offsets[j + 1]]
+            };
+            match children[*next..]
+                .iter()
+                .position(|&child| marks[child] != k)
+            {
+                Some(position) => {
+                    let child = children[*next + position];
+                    *next += position + 1;
+                    marks[child] = k;
+                    stack.push((child, 0));
+                }
+                None => {
+                    stack.pop();
+                    postorder.push(i);
+                }
+            }
+        }
+    }
+    postorder.reverse();
+    postorder
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// the sparse [LU decomposition] `P * A * Q = L * U` with partial pivoting
+///
+/// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+///
+/// Q is the fill reducing col permutation of the `SymbolicLu`, P the row permutation of the pivoting,
+/// L is lower triangular with a unit diagonal and U is upper triangular
+pub struct SparseLu<T = f32> {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    l: CscMatrix<T>,
+    u: CscMatrix<T>,
+}
+
+impl<T: Real> SparseLu<T> {
+    /// analyzes and decomposes the square matrix,
+    /// use `SymbolicLu` to decompose several matrices with the same pattern
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{CscMatrix, FillOrdering, Matrix, SparseLu};
+    /// let matrix = CscMatrix::from_dense(&Matrix::new(vec![vec![0., 1.], vec![2., 3.]]));
+    /// let lu = SparseLu::new(&matrix, FillOrdering::Natural);
+    /// // the zero on the diagonal swaps the rows
+    /// assert_eq!(lu.row_permutation(), &[1, 0]);
+    /// assert_eq!(lu.u().to_dense(), Matrix::new(vec![vec![2., 3.], vec![0., 1.]]));
+    /// ```
+    /// note it panics if the matrix is not square or singular
+    pub fn new(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Self {
+        Self::try_new(matrix, ordering).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the matrix is not square or singular
+    pub fn try_new(matrix: &CscMatrix<T>, ordering: FillOrdering) -> Result<Self, MathError> {
+        SymbolicLu::try_new(matrix, ordering)?.try_factor(matrix)
+    }
+
+    /// returns the lower triangular matrix L with a unit diagonal
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    /// returns the upper triangular matrix U
+    pub fn u(&self) -> &CscMatrix<T> {
+        &self.u
+    }
+
+    /// returns the row permutation p of the pivoting, row `k` of the decomposed matrix is row `p[k]`
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// returns the col permutation q of the ordering, col `k` of the decomposed matrix is col `q[k]`
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.cols
+    }
+
+    /// solves `A * x = b` with forward and back substitution
+    ///
+    /// note it panics if b has the wrong len
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        self.try_solve(b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `solve` but returns an error if b has the wrong len
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+        let n = self.rows.len();
+        if b.len() != n {
+            return Err(MathError::ShapeMismatch {
+                expected: n,
+                actual: b.len(),
+            });
+        }
+        let b = b.vec();
+        let mut y: Vec<T> = self.rows.iter().map(|&i| b[i]).collect();
+        // the diagonal is the first value of every col of L and the last of every col of U
+        let (offsets, indices, values) = (self.l.offsets(), self.l.indices(), self.l.values());
+        for j in 0..n {
+            let y_j = y[j];
+            for p in (offsets[j] + 1)..offsets[j + 1] {
+                y[indices[p]] -= values[p] * y_j;
+            }
+        }
+        let (offsets, indices, values) = (self.u.offsets(), self.u.indices(), self.u.values());
+        for j in (0..n).rev() {
+            y[j] /= values[offsets[j + 1] - 1];
+            let y_j = y[j];
+            for p in offsets[j]..(offsets[j + 1] - 1) {
+                y[indices[p]] -= values[p] * y_j;
+            }
+        }
+        let mut x = vec![T::zero(); n];
+        for (k, &i) in self.cols.iter().enumerate() {
+            x[i] = y[k];
+        }
+        Ok(Vector::from_vec(x))
+    }
+}
+
+impl<T: Real> CscMatrix<T> {
+    /// returns the sparse LU decomposition of this square matrix, see `SparseLu::new`
+    pub fn lu(&self, ordering: FillOrdering) -> SparseLu<T> {
+        SparseLu::new(self, ordering)
+    }
+
+    /// same as `lu` but returns an error instead of panicking, see `SparseLu::try_new`
+    pub fn try_lu(&self, ordering: FillOrdering) -> Result<SparseLu<T>, MathError> {
+        SparseLu::try_new(self, ordering)
+    }
+}
@@ -0,0 +1,366 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::Real;
+
+// the one sided jacobi method converges quadratically so this is never reached in practice
+const MAX_SWEEPS: usize = 64;
+
+#[derive(PartialEq, Clone, Debug)]
+/// the [singular value decomposition] `A = U * Σ * V^T` of a m * n matrix
+///
+/// [singular value decomposition]: https://en.wikipedia.org/wiki/Singular_value_decomposition
+///
+/// the singular values in Σ are sorted from the largest to the smallest,
+/// with r = min(m, n) the thin variant has a m * r U, a r * r Σ and a r * n V^T
+/// and the full variant a m * m U, a m * n Σ and a n * n V^T
+pub struct Svd<T = f32> {
+    m: usize,
+    n: usize,
+    // U and V stored row by row with one column for each singular value
+    u: Vec<T>,
+    s: Vec<T>,
+    v: Vec<T>,
+}
+
+impl<T: Real> Svd<T> {
+    /// decomposes the matrix with the one sided [Jacobi method]
+    ///
+    /// [Jacobi method]: https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm#Applications_for_real_symmetric_matrices
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Svd, Vector};
+    /// let matrix = Matrix::new(vec![vec![3., 0.], vec![0., -2.], vec![0., 0.]]);
+    /// let svd = Svd::new(&matrix);
+    /// assert_eq!(svd.singular_values(), Vector::new(vec![3., 2.]));
+    /// ```
+    pub fn new(matrix: &Matrix<T>) -> Self {
+        Self::try_new(matrix).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `new` but returns an error if the iteration did not converge
+    pub fn try_new(matrix: &Matrix<T>) -> Result<Self, MathError> {
+        let (m, n) = (matrix.cols(), matrix.rows());
+        let flatt = matrix.matrix_flatt();
+
+        if m >= n {
+            let (u, s, v) = jacobi(flatt, m, n)?;
+            Ok(Self { m, n, u, s, v })
+        } else {
+            // A^T = V * Σ * U^T so the roles of U and V are swapped
+            let mut transposed = vec![T::zero(); m * n];
+            for i in 0..m {
+                for j in 0..n {
+                    transposed[j * m + i] = flatt[i * n + j];
+                }
+            }
+            let (v, s, u) = jacobi(transposed, n, m)?;
+            Ok(Self { m, n, u, s, v })
+        }
+    }
+
+    /// returns the singular values sorted from the largest to the smallest
+    pub fn singular_values(&self) -> Vector<T> {
+        Vector::from_vec(self.s.clone())
+    }
+
+    /// returns the thin m * r matrix U with orthonormal columns
+    pub fn u(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.u.clone(), self.m, self.s.len())
+    }
+
+    /// returns the thin r * r diagonal matrix Σ
+    pub fn sigma(&self) -> Matrix<T> {
+        diagonal(&self.s, self.s.len(), self.s.len())
+    }
+
+    /// returns the thin r * n matrix V^T with orthonormal rows
+    pub fn vt(&self) -> Matrix<T> {
+        transpose(&self.v, self.n, self.s.len())
+    }
+
+    /// returns the full m * m orthogonal matrix U
+    pub fn u_full(&self) -> Matrix<T> {
+        Matrix::from_flatt(complete(&self.u, self.m, self.s.len()), self.m, self.m)
+    }
+
+    /// returns the full m * n matrix Σ with the singular values on the diagonal
+    pub fn sigma_full(&self) -> Matrix<T> {
+        diagonal(&self.s, self.m, self.n)
+    }
+
+    /// returns the full n * n orthogonal matrix V^T
+    pub fn vt_full(&self) -> Matrix<T> {
+        transpose(&complete(&self.v, self.n, self.s.len()), self.n, self.n)
+    }
+
+    /// returns the number of singular values that are larger than `tolerance`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+    /// assert_eq!(matrix.svd().rank(1e-5), 1);
+    /// ```
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.s.iter().filter(|&&s| s > tolerance).count()
+    }
+
+    /// returns the [2-norm] of the matrix which is the largest singular value
+    ///
+    /// [2-norm]: https://en.wikipedia.org/wiki/Matrix_norm#Spectral_norm
+    pub fn norm2(&self) -> T {
+        self.s.first().copied().unwrap_or(T::zero())
+    }
+
+    /// returns the [condition number] in the 2-norm, the largest divided by the smallest singular value
+    ///
+    /// [condition number]: https://en.wikipedia.org/wiki/Condition_number
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![4., 0.], vec![0., 0.5]]);
+    /// assert_eq!(matrix.svd().cond(), 8.);
+    /// ```
+    /// note it is infinite for a rank deficient matrix
+    pub fn cond(&self) -> T {
+        match self.s.last() {
+            Some(&min) if min > T::zero() => self.norm2() / min,
+            _ => T::infinity(),
+        }
+    }
+
+    /// returns the [Moore–Penrose inverse] `V * Σ^+ * U^T`
+    ///
+    /// [Moore–Penrose inverse]: https://en.wikipedia.org/wiki/Moore%E2%80%93Penrose_inverse
+    ///
+    /// singular values below `max(m, n) * ε * norm2()` are treated as zero
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 0.], vec![0., 0.], vec![0., 4.]]);
+    /// assert_eq!(
+    ///     matrix.svd().pinv(),
+    ///     Matrix::new(vec![vec![0.5, 0., 0.], vec![0., 0., 0.25]])
+    /// );
+    /// ```
+    pub fn pinv(&self) -> Matrix<T> {
+        let tolerance = T::from_usize(self.m.max(self.n)) * T::epsilon() * self.norm2();
+        self.pinv_with_tolerance(tolerance)
+    }
+
+    /// returns the Moore–Penrose inverse where singular values up to `tolerance` are treated as zero
+    pub fn pinv_with_tolerance(&self, tolerance: T) -> Matrix<T> {
+        let (m, n, r) = (self.m, self.n, self.s.len());
+        let mut pinv = vec![T::zero(); n * m];
+        for (k, &s) in self.s.iter().enumerate().filter(|(_, &s)| s > tolerance) {
+            for i in 0..n {
+                let v_ik = self.v[i * r + k] / s;
+                for j in 0..m {
+                    pinv[i * m + j] += v_ik * self.u[j * r + k];
+                }
+            }
+        }
+        Matrix::from_flatt(pinv, n, m)
+    }
+
+    /// returns the best approximation of the matrix with rank `k` in the 2-norm
+    ///
+    /// this only keeps the k largest singular values `U_k * Σ_k * V_k^T`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![5., 0.], vec![0., 1.]]);
+    /// assert_eq!(
+    ///     matrix.svd().low_rank(1),
+    ///     Matrix::new(vec![vec![5., 0.], vec![0., 0.]])
+    /// );
+    /// ```
+    pub fn low_rank(&self, k: usize) -> Matrix<T> {
+        let (m, n, r) = (self.m, self.n, self.s.len());
+        let mut result = vec![T::zero(); m * n];
+        for (l, &s) in self.s.iter().enumerate().take(k) {
+            for i in 0..m {
+                let u_il = self.u[i * r + l] * s;
+                for j in 0..n {
+                    result[i * n + j] += u_il * self.v[j * r + l];
+                }
+            }
+        }
+        Matrix::from_flatt(result, m, n)
+    }
+}
+
+// U, the singular values and V each stored row by row
+type Factors<T> = (Vec<T>, Vec<T>, Vec<T>);
+
+// one sided jacobi on the m * n matrix a with m >= n, rotates pairs of columns until
+// all are orthogonal and returns U, the sorted singular values and V
+fn jacobi<T: Real>(mut a: Vec<T>, m: usize, n: usize) -> Result<Factors<T>, MathError> {
+    let mut v = vec![T::zero(); n * n];
+    for i in 0..n {
+        v[i * n + i] = T::one();
+    }
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..m {
+                    let (a_ip, a_iq) = (a[i * n + p], a[i * n + q]);
+                    alpha += a_ip * a_ip;
+                    beta += a_iq * a_iq;
+                    gamma += a_ip * a_iq;
+                }
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (T::from_f64(2.) * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(T::one()));
+                let c = T::one() / t.hypot(T::one());
+                let s = c * t;
+
+                rotate(&mut a, n, p, q, c, s);
+                rotate(&mut v, n, p, q, c, s);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+
+    if !converged {
+        return Err(MathError::NoConvergence {
+            iterations: MAX_SWEEPS,
+        });
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            (0..m)
+                .map(|i| a[i * n + j] * a[i * n + j])
+                .sum::<T>()
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let mut u = vec![T::zero(); m * n];
+    let mut sorted_v = vec![T::zero(); n * n];
+    let mut s = Vec::with_capacity(n);
+    let mut missing = Vec::new();
+    for (col, &j) in order.iter().enumerate() {
+        s.push(norms[j]);
+        if norms[j] == T::zero() {
+            missing.push(col);
+        } else {
+            for i in 0..m {
+                u[i * n + col] = a[i * n + j] / norms[j];
+            }
+        }
+        for i in 0..n {
+            sorted_v[i * n + col] = v[i * n + j];
+        }
+    }
+
+    // columns of U for a zero singular value are filled with any orthonormal vectors
+    let complete_cols = n - missing.len();
+    if !missing.is_empty() {
+        let mut kept = Vec::with_capacity(m * n);
+        for i in 0..m {
+            kept.extend_from_slice(&u[i * n..i * n + complete_cols]);
+        }
+        let full = complete(&kept, m, complete_cols);
+        for i in 0..m {
+            u[i * n..(i + 1) * n].copy_from_slice(&full[i * m..i * m + n]);
+        }
+    }
+
+    Ok((u, s, sorted_v))
+}
+
+// applies the rotation [c, s] [-s, c] to the columns p and q of the matrix with n columns
+fn rotate<T: Real>(a: &mut [T], n: usize, p: usize, q: usize, c: T, s: T) {
+    for row in a.chunks_mut(n) {
+        let (a_p, a_q) = (row[p], row[q]);
+        row[p] = c * a_p - s * a_q;
+        row[q] = s * a_p + c * a_q;
+    }
+}
+
+// extends the k orthonormal columns of the m * k matrix a to a m * m orthogonal matrix
+// with gram schmidt on the unit vector that sticks out the most of the columns so far
+fn complete<T: Real>(a: &[T], m: usize, k: usize) -> Vec<T> {
+    let mut cols: Vec<Vec<T>> = (0..k)
+        .map(|j| (0..m).map(|i| a[i * k + j]).collect())
+        .collect();
+
+    let orthogonalize = |cols: &[Vec<T>], x: &mut Vec<T>| {
+        // orthogonalizing twice keeps the result orthogonal in floating point
+        for _ in 0..2 {
+            for col in cols.iter() {
+                let dot: T = col.iter().zip(x.iter()).map(|(&a, &b)| a * b).sum();
+                x.iter_mut()
+                    .zip(col.iter())
+                    .for_each(|(x, &c)| *x -= dot * c);
+            }
+        }
+    };
+
+    while cols.len() < m {
+        let mut best = (vec![T::zero(); m], T::zero());
+        for e in 0..m {
+            let mut x = vec![T::zero(); m];
+            x[e] = T::one();
+            orthogonalize(&cols, &mut x);
+            let norm = x.iter().map(|&x| x * x).sum::<T>().sqrt();
+            if norm > best.1 {
+                best = (x, norm);
+            }
+        }
+        let (mut x, norm) = best;
+        x.iter_mut().for_each(|x| *x /= norm);
+        cols.push(x);
+    }
+
+    let mut result = vec![T::zero(); m * m];
+    for (j, col) in cols.iter().enumerate() {
+        for i in 0..m {
+            result[i * m + j] = col[i];
+        }
+    }
+    result
+}
+
+fn diagonal<T: Real>(s: &[T], rows: usize, cols: usize) -> Matrix<T> {
+    let mut flatt = vec![T::zero(); rows * cols];
+    for (i, &s) in s.iter().enumerate() {
+        flatt[i * cols + i] = s;
+    }
+    Matrix::from_flatt(flatt, rows, cols)
+}
+
+fn transpose<T: Real>(a: &[T], rows: usize, cols: usize) -> Matrix<T> {
+    let mut flatt = vec![T::zero(); rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            flatt[j * rows + i] = a[i * cols + j];
+        }
+    }
+    Matrix::from_flatt(flatt, cols, rows)
+}
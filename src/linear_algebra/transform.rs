@@ -0,0 +1,208 @@
+use crate::linear_algebra::{Mat4, Quaternion, Vec3, Vec4};
+use crate::scalar::Real;
+
+/// the builders for [homogeneous] 3D transforms
+///
+/// [homogeneous]: https://en.wikipedia.org/wiki/Transformation_matrix#Affine_transformations
+///
+/// the arrays of a `Mat4` are the rows so a point is transformed with `matrix * point`
+/// and `a * b` applies `b` first, the coordinate system is right handed like in OpenGL
+///
+/// note a GPU expects the columns one after the other so upload `matrix.transpose().as_slice()`
+impl<T: Real> Mat4<T> {
+    /// creates the transform that moves a point by `offset`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let matrix = Mat4::translation(Vec3::new([1., 2., 3.]));
+    /// assert_eq!(matrix.transform_point(Vec3::new([1., 1., 1.])), Vec3::new([2., 3., 4.]));
+    /// assert_eq!(matrix.transform_dir(Vec3::new([1., 1., 1.])), Vec3::new([1., 1., 1.]));
+    /// ```
+    pub fn translation(offset: Vec3<T>) -> Self {
+        let mut matrix = Mat4::identity();
+        for i in 0..3 {
+            matrix[(i, 3)] = offset[i];
+        }
+        matrix
+    }
+
+    /// creates the transform that scales each axis by its factor
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let matrix = Mat4::scale(Vec3::new([2., 1., -1.]));
+    /// assert_eq!(matrix.transform_point(Vec3::new([1., 2., 3.])), Vec3::new([2., 2., -3.]));
+    /// ```
+    pub fn scale(factors: Vec3<T>) -> Self {
+        let mut matrix = Mat4::identity();
+        for i in 0..3 {
+            matrix[(i, i)] = factors[i];
+        }
+        matrix
+    }
+
+    /// creates the rotation by `angle` in radians around the x axis, y is rotated towards z
+    pub fn rotation_x(angle: T) -> Self {
+        Mat4::axis_rotation(1, 2, angle)
+    }
+
+    /// creates the rotation by `angle` in radians around the y axis, z is rotated towards x
+    pub fn rotation_y(angle: T) -> Self {
+        Mat4::axis_rotation(2, 0, angle)
+    }
+
+    /// creates the rotation by `angle` in radians around the z axis, x is rotated towards y
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let matrix = Mat4::rotation_z(std::f32::consts::FRAC_PI_2);
+    /// let result = matrix.transform_point(Vec3::new([1., 0., 0.]));
+    /// assert!(result.dist(Vec3::new([0., 1., 0.])) < 1e-6);
+    /// ```
+    pub fn rotation_z(angle: T) -> Self {
+        Mat4::axis_rotation(0, 1, angle)
+    }
+
+    /// creates the rotation by `angle` in radians around the `axis` which does not have to be normalized
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let matrix: Mat4 = Mat4::rotation(Vec3::new([0., 0., 2.]), 0.3);
+    /// assert!((matrix - Mat4::rotation_z(0.3)).as_slice().iter().all(|x| x.abs() < 1e-6));
+    /// ```
+    /// note it panics if the axis is zero
+    pub fn rotation(axis: Vec3<T>, angle: T) -> Self {
+        Mat4::from_quaternion(Quaternion::from_axis_angle(&axis, angle))
+    }
+
+    /// creates the rotation of the unit quaternion
+    pub fn from_quaternion(quaternion: Quaternion<T>) -> Self {
+        let rotation = quaternion.to_mat3();
+        Mat4::from_fn(|row, col| {
+            if row < 3 && col < 3 {
+                rotation[(row, col)]
+            } else if row == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// creates the view transform of a camera at `eye` that looks at `target`
+    ///
+    /// the camera looks along its negative z axis and `up` is the direction of its y axis,
+    /// `up` does not have to be orthogonal to the view direction but it must not be parallel
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let view = Mat4::look_at(
+    ///     Vec3::new([0., 0., 5.]),
+    ///     Vec3::new([0., 0., 0.]),
+    ///     Vec3::new([0., 1., 0.]),
+    /// );
+    /// assert_eq!(view.transform_point(Vec3::new([1., 0., 0.])), Vec3::new([1., 0., -5.]));
+    /// ```
+    pub fn look_at(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let mut forward = target - eye;
+        forward.unit();
+        let mut side = forward.cross_vec(up);
+        side.unit();
+        let up = side.cross_vec(forward);
+        let zero = T::zero();
+        Mat4::new([
+            [side[0], side[1], side[2], -side.dot_vec(eye)],
+            [up[0], up[1], up[2], -up.dot_vec(eye)],
+            [-forward[0], -forward[1], -forward[2], forward.dot_vec(eye)],
+            [zero, zero, zero, T::one()],
+        ])
+    }
+
+    /// creates the [perspective projection] with the vertical field of view `fov_y` in radians
+    ///
+    /// [perspective projection]: https://en.wikipedia.org/wiki/3D_projection#Perspective_projection
+    ///
+    /// the view space z between `-near` and `-far` is mapped to the clip space depth between -1 and 1 like in OpenGL
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let projection = Mat4::perspective(std::f32::consts::FRAC_PI_2, 2., 1., 10.);
+    /// let result = projection.transform_point(Vec3::new([2., 1., -1.]));
+    /// assert!(result.dist(Vec3::new([1., 1., -1.])) < 1e-6);
+    /// let result = projection.transform_point(Vec3::new([0., 0., -10.]));
+    /// assert!(result.dist(Vec3::new([0., 0., 1.])) < 1e-6);
+    /// ```
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::from_f64(2.);
+        let half = fov_y / two;
+        let focal = half.cos() / half.sin();
+        let mut matrix = Mat4::zero();
+        matrix[(0, 0)] = focal / aspect;
+        matrix[(1, 1)] = focal;
+        matrix[(2, 2)] = (far + near) / (near - far);
+        matrix[(2, 3)] = two * far * near / (near - far);
+        matrix[(3, 2)] = -T::one();
+        matrix
+    }
+
+    /// creates the [orthographic projection] of the box between `left`, `right`, `bottom`, `top`, `-near` and `-far`
+    ///
+    /// [orthographic projection]: https://en.wikipedia.org/wiki/Orthographic_projection
+    ///
+    /// the box is mapped to the cube between -1 and 1 like in OpenGL
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Mat4, Vec3};
+    /// let projection = Mat4::orthographic(0., 4., 0., 2., 1., 3.);
+    /// let result = projection.transform_point(Vec3::new([4., 1., -3.]));
+    /// assert_eq!(result, Vec3::new([1., 0., 1.]));
+    /// ```
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::from_f64(2.);
+        let mut matrix = Mat4::identity();
+        matrix[(0, 0)] = two / (right - left);
+        matrix[(1, 1)] = two / (top - bottom);
+        matrix[(2, 2)] = -two / (far - near);
+        matrix[(0, 3)] = -(right + left) / (right - left);
+        matrix[(1, 3)] = -(top + bottom) / (top - bottom);
+        matrix[(2, 3)] = -(far + near) / (far - near);
+        matrix
+    }
+
+    /// transforms the point with `w = 1` so it is translated and divides by the resulting `w`
+    pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let result = *self * Vec4::new([point[0], point[1], point[2], T::one()]);
+        Vec3::new([result[0], result[1], result[2]]) / result[3]
+    }
+
+    /// transforms the direction with `w = 0` so it is not translated
+    pub fn transform_dir(&self, direction: Vec3<T>) -> Vec3<T> {
+        let result = *self * Vec4::new([direction[0], direction[1], direction[2], T::zero()]);
+        Vec3::new([result[0], result[1], result[2]])
+    }
+
+    // returns the rotation in the plane of the axes `a` and `b` that rotates `a` towards `b`
+    fn axis_rotation(a: usize, b: usize, angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let mut matrix = Mat4::identity();
+        matrix[(a, a)] = cos;
+        matrix[(b, b)] = cos;
+        matrix[(a, b)] = -sin;
+        matrix[(b, a)] = sin;
+        matrix
+    }
+}
@@ -1,92 +1,194 @@
+use crate::complex::Complex;
+use crate::linear_algebra::view::check_same_len;
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::{VectorView, VectorViewMut};
 use crate::random;
+use crate::scalar::{Field, Real, Scalar};
 use std::mem;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-
-fn check_same_len(vec1: &Vector, vec2: &Vector) {
-    if vec1.vec.len() != vec2.vec.len() {
-        panic!(
-            "the other vector has not the same len self.len() = {}, other.len() = {}",
-            vec1.vec.len(),
-            vec2.vec.len()
-        );
-    }
-}
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use std::slice::SliceIndex;
 
 #[derive(PartialEq, Clone, Debug)]
-/// this is a reper for `Vec<f32>`
+/// this is a reper for `Vec<T>` where `T` is `f32` by default
 ///
-/// the Vector implements many useful mathematical functions
-pub struct Vector {
-    vec: Vec<f32>,
+/// the Vector implements many useful mathematical functions,
+/// the ones that need a square root like `mag()` are only implemented for `f32` and `f64`
+pub struct Vector<T = f32> {
+    vec: Vec<T>,
 }
 
-impl Add for Vector {
-    type Output = Self;
-    fn add(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.add_vec(&other);
-        result
-    }
-}
+// implements `op` and `op_assign` component wise for owned and borrowed vectors and for scalars
+macro_rules! impl_vector_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $vec:ident, $scalar:ident) => {
+        impl<T: Scalar> $op for Vector<T> {
+            type Output = Self;
 
-impl AddAssign for Vector {
-    fn add_assign(&mut self, other: Self) {
-        self.add_vec(&other);
-    }
+            fn $method(mut self, other: Self) -> Self {
+                self.$vec(&other);
+                self
+            }
+        }
+
+        impl<'a, T: Scalar> $op<&'a Vector<T>> for Vector<T> {
+            type Output = Self;
+
+            fn $method(mut self, other: &'a Vector<T>) -> Self {
+                self.$vec(other);
+                self
+            }
+        }
+
+        impl<'a, T: Scalar> $op<Vector<T>> for &'a Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, other: Vector<T>) -> Vector<T> {
+                self.clone().$method(&other)
+            }
+        }
+
+        impl<'a, 'b, T: Scalar> $op<&'b Vector<T>> for &'a Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, other: &'b Vector<T>) -> Vector<T> {
+                self.clone().$method(other)
+            }
+        }
+
+        impl<T: Scalar> $op<T> for Vector<T> {
+            type Output = Self;
+
+            fn $method(mut self, scalar: T) -> Self {
+                self.$scalar(&scalar);
+                self
+            }
+        }
+
+        impl<'a, T: Scalar> $op<T> for &'a Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, scalar: T) -> Vector<T> {
+                self.clone().$method(scalar)
+            }
+        }
+
+        impl<T: Scalar> $op_assign for Vector<T> {
+            fn $method_assign(&mut self, other: Self) {
+                self.$vec(&other);
+            }
+        }
+
+        impl<'a, T: Scalar> $op_assign<&'a Vector<T>> for Vector<T> {
+            fn $method_assign(&mut self, other: &'a Vector<T>) {
+                self.$vec(other);
+            }
+        }
+
+        impl<T: Scalar> $op_assign<T> for Vector<T> {
+            fn $method_assign(&mut self, scalar: T) {
+                self.$scalar(&scalar);
+            }
+        }
+    };
 }
 
-impl Sub for Vector {
-    type Output = Self;
+impl_vector_op!(Add, add, AddAssign, add_assign, add_vec, add_scalar);
+impl_vector_op!(Sub, sub, SubAssign, sub_assign, sub_vec, sub_scalar);
+impl_vector_op!(Mul, mul, MulAssign, mul_assign, mul_vec, mul_scalar);
+impl_vector_op!(Div, div, DivAssign, div_assign, div_vec, div_scalar);
 
-    fn sub(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.sub_vec(&other);
-        result
-    }
+// implements `scalar op vector` for the primitive scalars, it applies the scalar to each component
+macro_rules! impl_scalar_vector_op {
+    ($t:ty, $($op:ident, $method:ident);*) => {$(
+        impl $op<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn $method(self, vector: Vector<$t>) -> Vector<$t> {
+                self.$method(&vector)
+            }
+        }
+
+        impl<'a> $op<&'a Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn $method(self, vector: &'a Vector<$t>) -> Vector<$t> {
+                Vector::from_vec(vec![self; vector.len()]).$method(vector)
+            }
+        }
+    )*};
 }
 
-impl SubAssign for Vector {
-    fn sub_assign(&mut self, other: Self) {
-        self.sub_vec(&other);
-    }
+macro_rules! impl_scalar_vector_ops {
+    ($($t:ty)*) => ($(
+        impl_scalar_vector_op!($t, Add, add; Sub, sub; Mul, mul; Div, div);
+    )*)
 }
 
-impl Mul for Vector {
+impl_scalar_vector_ops!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// negates each component
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// let a = Vector::new(vec![1., -2.]);
+/// let b = Vector::new(vec![3., 4.]);
+/// assert_eq!(-&a, Vector::new(vec![-1., 2.]));
+/// assert_eq!(&a * 2. + &b, Vector::new(vec![5., 0.]));
+/// assert_eq!(1. - a, Vector::new(vec![0., 3.]));
+/// ```
+impl<T: Field> Neg for Vector<T> {
     type Output = Self;
 
-    fn mul(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.mul_vec(&other);
-        result
+    fn neg(mut self) -> Self {
+        self.vec.iter_mut().for_each(|x| *x = -*x);
+        self
     }
 }
 
-impl MulAssign for Vector {
-    fn mul_assign(&mut self, other: Self) {
-        self.mul_vec(&other);
+impl<T: Field> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        -self.clone()
     }
 }
 
-impl Div for Vector {
-    type Output = Self;
-
-    fn div(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.div_vec(&other);
-        result
+/// `vector[i]` returns the value at the index and `vector[a..b]` the slice of the range
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Vector, VectorView};
+/// let mut vector = Vector::new(vec![1., 3., 6., 2.]);
+/// vector[0] *= 4.;
+/// assert_eq!(vector[0], 4.);
+/// assert_eq!(&vector[1..3], &[3., 6.]);
+/// assert_eq!(VectorView::from_slice(&vector[2..]).to_vector(), Vector::new(vec![6., 2.]));
+/// ```
+/// note it panics if the index or the range is out of bounds
+impl<T: Scalar, I: SliceIndex<[T]>> Index<I> for Vector<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        &self.vec[index]
     }
 }
 
-impl DivAssign for Vector {
-    fn div_assign(&mut self, other: Self) {
-        self.div_vec(&other);
+impl<T: Scalar, I: SliceIndex<[T]>> IndexMut<I> for Vector<T> {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.vec[index]
     }
 }
 
 impl Vector {
     /// creates a new vector
+    ///
+    /// use `Vector::from_vec` for other scalar types than `f32`
     pub fn new(vec: Vec<f32>) -> Self {
-        Self { vec }
+        Self::from_vec(vec)
     }
 
     /// generates a vector of length `len` with random values between 0 and 1
@@ -120,114 +222,68 @@ impl Vector {
         Self { vec: vec![0.; len] }
     }
 
-    /// returns the angle in degrees between the 2 vectors
-    ///   
-    /// ## Example
-    ///
-    /// ```rust
-    /// use math::linear_algebra::Vector;
-    /// let vector1 = Vector::new(vec![1., 0., 0.]);
-    /// let vector2 = Vector::new(vec![0., 1., 0.]);
-    /// assert_eq!(vector1.angle(&vector2), 90.);
-    /// ```
-    pub fn angle(&self, other: &Vector) -> f32 {
-        self.rot(other) * (180. / std::f32::consts::PI)
-    }
-
-    /// returns the rotaion in radians between the 2 vectors
-    ///   
-    /// ## Example
+    /// this return a vector of bytes representing the vector
     ///
-    /// ```rust
-    /// use math::linear_algebra::Vector;
-    /// let vector1 = Vector::new(vec![1., 0., 0.]);
-    /// let vector2 = Vector::new(vec![0., 1., 0.]);
-    /// assert_eq!(vector1.rot(&vector2), 1.5707964);
-    /// ```
-    pub fn rot(&self, other: &Vector) -> f32 {
-        (self.dot_vec(other) / (self.mag() * other.mag())).acos()
-    }
-
-    /// returns the magnetude of the vector
+    /// this is useful for the *GPU* because the interface only uses bytes
     ///
     /// ## Example
     ///
     /// ```rust
     /// use math::linear_algebra::Vector;
-    /// let vector = Vector::new(vec![2., 3., 5.]);
-    /// assert_eq!(vector.mag(), ((2. * 2. + 3. * 3. + 5. * 5.) as f32).sqrt());
+    /// let vector = Vector::new(vec![2., 1., 6.]);
+    /// assert_eq!(
+    ///    vector.bytes(),
+    ///    vec![0, 0, 64, 64, 0, 0, 0, 64, 0, 0, 128, 63, 0, 0, 192, 64]
+    /// );
     /// ```
-    pub fn mag(&self) -> f32 {
-        let sqr_sum: f32 = self.vec.iter().map(|v| v * v).sum();
-        sqr_sum.sqrt()
-    }
+    /// note the fist `f32` is the len of the vector, use `BufferWriter` for the std140 and std430 layouts of shaders
+    /// and `to_writer` for a portable format that can be read back
+    pub fn bytes(&self) -> Vec<u8> {
+        let size = (1 + self.vec.len()) * mem::size_of::<f32>();
+        let mut bytes = Vec::<u8>::with_capacity(size);
 
-    /// sets the magnetude of the vector to a spicific value
-    ///   
-    /// ## Example
-    ///
-    /// ```rust
-    /// use math::linear_algebra::Vector;
-    /// let mut vector = Vector::new(vec![2., 3., 5.]);
-    /// vector.set_mag(4.);
-    /// assert_eq!(vector.mag(), 4.);
-    /// ```
-    pub fn set_mag(&mut self, mag: f32) {
-        self.mul_scalar(&(mag / self.mag()));
-    }
+        let push_f32_bytes = |num: f32, bytes: &mut Vec<u8>| {
+            for b in num.to_ne_bytes().to_vec() {
+                bytes.push(b);
+            }
+        };
 
-    /// calculates the [Euclidean distance] between 2 vectors
-    ///
-    /// [Euclidean distance]:https://en.wikipedia.org/wiki/Euclidean_distance
-    ///   
-    /// ## Example
-    ///
-    /// ```rust
-    /// use math::linear_algebra::Vector;
-    /// let vector1 = Vector::new(vec![2., 7., 1.]);
-    /// let vector2 = Vector::new(vec![8., 2., 8.]);
-    /// assert_eq!(vector1.dist(&vector2), 10.488089);
-    /// ```
-    pub fn dist(&self, other: &Vector) -> f32 {
-        check_same_len(self, other);
-        let mut res = 0.;
-        for i in 0..self.vec.len() {
-            res += (self.vec[i] - other.vec()[i]) * (self.vec[i] - other.vec()[i]);
-        }
-        res.sqrt()
+        push_f32_bytes(self.vec.len() as f32, &mut bytes);
+
+        self.vec
+            .iter()
+            .for_each(|&val| push_f32_bytes(val, &mut bytes));
+        bytes
     }
+}
 
-    /// Limit the magnitude of this vector to the value used for the `max` parameter
+impl<T: Scalar> Vector<T> {
+    /// creates a new vector of any scalar type
     ///
     /// ## Example
     ///
     /// ```rust
     /// use math::linear_algebra::Vector;
-    /// let mut vector = Vector::new(vec![2., 3., 5.]);
-    /// vector.limit(2.);
-    /// assert_eq!(vector.mag(), 2.);
-    ///
-    /// vector.limit(3.);
-    /// assert_eq!(vector.mag(), 2.);
+    /// let vector = Vector::from_vec(vec![1_i32, 2, 3]);
+    /// assert_eq!(vector.dot_vec(&vector), 14);
     /// ```
-    pub fn limit(&mut self, max: f32) {
-        if self.mag() > max {
-            self.set_mag(max);
-        }
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self { vec }
     }
 
-    /// normalizes the vetor same dirction but the magnetude is 1
+    /// generates a vector of length `len` with all values being zero
     ///
     /// ## Example
     ///
     /// ```rust
     /// use math::linear_algebra::Vector;
-    /// let mut vector = Vector::new(vec![2., 3., 5.]);
-    /// vector.unit();
-    /// assert_eq!(vector.mag(), 1.);
+    /// let vector = Vector::<f64>::zero(3);
+    /// assert_eq!(vector.vec(), vec![0., 0., 0.]);
     /// ```
-    pub fn unit(&mut self) {
-        self.div_scalar(&self.mag());
+    pub fn zero(len: usize) -> Self {
+        Self {
+            vec: vec![T::zero(); len],
+        }
     }
 
     // vector math
@@ -245,16 +301,37 @@ impl Vector {
     /// assert_eq!(vector1.cross_vec(&vector2), Vector::new(vec![0., 0., 1.]));
     /// ```  
     /// note this only works with 3 dimensional vectors
-    pub fn cross_vec(&self, other: &Vector) -> Vector {
-        if self.len() != 3 || other.len() != 3 {
-            panic!("this only works with 3 dimensional vectors");
+    pub fn cross_vec<'b>(&self, other: impl Into<VectorView<'b, T>>) -> Vector<T>
+    where
+        T: 'b,
+    {
+        self.try_cross_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `cross_vec` but returns an error if one of the vectors is not 3 dimensional
+    pub fn try_cross_vec<'b>(
+        &self,
+        other: impl Into<VectorView<'b, T>>,
+    ) -> Result<Vector<T>, MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        for len in [self.len(), other.len()].iter() {
+            if *len != 3 {
+                return Err(MathError::ShapeMismatch {
+                    expected: 3,
+                    actual: *len,
+                });
+            }
         }
 
-        Vector::new(vec![
+        Ok(Vector::from_vec(vec![
             self.index(1) * other.index(2) - self.index(2) * other.index(1),
             self.index(2) * other.index(0) - self.index(0) * other.index(2),
             self.index(0) * other.index(1) - self.index(1) * other.index(0),
-        ])
+        ]))
     }
 
     /// returns the [dot product]
@@ -270,13 +347,20 @@ impl Vector {
     /// assert_eq!(vector1.dot_vec(&vector2), 38.);
     /// ```
     /// note it panics if the vectors have not the same len  
-    pub fn dot_vec(&self, other: &Vector) -> f32 {
-        check_same_len(self, other);
-        let mut res = 0.;
-        for i in 0..self.vec.len() {
-            res += self.vec[i] * other.vec()[i];
-        }
-        res
+    pub fn dot_vec<'b>(&self, other: impl Into<VectorView<'b, T>>) -> T
+    where
+        T: 'b,
+    {
+        self.try_dot_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the vectors have not the same len
+    pub fn try_dot_vec<'b>(&self, other: impl Into<VectorView<'b, T>>) -> Result<T, MathError>
+    where
+        T: 'b,
+    {
+        self.view().try_dot_vec(other)
     }
 
     /// multiplies each component from the vector with the component of the other vector and stors the result in this vector   
@@ -291,11 +375,20 @@ impl Vector {
     /// assert_eq!(vector1, Vector::new(vec![0. * 3., 2. * 1., 3. * 3.]));
     /// ```
     /// note it panics if the vectors have not the same len
-    pub fn mul_vec(&mut self, other: &Vector) {
-        check_same_len(self, other);
-        for i in 0..other.len() {
-            self.vec[i] = self.vec[i] * other.vec[i];
-        }
+    pub fn mul_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_mul_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `mul_vec` but returns an error if the vectors have not the same len
+    pub fn try_mul_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_mul_vec(other)
     }
 
     /// adds each component from the vector with the component of the other vector and stors the result in this vector   
@@ -310,11 +403,20 @@ impl Vector {
     /// assert_eq!(vector1, Vector::new(vec![0. + 3., 2. + 1., 3. + 3.]));
     /// ```
     /// note it panics if the vectors have not the same len
-    pub fn add_vec(&mut self, other: &Vector) {
-        check_same_len(self, other);
-        for i in 0..other.len() {
-            self.vec[i] = self.vec[i] + other.vec[i];
-        }
+    pub fn add_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_add_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `add_vec` but returns an error if the vectors have not the same len
+    pub fn try_add_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_add_vec(other)
     }
 
     /// subtracts each component from the vector with the component of the other vector and stors the result in this vector   
@@ -329,11 +431,20 @@ impl Vector {
     /// assert_eq!(vector1, Vector::new(vec![0. - 3., 2. - 1., 3. - 3.]));
     /// ```
     /// note it panics if the vectors have not the same len
-    pub fn sub_vec(&mut self, other: &Vector) {
-        check_same_len(self, other);
-        for i in 0..other.len() {
-            self.vec[i] = self.vec[i] - other.vec[i];
-        }
+    pub fn sub_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_sub_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `sub_vec` but returns an error if the vectors have not the same len
+    pub fn try_sub_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_sub_vec(other)
     }
 
     /// divides each component from the vector with the component of the other vector and stors the result in this vector   
@@ -348,11 +459,20 @@ impl Vector {
     /// assert_eq!(vector1, Vector::new(vec![0. / 3., 2. / 1., 3. / 3.]));
     /// ```
     /// note it panics if the vectors have not the same len
-    pub fn div_vec(&mut self, other: &Vector) {
-        check_same_len(self, other);
-        for i in 0..other.len() {
-            self.vec[i] = self.vec[i] / other.vec[i];
-        }
+    pub fn div_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_div_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `div_vec` but returns an error if the vectors have not the same len
+    pub fn try_div_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.view_mut().try_div_vec(other)
     }
 
     /// multiplies each component from the vector with a scalar value and stors the result in this vector   
@@ -365,8 +485,8 @@ impl Vector {
     /// vector.mul_scalar(&2.);
     /// assert_eq!(vector, Vector::new(vec![2. * 2., 3. * 2., 5. * 2.]));
     /// ```
-    pub fn mul_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v * scalar).collect();
+    pub fn mul_scalar(&mut self, scalar: &T) {
+        self.vec = self.vec.iter().map(|&v| v * *scalar).collect();
     }
 
     /// divides each component from the vector with a scalar value and stors the result in this vector   
@@ -379,8 +499,8 @@ impl Vector {
     /// vector.div_scalar(&2.);
     /// assert_eq!(vector, Vector::new(vec![2. / 2., 3. / 2., 5. / 2.]));
     /// ```
-    pub fn div_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v / scalar).collect();
+    pub fn div_scalar(&mut self, scalar: &T) {
+        self.vec = self.vec.iter().map(|&v| v / *scalar).collect();
     }
 
     /// adds each component from the vector with a scalar value and stors the result in this vector   
@@ -393,8 +513,8 @@ impl Vector {
     /// vector.add_scalar(&2.);
     /// assert_eq!(vector, Vector::new(vec![2. + 2., 3. + 2., 5. + 2.]));
     /// ```
-    pub fn add_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v + scalar).collect();
+    pub fn add_scalar(&mut self, scalar: &T) {
+        self.vec = self.vec.iter().map(|&v| v + *scalar).collect();
     }
 
     /// subtracts each component from the vector with a scalar value and stors the result in this vector   
@@ -407,11 +527,11 @@ impl Vector {
     /// vector.sub_scalar(&2.);
     /// assert_eq!(vector, Vector::new(vec![2. - 2., 3. - 2., 5. - 2.]));
     /// ```
-    pub fn sub_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v - scalar).collect();
+    pub fn sub_scalar(&mut self, scalar: &T) {
+        self.vec = self.vec.iter().map(|&v| v - *scalar).collect();
     }
 
-    /// getter for the internal Vec<f32> representation
+    /// getter for the internal Vec<T> representation
     ///
     /// ## Example
     ///
@@ -420,7 +540,7 @@ impl Vector {
     /// let mut vector = Vector::new(vec![2., 1., 6.]);
     /// assert_eq!(vector.vec(), vec![2., 1., 6.]);
     /// ```
-    pub fn vec(&self) -> Vec<f32> {
+    pub fn vec(&self) -> Vec<T> {
         self.vec.clone()
     }
 
@@ -440,6 +560,34 @@ impl Vector {
         self.vec.len()
     }
 
+    /// returns a borrowed view of the whole vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![4., 3., 5., 1.]);
+    /// let view = vector.view().slice(1, 3).step(2);
+    /// assert_eq!(view.to_vector(), Vector::new(vec![3., 1.]));
+    /// ```
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView::from_slice(&self.vec)
+    }
+
+    /// returns a mutable view of the whole vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![4., 3., 5., 1.]);
+    /// vector.view_mut().slice_mut(2, 2).fill(0.);
+    /// assert_eq!(vector, Vector::new(vec![4., 3., 0., 0.]));
+    /// ```
+    pub fn view_mut(&mut self) -> VectorViewMut<'_, T> {
+        VectorViewMut::from_slice(&mut self.vec)
+    }
+
     /// returns the value at the given index
     ///
     /// ## Example
@@ -449,40 +597,288 @@ impl Vector {
     /// let vector = Vector::new(vec![1., 3., 6.]);
     /// assert_eq!(vector.index(1), 3.);
     /// ```  
-    pub fn index(&self, index: usize) -> f32 {
-        self.vec[index]
+    pub fn index(&self, index: usize) -> T {
+        self.try_index(index)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
-    /// this return a vector of bytes representing the vector
+    /// same as `index` but returns an error if the index is out of bounds
     ///
-    /// this is useful for the *GPU* because the interface only uses bytes
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Vector};
+    /// let vector = Vector::new(vec![1., 3., 6.]);
+    /// assert_eq!(vector.try_index(2), Ok(6.));
+    /// assert_eq!(
+    ///     vector.try_index(3),
+    ///     Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+    /// );
+    /// ```
+    pub fn try_index(&self, index: usize) -> Result<T, MathError> {
+        self.vec
+            .get(index)
+            .copied()
+            .ok_or(MathError::IndexOutOfBounds {
+                index,
+                len: self.vec.len(),
+            })
+    }
+
+    /// sets the value at the given index
     ///
     /// ## Example
     ///
     /// ```rust
     /// use math::linear_algebra::Vector;
-    /// let vector = Vector::new(vec![2., 1., 6.]);
+    /// let mut vector = Vector::new(vec![1., 3., 6.]);
+    /// vector.set_index(1, 5.);
+    /// assert_eq!(vector, Vector::new(vec![1., 5., 6.]));
+    /// ```
+    /// note it panics if the index is out of bounds
+    pub fn set_index(&mut self, index: usize, val: T) {
+        self.try_set_index(index, val)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `set_index` but returns an error if the index is out of bounds
+    pub fn try_set_index(&mut self, index: usize, val: T) -> Result<(), MathError> {
+        self.view_mut().try_set_index(index, val)
+    }
+}
+
+impl<T: Real> Vector<T> {
+    /// returns the angle in degrees between the 2 vectors
+    ///   
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector1 = Vector::new(vec![1., 0., 0.]);
+    /// let vector2 = Vector::new(vec![0., 1., 0.]);
+    /// assert_eq!(vector1.angle(&vector2), 90.);
+    /// ```
+    pub fn angle(&self, other: &Vector<T>) -> T {
+        self.rot(other) * T::from_f64(180. / std::f64::consts::PI)
+    }
+
+    /// returns the rotaion in radians between the 2 vectors
+    ///   
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector1 = Vector::new(vec![1., 0., 0.]);
+    /// let vector2 = Vector::new(vec![0., 1., 0.]);
+    /// assert_eq!(vector1.rot(&vector2), 1.5707964);
+    /// ```
+    pub fn rot(&self, other: &Vector<T>) -> T {
+        (self.dot_vec(other) / (self.mag() * other.mag())).acos()
+    }
+
+    /// returns the magnetude of the vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![2., 3., 5.]);
+    /// assert_eq!(vector.mag(), ((2. * 2. + 3. * 3. + 5. * 5.) as f32).sqrt());
+    /// ```
+    pub fn mag(&self) -> T {
+        let sqr_sum: T = self.vec.iter().map(|&v| v * v).sum();
+        sqr_sum.sqrt()
+    }
+
+    /// sets the magnetude of the vector to a spicific value
+    ///   
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![2., 3., 5.]);
+    /// vector.set_mag(4.);
+    /// assert_eq!(vector.mag(), 4.);
+    /// ```
+    pub fn set_mag(&mut self, mag: T) {
+        self.mul_scalar(&(mag / self.mag()));
+    }
+
+    /// calculates the [Euclidean distance] between 2 vectors
+    ///
+    /// [Euclidean distance]:https://en.wikipedia.org/wiki/Euclidean_distance
+    ///   
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector1 = Vector::new(vec![2., 7., 1.]);
+    /// let vector2 = Vector::new(vec![8., 2., 8.]);
+    /// assert_eq!(vector1.dist(&vector2), 10.488089);
+    /// ```
+    pub fn dist<'b>(&self, other: impl Into<VectorView<'b, T>>) -> T
+    where
+        T: 'b,
+    {
+        self.try_dist(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dist` but returns an error if the vectors have not the same len
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{MathError, Vector};
+    /// let vector1 = Vector::new(vec![2., 7., 1.]);
+    /// let vector2 = Vector::new(vec![8., 2.]);
     /// assert_eq!(
-    ///    vector.bytes(),
-    ///    vec![0, 0, 64, 64, 0, 0, 0, 64, 0, 0, 128, 63, 0, 0, 192, 64]
+    ///     vector1.try_dist(&vector2),
+    ///     Err(MathError::ShapeMismatch { expected: 3, actual: 2 })
     /// );
     /// ```
-    /// note the fist `f32` is the len of the vector
-    pub fn bytes(&self) -> Vec<u8> {
-        let size = (1 + self.vec.len()) * mem::size_of::<f32>();
-        let mut bytes = Vec::<u8>::with_capacity(size);
+    pub fn try_dist<'b>(&self, other: impl Into<VectorView<'b, T>>) -> Result<T, MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_same_len(self.len(), other.len())?;
+        Ok(self
+            .vec
+            .iter()
+            .zip(other.iter())
+            .map(|(&x, y)| (x - y) * (x - y))
+            .sum::<T>()
+            .sqrt())
+    }
 
-        let push_f32_bytes = |num: f32, bytes: &mut Vec<u8>| {
-            for b in num.to_ne_bytes().to_vec() {
-                bytes.push(b);
-            }
-        };
+    /// Limit the magnitude of this vector to the value used for the `max` parameter
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![2., 3., 5.]);
+    /// vector.limit(2.);
+    /// assert_eq!(vector.mag(), 2.);
+    ///
+    /// vector.limit(3.);
+    /// assert_eq!(vector.mag(), 2.);
+    /// ```
+    pub fn limit(&mut self, max: T) {
+        if self.mag() > max {
+            self.set_mag(max);
+        }
+    }
 
-        push_f32_bytes(self.vec.len() as f32, &mut bytes);
+    /// normalizes the vetor same dirction but the magnetude is 1
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![2., 3., 5.]);
+    /// vector.unit();
+    /// assert_eq!(vector.mag(), 1.);
+    /// ```
+    pub fn unit(&mut self) {
+        self.div_scalar(&self.mag());
+    }
+    /// converts the vector to a complex vector with zero imaginary parts
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2.]).to_complex();
+    /// assert_eq!(vector.index(1), Complex::new(2., 0.));
+    /// ```
+    pub fn to_complex(&self) -> Vector<Complex<T>> {
+        Vector::from_vec(self.vec.iter().map(|&x| Complex::from(x)).collect())
+    }
+}
 
-        self.vec
+impl<T: Real> Vector<Complex<T>> {
+    /// conjugates each component of the vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::from_vec(vec![Complex::new(1., 2.), Complex::new(3., -4.)]);
+    /// vector.conj();
+    /// assert_eq!(
+    ///     vector,
+    ///     Vector::from_vec(vec![Complex::new(1., -2.), Complex::new(3., 4.)])
+    /// );
+    /// ```
+    pub fn conj(&mut self) {
+        self.vec.iter_mut().for_each(|x| *x = x.conj());
+    }
+
+    /// returns the [inner product] `sum(conj(self[i]) * other[i])` which conjugates this vector
+    /// unlike `dot_vec`
+    ///
+    /// [inner product]: https://en.wikipedia.org/wiki/Inner_product_space
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::from_vec(vec![Complex::new(1., 1.), Complex::new(0., 2.)]);
+    /// assert_eq!(vector.inner(&vector), Complex::new(6., 0.));
+    /// ```
+    /// note it panics if the vectors have not the same len
+    pub fn inner<'b>(&self, other: impl Into<VectorView<'b, Complex<T>>>) -> Complex<T>
+    where
+        T: 'b,
+    {
+        self.try_inner(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `inner` but returns an error if the vectors have not the same len
+    pub fn try_inner<'b>(
+        &self,
+        other: impl Into<VectorView<'b, Complex<T>>>,
+    ) -> Result<Complex<T>, MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_same_len(self.len(), other.len())?;
+        Ok(self
+            .vec
             .iter()
-            .for_each(|&val| push_f32_bytes(val, &mut bytes));
-        bytes
+            .zip(other.iter())
+            .map(|(x, y)| x.conj() * y)
+            .sum())
+    }
+
+    /// returns the euclidean norm `sqrt(sum(abs(self[i])^2))`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::complex::Complex;
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::from_vec(vec![Complex::new(3., 4.), Complex::new(0., 0.)]);
+    /// assert_eq!(vector.norm(), 5.);
+    /// ```
+    pub fn norm(&self) -> T {
+        self.vec.iter().map(|x| x.norm_sqr()).sum::<T>().sqrt()
+    }
+
+    /// returns the real parts of all components
+    pub fn re(&self) -> Vector<T> {
+        Vector::from_vec(self.vec.iter().map(|x| x.re).collect())
+    }
+
+    /// returns the imaginary parts of all components
+    pub fn im(&self) -> Vector<T> {
+        Vector::from_vec(self.vec.iter().map(|x| x.im).collect())
     }
 }
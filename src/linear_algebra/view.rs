@@ -0,0 +1,1389 @@
+use crate::linear_algebra::MathError;
+use crate::linear_algebra::Matrix;
+use crate::linear_algebra::Vector;
+use crate::scalar::Scalar;
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::{
+    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+#[derive(Clone, Copy)]
+/// a borrowed vector that reads `len` elements from a slice starting at `offset`
+/// with `stride` elements between two neighbours
+///
+/// it can be a whole `Vector`, a row, a column or the diagonal of a `Matrix` or a strided slice
+/// of any of them, nothing is copied until `to_vector` is called
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Matrix, Vector};
+/// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let diagonal = matrix.diagonal();
+/// assert_eq!(diagonal.to_vector(), Vector::new(vec![1., 4.]));
+/// ```
+pub struct VectorView<'a, T = f32> {
+    data: &'a [T],
+    offset: usize,
+    len: usize,
+    stride: usize,
+}
+
+/// the mutable version of `VectorView` which writes in place into the borrowed slice
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// matrix.diagonal_mut().mul_scalar(&10.);
+/// assert_eq!(matrix, Matrix::new(vec![vec![10., 2.], vec![3., 40.]]));
+/// ```
+pub struct VectorViewMut<'a, T = f32> {
+    data: &'a mut [T],
+    offset: usize,
+    len: usize,
+    stride: usize,
+}
+
+#[derive(Clone, Copy)]
+/// a borrowed matrix with `cols` and `rows` like a `Matrix`
+///
+/// the element `index(row, col)` is read from `offset + row * strides.0 + col * strides.1`
+/// so submatrices, transposes and strided slices of a `Matrix` need no copy
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+/// let block = matrix.submatrix(1, 1, 2, 2);
+/// assert_eq!(block.to_matrix(), Matrix::new(vec![vec![5., 6.], vec![8., 9.]]));
+/// ```
+pub struct MatrixView<'a, T = f32> {
+    data: &'a [T],
+    offset: usize,
+    cols: usize,
+    rows: usize,
+    strides: (usize, usize),
+}
+
+/// the mutable version of `MatrixView` which writes in place into the borrowed matrix
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// matrix.submatrix_mut(0, 1, 2, 1).fill(0.);
+/// assert_eq!(matrix, Matrix::new(vec![vec![1., 0.], vec![3., 0.]]));
+/// ```
+pub struct MatrixViewMut<'a, T = f32> {
+    data: &'a mut [T],
+    offset: usize,
+    cols: usize,
+    rows: usize,
+    strides: (usize, usize),
+}
+
+impl<'a, T: Scalar> VectorView<'a, T> {
+    /// creates a view of the whole slice
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::VectorView;
+    /// let data = [1., 2., 3.];
+    /// let view = VectorView::from_slice(&data);
+    /// assert_eq!(view.index(2), 3.);
+    /// ```
+    pub fn from_slice(data: &'a [T]) -> Self {
+        VectorView::from_parts(data, 0, data.len(), 1)
+    }
+
+    pub(crate) fn from_parts(data: &'a [T], offset: usize, len: usize, stride: usize) -> Self {
+        VectorView {
+            data,
+            offset,
+            len,
+            stride,
+        }
+    }
+
+    /// returns the number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// returns true if the view has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the element at the index
+    ///
+    /// note it panics if the index is out of bounds
+    pub fn index(&self, index: usize) -> T {
+        self.try_index(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `index` but returns an error if the index is out of bounds
+    pub fn try_index(&self, index: usize) -> Result<T, MathError> {
+        check_index(index, self.len)?;
+        Ok(self.get(index))
+    }
+
+    /// returns an iterator over the elements
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let view = *self;
+        (0..view.len).map(move |i| view.get(i))
+    }
+
+    /// copies the elements in to a new `Vector`
+    pub fn to_vector(&self) -> Vector<T> {
+        Vector::from_vec(self.iter().collect())
+    }
+
+    /// returns the view of the `len` elements starting at `start`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2., 3., 4.]);
+    /// assert_eq!(vector.view().slice(1, 2).to_vector(), Vector::new(vec![2., 3.]));
+    /// ```
+    /// note it panics if the slice is out of bounds
+    pub fn slice(&self, start: usize, len: usize) -> VectorView<'a, T> {
+        self.try_slice(start, len)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `slice` but returns an error if the slice is out of bounds
+    pub fn try_slice(&self, start: usize, len: usize) -> Result<VectorView<'a, T>, MathError> {
+        check_range(start, len, self.len)?;
+        Ok(VectorView::from_parts(
+            self.data,
+            self.offset + start * self.stride,
+            len,
+            self.stride,
+        ))
+    }
+
+    /// returns the view of every `step`-th element starting with the first
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2., 3., 4., 5.]);
+    /// assert_eq!(vector.view().step(2).to_vector(), Vector::new(vec![1., 3., 5.]));
+    /// ```
+    /// note it panics if the step is zero
+    pub fn step(&self, step: usize) -> VectorView<'a, T> {
+        if step == 0 {
+            panic!("the step has to be larger than zero");
+        }
+        VectorView::from_parts(
+            self.data,
+            self.offset,
+            self.len.div_ceil(step),
+            self.stride * step,
+        )
+    }
+
+    /// returns the [dot product] with the other vector
+    ///
+    /// [dot product]: https://en.wikipedia.org/wiki/Dot_product
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn dot_vec<'b>(&self, other: impl Into<VectorView<'b, T>>) -> T
+    where
+        T: 'b,
+    {
+        self.try_dot_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the vectors have not the same len
+    pub fn try_dot_vec<'b>(&self, other: impl Into<VectorView<'b, T>>) -> Result<T, MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_same_len(self.len, other.len)?;
+        Ok((0..self.len).map(|i| self.get(i) * other.get(i)).sum())
+    }
+
+    fn get(&self, index: usize) -> T {
+        self.data[self.offset + index * self.stride]
+    }
+}
+
+impl<'a, T: Scalar> VectorViewMut<'a, T> {
+    /// creates a mutable view of the whole slice
+    pub fn from_slice(data: &'a mut [T]) -> Self {
+        let len = data.len();
+        VectorViewMut::from_parts(data, 0, len, 1)
+    }
+
+    pub(crate) fn from_parts(data: &'a mut [T], offset: usize, len: usize, stride: usize) -> Self {
+        VectorViewMut {
+            data,
+            offset,
+            len,
+            stride,
+        }
+    }
+
+    /// returns a read only view of the same elements
+    pub fn as_view(&self) -> VectorView<'_, T> {
+        VectorView::from_parts(self.data, self.offset, self.len, self.stride)
+    }
+
+    /// returns the number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// returns true if the view has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns the element at the index
+    ///
+    /// note it panics if the index is out of bounds
+    pub fn index(&self, index: usize) -> T {
+        self.as_view().index(index)
+    }
+
+    /// same as `index` but returns an error if the index is out of bounds
+    pub fn try_index(&self, index: usize) -> Result<T, MathError> {
+        self.as_view().try_index(index)
+    }
+
+    /// copies the elements in to a new `Vector`
+    pub fn to_vector(&self) -> Vector<T> {
+        self.as_view().to_vector()
+    }
+
+    /// sets the element at the index
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![1., 2., 3.]);
+    /// vector.view_mut().set_index(1, 5.);
+    /// assert_eq!(vector, Vector::new(vec![1., 5., 3.]));
+    /// ```
+    /// note it panics if the index is out of bounds
+    pub fn set_index(&mut self, index: usize, val: T) {
+        self.try_set_index(index, val)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `set_index` but returns an error if the index is out of bounds
+    pub fn try_set_index(&mut self, index: usize, val: T) -> Result<(), MathError> {
+        check_index(index, self.len)?;
+        *self.get_mut(index) = val;
+        Ok(())
+    }
+
+    /// returns the mutable view of the `len` elements starting at `start`
+    ///
+    /// note it panics if the slice is out of bounds
+    pub fn slice_mut(&mut self, start: usize, len: usize) -> VectorViewMut<'_, T> {
+        self.try_slice_mut(start, len)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `slice_mut` but returns an error if the slice is out of bounds
+    pub fn try_slice_mut(
+        &mut self,
+        start: usize,
+        len: usize,
+    ) -> Result<VectorViewMut<'_, T>, MathError> {
+        check_range(start, len, self.len)?;
+        Ok(VectorViewMut::from_parts(
+            self.data,
+            self.offset + start * self.stride,
+            len,
+            self.stride,
+        ))
+    }
+
+    /// returns the mutable view of every `step`-th element starting with the first
+    ///
+    /// note it panics if the step is zero
+    pub fn step_mut(&mut self, step: usize) -> VectorViewMut<'_, T> {
+        if step == 0 {
+            panic!("the step has to be larger than zero");
+        }
+        VectorViewMut::from_parts(
+            self.data,
+            self.offset,
+            self.len.div_ceil(step),
+            self.stride * step,
+        )
+    }
+
+    /// sets all elements to the value
+    pub fn fill(&mut self, val: T) {
+        self.apply(|_| val);
+    }
+
+    /// copies the elements of the other vector in to this view
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new_zero(2, 2);
+    /// matrix.col_view_mut(1).copy_from(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0., 0.], vec![1., 2.]]));
+    /// ```
+    /// note it panics if the vectors have not the same len
+    pub fn copy_from<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_copy_from(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `copy_from` but returns an error if the vectors have not the same len
+    pub fn try_copy_from<'b>(
+        &mut self,
+        other: impl Into<VectorView<'b, T>>,
+    ) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |_, y| y)
+    }
+
+    /// adds each component of the other vector to this view
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn add_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_add_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `add_vec` but returns an error if the vectors have not the same len
+    pub fn try_add_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x + y)
+    }
+
+    /// subtracts each component of the other vector from this view
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn sub_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_sub_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `sub_vec` but returns an error if the vectors have not the same len
+    pub fn try_sub_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x - y)
+    }
+
+    /// multiplies each component of this view with the component of the other vector
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn mul_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_mul_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `mul_vec` but returns an error if the vectors have not the same len
+    pub fn try_mul_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x * y)
+    }
+
+    /// divides each component of this view by the component of the other vector
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn div_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_div_vec(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `div_vec` but returns an error if the vectors have not the same len
+    pub fn try_div_vec<'b>(&mut self, other: impl Into<VectorView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x / y)
+    }
+
+    /// multiplies each component with the scalar
+    pub fn mul_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x * *scalar);
+    }
+
+    /// divides each component by the scalar
+    pub fn div_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x / *scalar);
+    }
+
+    /// adds the scalar to each component
+    pub fn add_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x + *scalar);
+    }
+
+    /// subtracts the scalar from each component
+    pub fn sub_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x - *scalar);
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[self.offset + index * self.stride]
+    }
+
+    fn apply(&mut self, f: impl Fn(T) -> T) {
+        for i in 0..self.len {
+            let x = self.get_mut(i);
+            *x = f(*x);
+        }
+    }
+
+    fn zip_apply<'b>(
+        &mut self,
+        other: impl Into<VectorView<'b, T>>,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_same_len(self.len, other.len)?;
+        for i in 0..self.len {
+            let x = self.get_mut(i);
+            *x = f(*x, other.get(i));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    pub(crate) fn from_parts(
+        data: &'a [T],
+        offset: usize,
+        cols: usize,
+        rows: usize,
+        strides: (usize, usize),
+    ) -> Self {
+        MatrixView {
+            data,
+            offset,
+            cols,
+            rows,
+            strides,
+        }
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns true if the view has as much rows as cols
+    pub fn is_square(&self) -> bool {
+        self.cols == self.rows
+    }
+
+    /// return index(row, col) from the view
+    ///
+    /// note it panics if the index is out of bounds
+    pub fn index(&self, row: usize, col: usize) -> T {
+        self.try_index(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `index` but returns an error if the index is out of bounds
+    pub fn try_index(&self, row: usize, col: usize) -> Result<T, MathError> {
+        check_index(row, self.cols)?;
+        check_index(col, self.rows)?;
+        Ok(self.get(row, col))
+    }
+
+    /// copies the elements in to a new `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_flatt(self.contiguous().into_owned(), self.cols, self.rows)
+    }
+
+    /// returns the column like `Matrix::col` as a view
+    ///
+    /// note it panics if the column is out of bounds
+    pub fn col(&self, col: usize) -> VectorView<'a, T> {
+        self.try_col(col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `col` but returns an error if the column is out of bounds
+    pub fn try_col(&self, col: usize) -> Result<VectorView<'a, T>, MathError> {
+        check_index(col, self.cols)?;
+        Ok(VectorView::from_parts(
+            self.data,
+            self.offset + col * self.strides.0,
+            self.rows,
+            self.strides.1,
+        ))
+    }
+
+    /// returns the row like `Matrix::row` as a view
+    ///
+    /// note it panics if the row is out of bounds
+    pub fn row(&self, row: usize) -> VectorView<'a, T> {
+        self.try_row(row)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `row` but returns an error if the row is out of bounds
+    pub fn try_row(&self, row: usize) -> Result<VectorView<'a, T>, MathError> {
+        check_index(row, self.rows)?;
+        Ok(VectorView::from_parts(
+            self.data,
+            self.offset + row * self.strides.1,
+            self.cols,
+            self.strides.0,
+        ))
+    }
+
+    /// returns the [main diagonal] `index(i, i)` as a view
+    ///
+    /// [main diagonal]: https://en.wikipedia.org/wiki/Main_diagonal
+    pub fn diagonal(&self) -> VectorView<'a, T> {
+        VectorView::from_parts(
+            self.data,
+            self.offset,
+            self.cols.min(self.rows),
+            self.strides.0 + self.strides.1,
+        )
+    }
+
+    /// returns the block with `cols` and `rows` whose `index(0, 0)` is `index(row, col)` of this view
+    ///
+    /// note it panics if the block does not fit in to this view
+    pub fn submatrix(&self, row: usize, col: usize, cols: usize, rows: usize) -> MatrixView<'a, T> {
+        self.try_submatrix(row, col, cols, rows)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `submatrix` but returns an error if the block does not fit in to this view
+    pub fn try_submatrix(
+        &self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> Result<MatrixView<'a, T>, MathError> {
+        check_range(row, cols, self.cols)?;
+        check_range(col, rows, self.rows)?;
+        Ok(MatrixView::from_parts(
+            self.data,
+            self.offset + row * self.strides.0 + col * self.strides.1,
+            cols,
+            rows,
+            self.strides,
+        ))
+    }
+
+    /// returns the view of every `row_step`-th column and every `col_step`-th row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// let corners = matrix.view().step(2, 2);
+    /// assert_eq!(corners.to_matrix(), Matrix::new(vec![vec![1., 3.], vec![7., 9.]]));
+    /// ```
+    /// note it panics if a step is zero
+    pub fn step(&self, row_step: usize, col_step: usize) -> MatrixView<'a, T> {
+        if row_step == 0 || col_step == 0 {
+            panic!("the step has to be larger than zero");
+        }
+        MatrixView::from_parts(
+            self.data,
+            self.offset,
+            self.cols.div_ceil(row_step),
+            self.rows.div_ceil(col_step),
+            (self.strides.0 * row_step, self.strides.1 * col_step),
+        )
+    }
+
+    /// returns the transposed view which flips rows and cols without copying
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView::from_parts(
+            self.data,
+            self.offset,
+            self.rows,
+            self.cols,
+            (self.strides.1, self.strides.0),
+        )
+    }
+
+    /// multiplies the view with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the len of the vector is not the rows of the view
+    pub fn dot_vec<'b>(&self, vector: impl Into<VectorView<'b, T>>) -> Vector<T>
+    where
+        T: 'b,
+    {
+        self.try_dot_vec(vector)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_vec` but returns an error if the len of the vector is not the rows of the view
+    pub fn try_dot_vec<'b>(
+        &self,
+        vector: impl Into<VectorView<'b, T>>,
+    ) -> Result<Vector<T>, MathError>
+    where
+        T: 'b,
+    {
+        let vector = vector.into();
+        check_vector(self, &vector)?;
+        Ok(Vector::from_vec(
+            (0..self.cols)
+                .map(|i| self.col(i).dot_vec(vector))
+                .collect(),
+        ))
+    }
+
+    /// returns the [matrix product] like `Matrix::dot_mat`
+    ///
+    /// [matrix product]: https://en.wikipedia.org/wiki/Matrix_multiplication
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 0.], vec![3., 4., 0.]]);
+    /// let block = matrix.submatrix(0, 0, 2, 2);
+    /// let square = block.dot_mat(block);
+    /// assert_eq!(square, Matrix::new(vec![vec![7., 10.], vec![15., 22.]]));
+    /// ```
+    /// note it panics if the rows of this view are not the cols of the other matrix
+    pub fn dot_mat<'b>(&self, other: impl Into<MatrixView<'b, T>>) -> Matrix<T>
+    where
+        T: 'b,
+    {
+        self.try_dot_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `dot_mat` but returns an error if the rows of this view are not the cols
+    /// of the other matrix
+    pub fn try_dot_mat<'b>(
+        &self,
+        other: impl Into<MatrixView<'b, T>>,
+    ) -> Result<Matrix<T>, MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_dot(self, &other)?;
+        let (m, k, n) = (self.cols, self.rows, other.rows);
+        let matrix_flatt = dot_blocked(&self.contiguous(), &other.contiguous(), m, k, n);
+        Matrix::try_from_flatt(matrix_flatt, m, n)
+    }
+
+    // returns the elements row by row, this only copies if the view is not contiguous
+    pub(crate) fn contiguous(&self) -> Cow<'a, [T]> {
+        let len = self.cols * self.rows;
+        if len == 0 {
+            Cow::Borrowed(&[])
+        } else if self.strides == (self.rows, 1) {
+            Cow::Borrowed(&self.data[self.offset..self.offset + len])
+        } else {
+            let mut flatt = Vec::with_capacity(len);
+            for i in 0..self.cols {
+                flatt.extend(self.col(i).iter());
+            }
+            Cow::Owned(flatt)
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> T {
+        self.data[self.offset + row * self.strides.0 + col * self.strides.1]
+    }
+}
+
+impl<'a, T: Scalar> MatrixViewMut<'a, T> {
+    pub(crate) fn from_parts(
+        data: &'a mut [T],
+        offset: usize,
+        cols: usize,
+        rows: usize,
+        strides: (usize, usize),
+    ) -> Self {
+        MatrixViewMut {
+            data,
+            offset,
+            cols,
+            rows,
+            strides,
+        }
+    }
+
+    /// returns a read only view of the same elements
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView::from_parts(self.data, self.offset, self.cols, self.rows, self.strides)
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// return index(row, col) from the view
+    ///
+    /// note it panics if the index is out of bounds
+    pub fn index(&self, row: usize, col: usize) -> T {
+        self.as_view().index(row, col)
+    }
+
+    /// same as `index` but returns an error if the index is out of bounds
+    pub fn try_index(&self, row: usize, col: usize) -> Result<T, MathError> {
+        self.as_view().try_index(row, col)
+    }
+
+    /// copies the elements in to a new `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+
+    /// sets the value at index(row, col)
+    ///
+    /// note it panics if the index is out of bounds
+    pub fn set_index(&mut self, row: usize, col: usize, val: T) {
+        self.try_set_index(row, col, val)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `set_index` but returns an error if the index is out of bounds
+    pub fn try_set_index(&mut self, row: usize, col: usize, val: T) -> Result<(), MathError> {
+        check_index(row, self.cols)?;
+        check_index(col, self.rows)?;
+        *self.get_mut(row, col) = val;
+        Ok(())
+    }
+
+    /// returns the column like `Matrix::col` as a mutable view
+    ///
+    /// note it panics if the column is out of bounds
+    pub fn col_mut(&mut self, col: usize) -> VectorViewMut<'_, T> {
+        self.try_col_mut(col)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `col_mut` but returns an error if the column is out of bounds
+    pub fn try_col_mut(&mut self, col: usize) -> Result<VectorViewMut<'_, T>, MathError> {
+        check_index(col, self.cols)?;
+        Ok(VectorViewMut::from_parts(
+            self.data,
+            self.offset + col * self.strides.0,
+            self.rows,
+            self.strides.1,
+        ))
+    }
+
+    /// returns the row like `Matrix::row` as a mutable view
+    ///
+    /// note it panics if the row is out of bounds
+    pub fn row_mut(&mut self, row: usize) -> VectorViewMut<'_, T> {
+        self.try_row_mut(row)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `row_mut` but returns an error if the row is out of bounds
+    pub fn try_row_mut(&mut self, row: usize) -> Result<VectorViewMut<'_, T>, MathError> {
+        check_index(row, self.rows)?;
+        Ok(VectorViewMut::from_parts(
+            self.data,
+            self.offset + row * self.strides.1,
+            self.cols,
+            self.strides.0,
+        ))
+    }
+
+    /// returns the main diagonal `index(i, i)` as a mutable view
+    pub fn diagonal_mut(&mut self) -> VectorViewMut<'_, T> {
+        VectorViewMut::from_parts(
+            self.data,
+            self.offset,
+            self.cols.min(self.rows),
+            self.strides.0 + self.strides.1,
+        )
+    }
+
+    /// returns the mutable block with `cols` and `rows` whose `index(0, 0)` is `index(row, col)`
+    /// of this view
+    ///
+    /// note it panics if the block does not fit in to this view
+    pub fn submatrix_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> MatrixViewMut<'_, T> {
+        self.try_submatrix_mut(row, col, cols, rows)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `submatrix_mut` but returns an error if the block does not fit in to this view
+    pub fn try_submatrix_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        cols: usize,
+        rows: usize,
+    ) -> Result<MatrixViewMut<'_, T>, MathError> {
+        check_range(row, cols, self.cols)?;
+        check_range(col, rows, self.rows)?;
+        Ok(MatrixViewMut::from_parts(
+            self.data,
+            self.offset + row * self.strides.0 + col * self.strides.1,
+            cols,
+            rows,
+            self.strides,
+        ))
+    }
+
+    /// returns the mutable view of every `row_step`-th column and every `col_step`-th row
+    ///
+    /// note it panics if a step is zero
+    pub fn step_mut(&mut self, row_step: usize, col_step: usize) -> MatrixViewMut<'_, T> {
+        if row_step == 0 || col_step == 0 {
+            panic!("the step has to be larger than zero");
+        }
+        MatrixViewMut::from_parts(
+            self.data,
+            self.offset,
+            self.cols.div_ceil(row_step),
+            self.rows.div_ceil(col_step),
+            (self.strides.0 * row_step, self.strides.1 * col_step),
+        )
+    }
+
+    /// sets all elements to the value
+    pub fn fill(&mut self, val: T) {
+        self.apply(|_| val);
+    }
+
+    /// copies the elements of the other matrix in to this view
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new_zero(3, 3);
+    /// let block = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.submatrix_mut(1, 1, 2, 2).copy_from(&block);
+    /// assert_eq!(
+    ///     matrix,
+    ///     Matrix::new(vec![vec![0., 0., 0.], vec![0., 1., 2.], vec![0., 3., 4.]])
+    /// );
+    /// ```
+    /// note it panics if the matrices have not the same cols and rows
+    pub fn copy_from<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_copy_from(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `copy_from` but returns an error if the matrices have not the same cols and rows
+    pub fn try_copy_from<'b>(
+        &mut self,
+        other: impl Into<MatrixView<'b, T>>,
+    ) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |_, y| y)
+    }
+
+    /// adds each component of the other matrix to this view
+    ///
+    /// note it panics if the matrices have not the same cols and rows
+    pub fn add_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_add_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `add_mat` but returns an error if the matrices have not the same cols and rows
+    pub fn try_add_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x + y)
+    }
+
+    /// subtracts each component of the other matrix from this view
+    ///
+    /// note it panics if the matrices have not the same cols and rows
+    pub fn sub_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_sub_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `sub_mat` but returns an error if the matrices have not the same cols and rows
+    pub fn try_sub_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x - y)
+    }
+
+    /// multiplies each component of this view with the component of the other matrix
+    ///
+    /// note it panics if the matrices have not the same cols and rows
+    pub fn mul_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_mul_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `mul_mat` but returns an error if the matrices have not the same cols and rows
+    pub fn try_mul_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x * y)
+    }
+
+    /// divides each component of this view by the component of the other matrix
+    ///
+    /// note it panics if the matrices have not the same cols and rows
+    pub fn div_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>)
+    where
+        T: 'b,
+    {
+        self.try_div_mat(other)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// same as `div_mat` but returns an error if the matrices have not the same cols and rows
+    pub fn try_div_mat<'b>(&mut self, other: impl Into<MatrixView<'b, T>>) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        self.zip_apply(other, |x, y| x / y)
+    }
+
+    /// multiplies each component with the scalar
+    pub fn mul_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x * *scalar);
+    }
+
+    /// divides each component by the scalar
+    pub fn div_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x / *scalar);
+    }
+
+    /// adds the scalar to each component
+    pub fn add_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x + *scalar);
+    }
+
+    /// subtracts the scalar from each component
+    pub fn sub_scalar(&mut self, scalar: &T) {
+        self.apply(|x| x - *scalar);
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> &mut T {
+        &mut self.data[self.offset + row * self.strides.0 + col * self.strides.1]
+    }
+
+    fn apply(&mut self, f: impl Fn(T) -> T) {
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                let x = self.get_mut(i, j);
+                *x = f(*x);
+            }
+        }
+    }
+
+    fn zip_apply<'b>(
+        &mut self,
+        other: impl Into<MatrixView<'b, T>>,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<(), MathError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        check_matrix(&self.as_view(), &other)?;
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                let x = self.get_mut(i, j);
+                *x = f(*x, other.get(i, j));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Scalar> From<&'a Vector<T>> for VectorView<'a, T> {
+    fn from(vector: &'a Vector<T>) -> Self {
+        vector.view()
+    }
+}
+
+impl<'a, 'b: 'a, T: Scalar> From<&'a VectorView<'b, T>> for VectorView<'a, T> {
+    fn from(view: &'a VectorView<'b, T>) -> Self {
+        *view
+    }
+}
+
+impl<'a, 'b: 'a, T: Scalar> From<&'a VectorViewMut<'b, T>> for VectorView<'a, T> {
+    fn from(view: &'a VectorViewMut<'b, T>) -> Self {
+        view.as_view()
+    }
+}
+
+impl<'a, T: Scalar> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> Self {
+        matrix.view()
+    }
+}
+
+impl<'a, 'b: 'a, T: Scalar> From<&'a MatrixView<'b, T>> for MatrixView<'a, T> {
+    fn from(view: &'a MatrixView<'b, T>) -> Self {
+        *view
+    }
+}
+
+impl<'a, 'b: 'a, T: Scalar> From<&'a MatrixViewMut<'b, T>> for MatrixView<'a, T> {
+    fn from(view: &'a MatrixViewMut<'b, T>) -> Self {
+        view.as_view()
+    }
+}
+
+/// the range of indices that is selected by `Matrix::slice`
+///
+/// it is implemented for all ranges of `usize` and for a single `usize` which selects one index
+pub trait SliceRange {
+    /// returns the start and the len of the range in `0..max`
+    /// or an error if it does not fit in to it
+    fn bounds(&self, max: usize) -> Result<(usize, usize), MathError>;
+}
+
+impl SliceRange for usize {
+    fn bounds(&self, max: usize) -> Result<(usize, usize), MathError> {
+        check_index(*self, max)?;
+        Ok((*self, 1))
+    }
+}
+
+macro_rules! impl_slice_range {
+    ($($range:ty),*) => {
+        $(
+            impl SliceRange for $range {
+                fn bounds(&self, max: usize) -> Result<(usize, usize), MathError> {
+                    range_bounds(self.clone(), max)
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>
+);
+
+impl<'a, T: Scalar> Index<usize> for VectorView<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        check_index(index, self.len).unwrap_or_else(|error| panic!("{}", error));
+        &self.data[self.offset + index * self.stride]
+    }
+}
+
+impl<'a, T: Scalar> Index<usize> for VectorViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        check_index(index, self.len).unwrap_or_else(|error| panic!("{}", error));
+        &self.data[self.offset + index * self.stride]
+    }
+}
+
+/// `view[i] = x` writes in to the borrowed data
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let mut diagonal = matrix.diagonal_mut();
+/// diagonal[1] += diagonal[0];
+/// assert_eq!(matrix, Matrix::new(vec![vec![1., 2.], vec![3., 5.]]));
+/// ```
+impl<'a, T: Scalar> IndexMut<usize> for VectorViewMut<'a, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        check_index(index, self.len).unwrap_or_else(|error| panic!("{}", error));
+        self.get_mut(index)
+    }
+}
+
+impl<'a, T: Scalar> Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_index(row, self.cols).unwrap_or_else(|error| panic!("{}", error));
+        check_index(col, self.rows).unwrap_or_else(|error| panic!("{}", error));
+        &self.data[self.offset + row * self.strides.0 + col * self.strides.1]
+    }
+}
+
+impl<'a, T: Scalar> Index<(usize, usize)> for MatrixViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_index(row, self.cols).unwrap_or_else(|error| panic!("{}", error));
+        check_index(col, self.rows).unwrap_or_else(|error| panic!("{}", error));
+        &self.data[self.offset + row * self.strides.0 + col * self.strides.1]
+    }
+}
+
+impl<'a, T: Scalar> IndexMut<(usize, usize)> for MatrixViewMut<'a, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        check_index(row, self.cols).unwrap_or_else(|error| panic!("{}", error));
+        check_index(col, self.rows).unwrap_or_else(|error| panic!("{}", error));
+        self.get_mut(row, col)
+    }
+}
+
+impl<'a, 'b, T: Scalar> PartialEq<VectorView<'b, T>> for VectorView<'a, T> {
+    fn eq(&self, other: &VectorView<'b, T>) -> bool {
+        self.len == other.len && (0..self.len).all(|i| self.get(i) == other.get(i))
+    }
+}
+
+impl<'a, T: Scalar> PartialEq<Vector<T>> for VectorView<'a, T> {
+    fn eq(&self, other: &Vector<T>) -> bool {
+        *self == other.view()
+    }
+}
+
+impl<'a, 'b, T: Scalar> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T> {
+    fn eq(&self, other: &MatrixView<'b, T>) -> bool {
+        self.cols == other.cols
+            && self.rows == other.rows
+            && (0..self.cols).all(|i| (0..self.rows).all(|j| self.get(i, j) == other.get(i, j)))
+    }
+}
+
+impl<'a, T: Scalar> PartialEq<Matrix<T>> for MatrixView<'a, T> {
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        *self == other.view()
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for VectorView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for VectorViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.cols).map(|i| self.col(i)))
+            .finish()
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for MatrixViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+pub(crate) fn check_same_len(expected: usize, actual: usize) -> Result<(), MathError> {
+    if expected != actual {
+        return Err(MathError::ShapeMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+pub(crate) fn check_index(index: usize, len: usize) -> Result<(), MathError> {
+    if index >= len {
+        return Err(MathError::IndexOutOfBounds { index, len });
+    }
+    Ok(())
+}
+
+// checks that the range start..start + len fits in to 0..max without overflowing
+fn check_range(start: usize, len: usize, max: usize) -> Result<(), MathError> {
+    match start.checked_add(len) {
+        Some(end) if end <= max => Ok(()),
+        end => Err(MathError::IndexOutOfBounds {
+            index: end.unwrap_or(usize::MAX),
+            len: max,
+        }),
+    }
+}
+
+// returns the start and the len of the range in 0..max
+fn range_bounds(range: impl RangeBounds<usize>, max: usize) -> Result<(usize, usize), MathError> {
+    // an inclusive end or an exclusive start of usize::MAX is always out of bounds
+    let overflow = MathError::IndexOutOfBounds {
+        index: usize::MAX,
+        len: max,
+    };
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(overflow.clone())?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(overflow)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => max,
+    };
+    if start > end {
+        return Err(MathError::IndexOutOfBounds {
+            index: start,
+            len: end,
+        });
+    }
+    check_range(start, end - start, max)?;
+    Ok((start, end - start))
+}
+
+pub(crate) fn check_vector<T: Scalar>(
+    mat: &MatrixView<T>,
+    vec: &VectorView<T>,
+) -> Result<(), MathError> {
+    if vec.len() != mat.rows() {
+        return Err(MathError::ShapeMismatch {
+            expected: mat.rows(),
+            actual: vec.len(),
+        });
+    }
+    Ok(())
+}
+
+fn check_dot<T: Scalar>(mat1: &MatrixView<T>, mat2: &MatrixView<T>) -> Result<(), MathError> {
+    if mat1.rows() != mat2.cols() {
+        return Err(MathError::ShapeMismatch {
+            expected: mat1.rows(),
+            actual: mat2.cols(),
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn check_matrix<T: Scalar>(
+    mat1: &MatrixView<T>,
+    mat2: &MatrixView<T>,
+) -> Result<(), MathError> {
+    if mat1.rows() != mat2.rows() {
+        return Err(MathError::ShapeMismatch {
+            expected: mat1.rows(),
+            actual: mat2.rows(),
+        });
+    }
+
+    if mat1.cols() != mat2.cols() {
+        return Err(MathError::ShapeMismatch {
+            expected: mat1.cols(),
+            actual: mat2.cols(),
+        });
+    }
+    Ok(())
+}
+
+// size of the square tiles used by `dot_blocked`, 64 * 64 f32 fit comfortably in the L1 cache
+const BLOCK_SIZE: usize = 64;
+
+// multiplies the m * k matrix `a` with the k * n matrix `b` (both flatt and row by row)
+// the loops are tiled so that the blocks of `a`, `b` and the result stay in the cache
+fn dot_blocked<T: Scalar>(a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T> {
+    let mut c = vec![T::zero(); m * n];
+    for i0 in (0..m).step_by(BLOCK_SIZE) {
+        let i1 = (i0 + BLOCK_SIZE).min(m);
+        for l0 in (0..k).step_by(BLOCK_SIZE) {
+            let l1 = (l0 + BLOCK_SIZE).min(k);
+            for j0 in (0..n).step_by(BLOCK_SIZE) {
+                let j1 = (j0 + BLOCK_SIZE).min(n);
+                for i in i0..i1 {
+                    let c_row = &mut c[i * n + j0..i * n + j1];
+                    for l in l0..l1 {
+                        let a_il = a[i * k + l];
+                        let b_row = &b[l * n + j0..l * n + j1];
+                        c_row
+                            .iter_mut()
+                            .zip(b_row)
+                            .for_each(|(c_ij, &b_lj)| *c_ij += a_il * b_lj);
+                    }
+                }
+            }
+        }
+    }
+    c
+}
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// a number that can be stored in a `Vector` or a `Matrix`
+///
+/// this is implemented for all primitive integers and floats,
+/// note the division of integers rounds towards zero
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + Product
+{
+    /// the additive identity
+    fn zero() -> Self;
+
+    /// the multiplicative identity
+    fn one() -> Self;
+}
+
+/// a [field] is a scalar with an exact division and a negation like `f32`, `f64` or a complex number
+///
+/// [field]: https://en.wikipedia.org/wiki/Field_(mathematics)
+pub trait Field: Scalar + Neg<Output = Self> {}
+
+/// a real floating point number, the decompositions are implemented for matrices of it
+///
+/// ## Example
+///
+/// ```rust
+/// use math::scalar::Real;
+/// fn norm<T: Real>(x: T, y: T) -> T {
+///     (x * x + y * y).sqrt()
+/// }
+/// assert_eq!(norm(3_f32, 4.), 5.);
+/// assert_eq!(norm(3_f64, 4.), 5.);
+/// ```
+pub trait Real: Field + PartialOrd {
+    /// the difference between 1 and the next larger number
+    fn epsilon() -> Self;
+
+    /// positive infinity
+    fn infinity() -> Self;
+
+    /// converts the f64 to the nearest number, this is used for constants in the algorithms
+    fn from_f64(x: f64) -> Self;
+
+    /// converts the usize to the nearest number
+    fn from_usize(x: usize) -> Self;
+
+    /// converts the number to f64
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn signum(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_scalar {
+    ($zero:expr, $one:expr, $($t:ty)*) => ($(
+        impl Scalar for $t {
+            fn zero() -> Self {
+                $zero
+            }
+
+            fn one() -> Self {
+                $one
+            }
+        }
+    )*)
+}
+
+impl_scalar!(0, 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_scalar!(0., 1., f32 f64);
+
+macro_rules! impl_real {
+    ($($t:ident)*) => ($(
+        impl Field for $t {}
+
+        impl Real for $t {
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn infinity() -> Self {
+                $t::INFINITY
+            }
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn from_usize(x: usize) -> Self {
+                x as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+        }
+    )*)
+}
+
+impl_real!(f32 f64);
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use math::complex::Complex;
+    use math::linear_algebra::{FormatError, Layout, Matrix, Vector};
+
+    #[test]
+    fn vector_golden() {
+        let bytes = Vector::from_vec(vec![1_i32, -2]).to_bytes();
+        assert_eq!(
+            bytes,
+            vec![
+                b'R', b'S', b'M', b'T', 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, // header
+                2, 0, 0, 0, 0, 0, 0, 0, // len
+                1, 0, 0, 0, 254, 255, 255, 255, // data
+            ]
+        );
+        assert_eq!(
+            Vector::<i32>::from_bytes(&bytes).unwrap(),
+            Vector::from_vec(vec![1, -2])
+        );
+    }
+
+    #[test]
+    fn matrix_golden() {
+        let matrix = Matrix::from_vec(vec![vec![1_u32, 2, 3], vec![4, 5, 6]]);
+        let mut expected = vec![b'R', b'S', b'M', b'T', 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0];
+        expected.extend(&[2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]);
+        for value in 1_u32..=6 {
+            expected.extend(&value.to_le_bytes());
+        }
+        assert_eq!(matrix.to_bytes(), expected);
+
+        let mut transpose = matrix.clone();
+        transpose.transpose();
+        let bytes = transpose.to_bytes();
+        assert_eq!(bytes[8], 1);
+        assert_eq!(
+            &bytes[16..32],
+            &[3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(&bytes[32..], &expected[32..]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut matrix = Matrix::from_vec(vec![vec![1.5_f64, -2.], vec![0.25, 1e300]]);
+        let bytes = matrix.to_bytes();
+        assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), matrix);
+        matrix.transpose();
+        let result = Matrix::<f64>::from_bytes(&matrix.to_bytes()).unwrap();
+        assert_eq!(result, matrix);
+        assert_eq!(result.layout(), Layout::ColMajor);
+
+        let strided =
+            Matrix::from_layout(vec![1., 2., 0., 3., 4., 0.], 2, 2, Layout::Strided(3, 1));
+        let result = Matrix::<f32>::from_bytes(&strided.to_bytes()).unwrap();
+        assert_eq!(result, strided);
+        assert_eq!(result.layout(), Layout::RowMajor);
+
+        let complex = Vector::from_vec(vec![Complex::new(1_f32, -1.), Complex::new(0., 2.)]);
+        assert_eq!(
+            Vector::<Complex<f32>>::from_bytes(&complex.to_bytes()).unwrap(),
+            complex
+        );
+        assert_eq!(
+            Matrix::<f32>::from_bytes(&Matrix::<f32>::zero(0, 3).to_bytes()).unwrap(),
+            Matrix::zero(0, 3)
+        );
+    }
+
+    #[test]
+    fn sequence() {
+        let mut bytes = Vec::new();
+        let vector = Vector::new(vec![1., 2.]);
+        let matrix = Matrix::new(vec![vec![3.], vec![4.]]);
+        vector.to_writer(&mut bytes).unwrap();
+        matrix.to_writer(&mut bytes).unwrap();
+        let mut reader = &bytes[..];
+        assert_eq!(Vector::from_reader(&mut reader).unwrap(), vector);
+        assert_eq!(Matrix::from_reader(&mut reader).unwrap(), matrix);
+        assert!(reader.is_empty());
+        assert!(matches!(
+            Vector::<f32>::from_bytes(&bytes),
+            Err(FormatError::TrailingBytes { len: 40 })
+        ));
+    }
+
+    #[test]
+    fn big_endian() {
+        let mut bytes = vec![b'R', b'S', b'M', b'T', 1, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0];
+        bytes.extend(&1_u64.to_be_bytes());
+        bytes.extend(&2.5_f64.to_be_bytes());
+        assert_eq!(
+            Vector::<f64>::from_bytes(&bytes).unwrap(),
+            Vector::from_vec(vec![2.5])
+        );
+    }
+
+    #[test]
+    fn corrupt() {
+        let bytes = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]).to_bytes();
+        let corrupt = |index: usize, value: u8| {
+            let mut bytes = bytes.clone();
+            bytes[index] = value;
+            Matrix::<f32>::from_bytes(&bytes)
+        };
+        assert!(matches!(corrupt(0, b'X'), Err(FormatError::BadMagic)));
+        assert!(matches!(
+            corrupt(4, 2),
+            Err(FormatError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            corrupt(5, 7),
+            Err(FormatError::InvalidHeader {
+                field: "endianness",
+                value: 7
+            })
+        ));
+        assert!(matches!(
+            corrupt(6, 0),
+            Err(FormatError::InvalidHeader {
+                field: "kind",
+                value: 0
+            })
+        ));
+        assert!(matches!(
+            corrupt(8, 2),
+            Err(FormatError::InvalidHeader {
+                field: "layout",
+                value: 2
+            })
+        ));
+        assert!(matches!(
+            corrupt(9, 1),
+            Err(FormatError::InvalidHeader {
+                field: "reserved",
+                value: 1
+            })
+        ));
+        assert!(matches!(
+            corrupt(15, 3),
+            Err(FormatError::InvalidHeader {
+                field: "reserved",
+                value: 3
+            })
+        ));
+        assert!(matches!(
+            Matrix::<f64>::from_bytes(&bytes),
+            Err(FormatError::WrongDtype {
+                expected: 6,
+                actual: 5
+            })
+        ));
+        assert!(matches!(
+            Matrix::<f32>::from_bytes(&bytes[..10]),
+            Err(FormatError::Truncated {
+                expected: 16,
+                actual: 10
+            })
+        ));
+        assert!(matches!(
+            Matrix::<f32>::from_bytes(&bytes[..20]),
+            Err(FormatError::Truncated {
+                expected: 16,
+                actual: 4
+            })
+        ));
+        // a huge dimension is reported as truncated input without allocating it
+        assert!(matches!(
+            corrupt(23, 1),
+            Err(FormatError::Truncated {
+                expected: 0x0800_0000_0000_0010,
+                actual: 16
+            })
+        ));
+        assert!(matches!(
+            corrupt(31, 255),
+            Err(FormatError::InvalidHeader { .. })
+        ));
+        let error = corrupt(0, 0).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the input does not start with the magic bytes"
+        );
+    }
+}
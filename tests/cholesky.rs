@@ -0,0 +1,169 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{assert_mat_delta, transpose};
+    use math::linear_algebra::CholeskyDecomposition;
+    use math::linear_algebra::LdltDecomposition;
+    use math::linear_algebra::MathError;
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Vector;
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![4., 12., -16.],
+            vec![12., 37., -43.],
+            vec![-16., -43., 98.],
+        ])
+    }
+
+    #[test]
+    fn cholesky() {
+        let cholesky = CholeskyDecomposition::new(&matrix());
+        assert_eq!(
+            cholesky.l(),
+            Matrix::new(vec![vec![2., 0., 0.], vec![6., 1., 0.], vec![-8., 5., 3.]])
+        );
+        assert_delta!(cholesky.det(), 36., 1e-3);
+        assert_delta!(cholesky.log_det(), 36_f32.ln(), 1e-5);
+    }
+
+    #[test]
+    fn cholesky_reconstruct() {
+        let a = Matrix::new_rand(6, 6);
+        let mut spd = a.dot_mat(&transpose(&a));
+        for i in 0..6 {
+            spd.set_index(i, i, spd.index(i, i) + 1.);
+        }
+        let l = spd.cholesky().l();
+        assert_mat_delta(&l.dot_mat(&transpose(&l)), &spd, 1e-5);
+        assert_delta!(spd.cholesky().det(), spd.det(), 1e-3);
+    }
+
+    #[test]
+    fn cholesky_solve() {
+        let matrix = matrix();
+        let x = Vector::new(vec![1., -2., 0.5]);
+        let solution = matrix.cholesky().solve(&matrix.dot_vec(&x));
+        solution
+            .vec()
+            .iter()
+            .zip(x.vec())
+            .for_each(|(a, b)| assert_delta!(a, b, 1e-4));
+
+        let x = Matrix::new(vec![vec![1., 0.], vec![-2., 3.], vec![0.5, 1.]]);
+        assert_mat_delta(&matrix.cholesky().solve_mat(&matrix.dot_mat(&x)), &x, 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not positive definite")]
+    fn cholesky_not_positive_definite_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+        let _ = matrix.cholesky();
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has to be symmetric")]
+    fn cholesky_not_symmetric_panic() {
+        let matrix = Matrix::new(vec![vec![4., 2.], vec![1., 5.]]);
+        let _ = matrix.cholesky();
+    }
+
+    #[test]
+    fn ldlt() {
+        let ldlt = LdltDecomposition::new(&matrix());
+        assert_eq!(
+            ldlt.l(),
+            Matrix::new(vec![vec![1., 0., 0.], vec![3., 1., 0.], vec![-4., 5., 1.]])
+        );
+        assert_eq!(ldlt.d(), Vector::new(vec![4., 1., 9.]));
+        assert_delta!(ldlt.det(), 36., 1e-3);
+        assert_delta!(ldlt.log_det(), 36_f32.ln(), 1e-5);
+
+        let x = Vector::new(vec![1., -2., 0.5]);
+        let solution = ldlt.solve(&matrix().dot_vec(&x));
+        solution
+            .vec()
+            .iter()
+            .zip(x.vec())
+            .for_each(|(a, b)| assert_delta!(a, b, 1e-4));
+    }
+
+    #[test]
+    fn ldlt_semidefinite() {
+        // u * u^T + w * w^T with u = (1, 1, 2) and w = (0, 1, 1) has rank 2
+        let matrix = Matrix::new(vec![vec![1., 1., 2.], vec![1., 2., 3.], vec![2., 3., 5.]]);
+        let ldlt = matrix.ldlt();
+        assert!(ldlt.is_singular());
+        assert_eq!(ldlt.d().index(2), 0.);
+        assert_eq!(ldlt.log_det(), f32::NEG_INFINITY);
+
+        let (l, d) = (ldlt.l(), ldlt.d());
+        let mut ld = l.clone();
+        for i in 0..3 {
+            for j in 0..3 {
+                ld.set_index(i, j, l.index(i, j) * d.index(j));
+            }
+        }
+        assert_mat_delta(&ld.dot_mat(&transpose(&l)), &matrix, 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not positive definite")]
+    fn ldlt_indefinite_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+        let _ = matrix.ldlt();
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not positive definite")]
+    fn ldlt_zero_pivot_panic() {
+        // the zero pivot leaves a nonzero value below it so the matrix is indefinite
+        let matrix = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+        let _ = matrix.ldlt();
+    }
+
+    #[test]
+    fn ldlt_zero_pivot_semidefinite() {
+        // a zero pivot whose whole column vanishes is semidefinite
+        let matrix = Matrix::new(vec![vec![0., 0., 0.], vec![0., 2., 1.], vec![0., 1., 1.]]);
+        let ldlt = LdltDecomposition::new(&matrix);
+        assert_eq!(ldlt.d(), Vector::new(vec![0., 2., 0.5]));
+        assert_eq!(
+            ldlt.l(),
+            Matrix::new(vec![vec![1., 0., 0.], vec![0., 1., 0.], vec![0., 0.5, 1.]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn ldlt_singular_panic() {
+        let matrix = Matrix::new(vec![vec![4., 2.], vec![2., 1.]]);
+        let _ = matrix.ldlt().solve(&Vector::new(vec![1., 1.]));
+    }
+
+    #[test]
+    fn try_new() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+        assert_eq!(
+            CholeskyDecomposition::try_new(&matrix),
+            Err(MathError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            LdltDecomposition::try_new(&matrix),
+            Err(MathError::NotPositiveDefinite)
+        );
+        let matrix = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+        assert_eq!(
+            LdltDecomposition::try_new(&matrix),
+            Err(MathError::NotPositiveDefinite)
+        );
+
+        let ldlt = LdltDecomposition::try_new(&Matrix::new(vec![vec![4., 2.], vec![2., 1.]]));
+        assert_eq!(
+            ldlt.unwrap().try_solve(&Vector::new(vec![1., 1.])),
+            Err(MathError::Singular)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+// helpers that are shared by the integration tests, not every test uses all of them
+#![allow(dead_code, unused_macros)]
+
+use math::complex::Complex;
+use math::linear_algebra::{Matrix, Vector};
+use math::scalar::Scalar;
+use std::fmt::Display;
+
+macro_rules! assert_delta {
+    ($x:expr, $y:expr, $d:expr) => {
+        if ($x - $y).abs() > $d {
+            panic!("{} is not {} +- {}", $x, $y, $d);
+        }
+    };
+}
+
+/// a real or complex scalar whose distance to another scalar can be compared with a real tolerance
+pub trait Delta: Scalar + Display {
+    type Real: Scalar + PartialOrd + Display;
+
+    fn delta(self, other: Self) -> Self::Real;
+    fn conj(self) -> Self;
+    fn from_real(value: Self::Real) -> Self;
+}
+
+macro_rules! impl_delta_real {
+    ($($t:ty)*) => ($(
+        impl Delta for $t {
+            type Real = $t;
+
+            fn delta(self, other: Self) -> $t {
+                (self - other).abs()
+            }
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn from_real(value: $t) -> Self {
+                value
+            }
+        }
+    )*)
+}
+
+impl_delta_real!(f32 f64);
+
+impl Delta for Complex<f64> {
+    type Real = f64;
+
+    fn delta(self, other: Self) -> f64 {
+        (self - other).abs()
+    }
+
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+
+    fn from_real(value: f64) -> Self {
+        Complex::new(value, 0.)
+    }
+}
+
+pub fn assert_mat_delta<T: Delta>(a: &Matrix<T>, b: &Matrix<T>, d: T::Real) {
+    assert_eq!(a.cols(), b.cols());
+    assert_eq!(a.rows(), b.rows());
+    a.matrix_flatt()
+        .iter()
+        .zip(b.matrix_flatt())
+        .for_each(|(&x, y)| {
+            if x.delta(y) > d {
+                panic!("{} is not {} +- {}", x, y, d);
+            }
+        });
+}
+
+pub fn transpose<T: Scalar>(matrix: &Matrix<T>) -> Matrix<T> {
+    let mut matrix = matrix.clone();
+    matrix.transpose();
+    matrix
+}
+
+// the transpose with conjugated values which is the transpose for real matrices
+pub fn adjoint<T: Delta>(matrix: &Matrix<T>) -> Matrix<T> {
+    let mut matrix = transpose(matrix);
+    for i in 0..matrix.cols() {
+        for j in 0..matrix.rows() {
+            let value = matrix.index(i, j).conj();
+            matrix.set_index(i, j, value);
+        }
+    }
+    matrix
+}
+
+pub fn identity<T: Scalar>(n: usize) -> Matrix<T> {
+    let mut matrix = Matrix::zero(n, n);
+    for i in 0..n {
+        matrix.set_index(i, i, T::one());
+    }
+    matrix
+}
+
+// checks `A = V * D * V^H` and `V^H * V = I` for the eigenvectors V and the eigenvalues
+// on the diagonal of D which have to be in descending order
+pub fn assert_eigen_decomposition<T: Delta>(
+    matrix: &Matrix<T>,
+    vectors: &Matrix<T>,
+    values: &Vector<T::Real>,
+    d: T::Real,
+) {
+    let n = matrix.rows();
+    let mut vd = vectors.clone();
+    for i in 0..n {
+        for j in 0..n {
+            vd.set_index(i, j, vectors.index(i, j) * T::from_real(values.index(j)));
+        }
+    }
+    assert_mat_delta(&vd.dot_mat(&adjoint(vectors)), matrix, d);
+    assert_mat_delta(&adjoint(vectors).dot_mat(vectors), &identity(n), d);
+
+    for i in 1..n {
+        assert!(values.index(i - 1) >= values.index(i));
+    }
+}
@@ -0,0 +1,173 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_eigen_decomposition;
+    use math::complex::Complex;
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Vector;
+    use math::linear_algebra::MathError;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    fn hermitian(n: usize) -> Matrix<Complex<f64>> {
+        let re = Matrix::new_rand(n, n);
+        let im = Matrix::new_rand(n, n);
+        let mut flatt = vec![Complex::new(0., 0.); n * n];
+        for i in 0..n {
+            for j in 0..n {
+                let z = c(re.index(i, j) as f64, im.index(i, j) as f64);
+                let w = c(re.index(j, i) as f64, im.index(j, i) as f64);
+                flatt[i * n + j] = z + w.conj();
+            }
+        }
+        Matrix::from_flatt(flatt, n, n)
+    }
+
+    fn conjugate_transpose(matrix: &Matrix<Complex<f64>>) -> Matrix<Complex<f64>> {
+        let mut matrix = matrix.clone();
+        matrix.conjugate_transpose();
+        matrix
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = c(1., 2.);
+        let b = c(3., -1.);
+        assert_eq!(a + b, c(4., 1.));
+        assert_eq!(a - b, c(-2., 3.));
+        assert_eq!(a * b, c(5., 5.));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(-a, c(-1., -2.));
+        assert_eq!(a * 2., c(2., 4.));
+        assert_eq!(a / 2., c(0.5, 1.));
+        assert_eq!(a * a.conj(), c(a.norm_sqr(), 0.));
+        assert_eq!(a * a.inv(), c(1., 0.));
+
+        let mut x = a;
+        x += b;
+        x *= b;
+        x -= a;
+        x /= c(0., 1.);
+        assert_eq!(x, ((a + b) * b - a) / c(0., 1.));
+
+        let values = [a, b, c(0., 1.)];
+        assert_eq!(values.iter().copied().sum::<Complex<f64>>(), c(4., 2.));
+        assert_eq!(values.iter().copied().product::<Complex<f64>>(), c(-5., 5.));
+    }
+
+    #[test]
+    fn functions() {
+        let z = c(1.5, -0.5);
+        assert_delta!(z.exp().ln(), z, 1e-15);
+        assert_delta!(z.sqrt() * z.sqrt(), z, 1e-15);
+        assert_delta!(z.powf(3.), z * z * z, 1e-14);
+
+        let (r, theta) = z.to_polar();
+        assert_delta!(Complex::from_polar(r, theta), z, 1e-15);
+        assert_delta!(r, 2.5_f64.sqrt(), 1e-15);
+
+        assert!(c(-4., -1e-9).sqrt().im < 0.);
+        assert!(c(f64::NAN, 0.).is_nan());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(c(1., 2.).to_string(), "1+2i");
+        assert_eq!(c(-1., -2.).to_string(), "-1-2i");
+        assert_eq!(format!("{:.1}", c(0.26, -0.74)), "0.3-0.7i");
+    }
+
+    #[test]
+    fn vector() {
+        let a = Vector::from_vec(vec![c(1., 1.), c(2., 0.)]);
+        let b = Vector::from_vec(vec![c(0., 1.), c(1., -1.)]);
+        assert_eq!(a.inner(&b), c(3., -1.));
+        assert_eq!(b.inner(&a), c(3., 1.));
+        assert_eq!(a.dot_vec(&b), c(1., -1.));
+        assert_delta!(a.norm(), 6_f64.sqrt(), 1e-15);
+        assert_eq!(a.re(), Vector::from_vec(vec![1., 2.]));
+        assert_eq!(a.im(), Vector::from_vec(vec![1., 0.]));
+        assert_eq!(
+            a.try_inner(&Vector::from_vec(vec![c(1., 0.)])),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn conjugate_transpose_dot() {
+        let matrix = Matrix::from_vec(vec![
+            vec![c(1., 1.), c(0., 2.), c(3., 0.)],
+            vec![c(2., -1.), c(1., 0.), c(0., -1.)],
+        ]);
+        let product = conjugate_transpose(&matrix).dot_mat(&matrix);
+        assert!(product.is_hermitian());
+        assert!(!matrix.is_hermitian());
+        for i in 0..product.rows() {
+            assert!(product.index(i, i).re >= 0.);
+        }
+    }
+
+    #[test]
+    fn hermitian_eigen() {
+        for &n in &[1, 2, 3, 6, 10] {
+            let matrix = hermitian(n);
+            assert!(matrix.is_hermitian());
+            let eigen = matrix.hermitian_eigen();
+            assert_eigen_decomposition(&matrix, &eigen.eigenvectors(), &eigen.eigenvalues(), 1e-10);
+        }
+    }
+
+    #[test]
+    fn hermitian_eigen_real() {
+        let matrix = Matrix::from_vec(vec![
+            vec![2_f64, -1., 0.],
+            vec![-1., 2., -1.],
+            vec![0., -1., 2.],
+        ]);
+        let expected = matrix.symmetric_eigen().eigenvalues();
+        let values = matrix.to_complex().hermitian_eigen().eigenvalues();
+        for i in 0..3 {
+            assert_delta!(values.index(i), expected.index(i), 1e-12);
+        }
+    }
+
+    #[test]
+    fn hermitian_eigen_not_hermitian() {
+        let matrix = Matrix::from_vec(vec![vec![c(1., 1.)]]);
+        assert_eq!(matrix.try_hermitian_eigen(), Err(MathError::NotSymmetric));
+        let matrix = Matrix::from_vec(vec![vec![c(1., 0.), c(2., 0.)]]);
+        assert_eq!(
+            matrix.try_hermitian_eigen(),
+            Err(MathError::NotSquare { cols: 1, rows: 2 })
+        );
+    }
+
+    #[test]
+    fn complex_eigen() {
+        let matrix = Matrix::from_vec(vec![
+            vec![1_f64, -2., 0.],
+            vec![2., 1., 0.],
+            vec![0., 0., 3.],
+        ]);
+        let eigen = matrix.eigen();
+        let values = eigen.complex_eigenvalues();
+        let a = matrix.to_complex();
+        for i in 0..3 {
+            let vector = eigen.complex_eigenvector(i);
+            let left = a.dot_vec(&vector);
+            let mut right = vector.clone();
+            right.mul_scalar(&values.index(i));
+            left.vec()
+                .iter()
+                .zip(right.vec())
+                .for_each(|(&x, y)| assert_delta!(x, y, 1e-12));
+        }
+    }
+}
@@ -0,0 +1,259 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{assert_eigen_decomposition, assert_mat_delta};
+    use math::linear_algebra::Eigen;
+    use math::linear_algebra::MathError;
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Vector;
+
+    fn symmetric(n: usize) -> Matrix {
+        let a = Matrix::new_rand(n, n);
+        let mut flatt = a.matrix_flatt();
+        for i in 0..n {
+            for j in 0..n {
+                flatt[i * n + j] = a.index(i, j) + a.index(j, i);
+            }
+        }
+        Matrix::new_flatt(flatt, n, n)
+    }
+
+    #[test]
+    fn known() {
+        let matrix = Matrix::new(vec![
+            vec![2., -1., 0.],
+            vec![-1., 2., -1.],
+            vec![0., -1., 2.],
+        ]);
+        let eigen = matrix.symmetric_eigen();
+        let sqrt2 = 2_f32.sqrt();
+        let values = eigen.eigenvalues();
+        assert_delta!(values.index(0), 2. + sqrt2, 1e-5);
+        assert_delta!(values.index(1), 2., 1e-5);
+        assert_delta!(values.index(2), 2. - sqrt2, 1e-5);
+
+        let expected = [
+            [0.5, -sqrt2 / 2., 0.5],
+            [sqrt2 / 2., 0., -sqrt2 / 2.],
+            [0.5, sqrt2 / 2., 0.5],
+        ];
+        for (i, vec) in expected.iter().enumerate() {
+            eigen
+                .eigenvector(i)
+                .vec()
+                .iter()
+                .zip(vec.iter())
+                .for_each(|(a, b)| assert_delta!(a.abs(), b.abs(), 1e-5));
+        }
+        assert_eigen_decomposition(&matrix, &eigen.eigenvectors(), &eigen.eigenvalues(), 1e-5);
+    }
+
+    #[test]
+    fn random() {
+        for &n in [1, 2, 5, 12].iter() {
+            let matrix = symmetric(n);
+            let eigen = matrix.symmetric_eigen();
+            assert_eigen_decomposition(&matrix, &eigen.eigenvectors(), &eigen.eigenvalues(), 1e-4);
+        }
+    }
+
+    #[test]
+    fn rank_deficient() {
+        // u * u^T only has the eigenvalue |u|^2 with the eigenvector u
+        let u = [1., 2., 2.];
+        let matrix = Matrix::new(
+            u.iter()
+                .map(|a| u.iter().map(|b| a * b).collect())
+                .collect(),
+        );
+        let eigen = matrix.symmetric_eigen();
+        let values = eigen.eigenvalues();
+        assert_delta!(values.index(0), 9., 1e-5);
+        assert_delta!(values.index(1), 0., 1e-5);
+        assert_delta!(values.index(2), 0., 1e-5);
+
+        let (val, vec) = eigen.dominant();
+        assert_delta!(val, 9., 1e-5);
+        vec.vec()
+            .iter()
+            .zip(u.iter())
+            .for_each(|(a, b)| assert_delta!(a, b / 3., 1e-5));
+        assert_eigen_decomposition(&matrix, &eigen.eigenvectors(), &eigen.eigenvalues(), 1e-5);
+    }
+
+    #[test]
+    fn eigen_val() {
+        let matrix = Matrix::new(vec![vec![-5., 1.], vec![1., 2.]]);
+        let (val, vec) = matrix.symmetric_eigen().dominant();
+        assert_eq!(matrix.eigen_val(), val);
+        assert_eq!(matrix.eigen_vec(), vec);
+        assert!(val < 0.);
+
+        let mut av = matrix.dot_vec(&vec);
+        av.sub_vec(&{
+            let mut vec = vec.clone();
+            vec.mul_scalar(&val);
+            vec
+        });
+        av.vec().iter().for_each(|x| assert_delta!(x, 0., 1e-5));
+    }
+
+    #[test]
+    fn eigenvector() {
+        let matrix = Matrix::new(vec![vec![4., 1.], vec![1., 4.]]);
+        let eigen = matrix.symmetric_eigen();
+        let s = 0.5_f32.sqrt();
+        let expected = [Vector::new(vec![s, s]), Vector::new(vec![s, -s])];
+        for (i, vec) in expected.iter().enumerate() {
+            eigen
+                .eigenvector(i)
+                .vec()
+                .iter()
+                .zip(vec.vec())
+                .for_each(|(a, b)| assert_delta!(a.abs(), b.abs(), 1e-6));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has to be symmetric")]
+    fn not_symmetric_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let _ = matrix.eigen_val();
+    }
+
+    #[test]
+    fn try_eigen_val() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(matrix.try_eigen_val(), Err(MathError::NotSymmetric));
+        assert_eq!(matrix.try_eigen_vec(), Err(MathError::NotSymmetric));
+
+        let matrix = Matrix::new(vec![vec![1., 0.], vec![0., 3.]]);
+        assert_eq!(matrix.try_eigen_val(), Ok(matrix.eigen_val()));
+        assert_eq!(matrix.try_eigen_vec(), Ok(Vector::new(vec![0., 1.])));
+    }
+
+    fn assert_general(matrix: &Matrix, eigen: &Eigen, d: f32) {
+        let v = eigen.eigenvectors();
+        assert_mat_delta(&matrix.dot_mat(&v), &v.dot_mat(&eigen.d()), d);
+
+        for i in 0..matrix.rows() {
+            let (val_re, val_im) = eigen.eigenvalue(i);
+            let (vec_re, vec_im) = eigen.eigenvector(i);
+            let len = vec_re.dot_vec(&vec_re) + vec_im.dot_vec(&vec_im);
+            assert_delta!(len, 1., 1e-5);
+
+            // A * (re + im * i) = (val_re + val_im * i) * (re + im * i)
+            let (a_re, a_im) = (matrix.dot_vec(&vec_re), matrix.dot_vec(&vec_im));
+            for k in 0..matrix.rows() {
+                let (re, im) = (vec_re.index(k), vec_im.index(k));
+                assert_delta!(a_re.index(k), val_re * re - val_im * im, d);
+                assert_delta!(a_im.index(k), val_im * re + val_re * im, d);
+            }
+        }
+    }
+
+    fn sorted_eigenvalues(eigen: &Eigen) -> Vec<(f32, f32)> {
+        let mut values: Vec<(f32, f32)> = (0..eigen.real_eigenvalues().len())
+            .map(|i| eigen.eigenvalue(i))
+            .collect();
+        values.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        values
+    }
+
+    #[test]
+    fn general_real() {
+        let matrix = Matrix::new(vec![vec![4., 1., 2.], vec![0., 3., 1.], vec![0., 0., -2.]]);
+        let eigen = matrix.eigen();
+        assert!(eigen.is_real());
+        let values = sorted_eigenvalues(&eigen);
+        assert_delta!(values[0].0, -2., 1e-5);
+        assert_delta!(values[1].0, 3., 1e-5);
+        assert_delta!(values[2].0, 4., 1e-5);
+        assert_general(&matrix, &eigen, 1e-4);
+    }
+
+    #[test]
+    fn general_complex() {
+        // companion matrix of (x - 2) * (x^2 + 2x + 5) = x^3 + x - 10 with the roots 2 and -1 ± 2i
+        let matrix = Matrix::new(vec![vec![0., 0., 10.], vec![1., 0., -1.], vec![0., 1., 0.]]);
+        let eigen = Eigen::new(&matrix);
+        assert!(!eigen.is_real());
+        let values = sorted_eigenvalues(&eigen);
+        assert_delta!(values[0].0, -1., 1e-4);
+        assert_delta!(values[0].1, -2., 1e-4);
+        assert_delta!(values[1].0, -1., 1e-4);
+        assert_delta!(values[1].1, 2., 1e-4);
+        assert_delta!(values[2].0, 2., 1e-4);
+        assert_delta!(values[2].1, 0., 1e-4);
+        assert_general(&matrix, &eigen, 1e-4);
+    }
+
+    #[test]
+    fn general_random() {
+        for &n in [1, 2, 4, 7, 15].iter() {
+            let matrix = Matrix::new_rand(n, n);
+            let eigen = matrix.eigen();
+            assert_general(&matrix, &eigen, 1e-4);
+
+            let values = Eigen::new_without_vectors(&matrix);
+            assert!(!values.has_vectors());
+            sorted_eigenvalues(&values)
+                .iter()
+                .zip(sorted_eigenvalues(&eigen).iter())
+                .for_each(|(a, b)| {
+                    assert_delta!(a.0, b.0, 1e-5);
+                    assert_delta!(a.1, b.1, 1e-5);
+                });
+
+            // the sum of the eigenvalues is the trace
+            let trace: f32 = (0..n).map(|i| matrix.index(i, i)).sum();
+            let sum: f32 = eigen.real_eigenvalues().vec().iter().sum();
+            assert_delta!(sum, trace, 1e-4);
+        }
+    }
+
+    #[test]
+    fn general_symmetric() {
+        let matrix = symmetric(6);
+        let mut general = sorted_eigenvalues(&matrix.eigen());
+        general.reverse();
+        general
+            .iter()
+            .zip(matrix.symmetric_eigen().eigenvalues().vec())
+            .for_each(|(a, b)| {
+                assert_delta!(a.0, b, 1e-4);
+                assert_eq!(a.1, 0.);
+            });
+    }
+
+    #[test]
+    fn general_not_finite() {
+        for &x in [f32::NAN, f32::INFINITY].iter() {
+            let matrix = Matrix::new(vec![vec![x, 1.], vec![0., 2.]]);
+            assert_eq!(
+                matrix.try_eigen().map(|_| ()),
+                Err(MathError::NoConvergence { iterations: 0 })
+            );
+            assert_eq!(
+                Eigen::try_new_without_vectors(&matrix).map(|_| ()),
+                Err(MathError::NoConvergence { iterations: 0 })
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the eigenvectors were not computed")]
+    fn general_without_vectors_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let _ = Eigen::new_without_vectors(&matrix).eigenvectors();
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has to be a square matrix")]
+    fn general_not_square_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![3., 4., 5.]]);
+        let _ = matrix.eigen();
+    }
+}
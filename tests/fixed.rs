@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{
+        Mat2, Mat3, MathError, Matrix, MatrixView, SMatrix, Vec2, Vec3, Vec4, Vector, VectorView,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn copy_and_ops() {
+        let a = Vec3::new([1., 2., 3.]);
+        let b = a;
+        assert_eq!(a + b, Vec3::new([2., 4., 6.]));
+        assert_eq!(a - b, Vec3::zero());
+        assert_eq!(a * b, Vec3::new([1., 4., 9.]));
+        assert_eq!(a / b, Vec3::new([1., 1., 1.]));
+        assert_eq!(2. * a, a * 2.);
+        assert_eq!(-a, Vec3::new([-1., -2., -3.]));
+        let mut c = a;
+        c += b;
+        c *= 0.5;
+        assert_eq!(c, a);
+        assert_eq!(
+            Vec3::<i32>::new([1, 0, 0]).cross_vec(Vec3::new([0, 1, 0])),
+            Vec3::new([0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn components_and_swizzles() {
+        let vector = Vec4::new([1., 2., 3., 4.]);
+        assert_eq!(
+            (vector.x(), vector.y(), vector.z(), vector.w()),
+            (1., 2., 3., 4.)
+        );
+        assert_eq!(vector.xy(), Vec2::new([1., 2.]));
+        assert_eq!(vector.xzy(), Vec3::new([1., 3., 2.]));
+        assert_eq!(vector.wwx(), Vec3::new([4., 4., 1.]));
+        assert_eq!(Vec2::new([5., 6.]).yx(), Vec2::new([6., 5.]));
+        assert_eq!(vector.swizzle([2, 2, 0, 1]), Vec4::new([3., 3., 1., 2.]));
+    }
+
+    #[test]
+    fn real() {
+        let mut vector = Vec2::new([3., 4.]);
+        assert_eq!(vector.mag(), 5.);
+        assert_eq!(vector.dist(Vec2::zero()), 5.);
+        vector.unit();
+        assert_eq!(vector, Vec2::new([0.6, 0.8]));
+    }
+
+    #[test]
+    fn matrix_products() {
+        let a = SMatrix::new([[1., 2., 3.], [4., 5., 6.]]);
+        let b = SMatrix::new([[7., 8.], [9., 10.], [11., 12.]]);
+        let product = Matrix::from(a).dot_mat(&Matrix::from(b));
+        assert_eq!(Matrix::from(a * b), product);
+        assert_eq!(a * b, a.dot_mat(b));
+        let vector = Vec3::new([1., 0., -1.]);
+        assert_eq!(
+            (a * vector).to_vector(),
+            Matrix::from(a).dot_vec(&vector.to_vector())
+        );
+        assert_eq!(Mat3::identity() * b, b);
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.transpose()[(2, 1)], a[(1, 2)]);
+    }
+
+    #[test]
+    fn matrix_ops() {
+        let mut a = Mat2::new([[1., 2.], [3., 4.]]);
+        let b = a;
+        assert_eq!(a + b, a * 2.);
+        assert_eq!(a - b, Mat2::zero());
+        assert_eq!(-a, a * -1.);
+        assert_eq!(2. * a, a + b);
+        a[(0, 1)] = 5.;
+        a += b;
+        assert_eq!(a, Mat2::new([[2., 7.], [6., 8.]]));
+        a /= 2.;
+        assert_eq!(a.index(0, 1), 3.5);
+    }
+
+    #[test]
+    fn vector_conversions() {
+        let vector = Vector::new(vec![1., 2., 3.]);
+        let fixed = Vec3::try_from(&vector).unwrap();
+        assert_eq!(Vector::from(fixed), vector);
+        assert_eq!(<[f32; 3]>::from(fixed), [1., 2., 3.]);
+        assert_eq!(Vec3::from([1., 2., 3.]), fixed);
+        assert_eq!(
+            Vec4::try_from(vector),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(VectorView::from(&fixed).index(2), 3.);
+    }
+
+    #[test]
+    fn matrix_conversions() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let fixed = SMatrix::<2, 3>::try_from(&matrix).unwrap();
+        assert_eq!(fixed, SMatrix::new([[1., 2., 3.], [4., 5., 6.]]));
+        assert_eq!(Matrix::from(fixed), matrix);
+        assert_eq!(
+            Matrix::from(fixed.transpose()),
+            Matrix::from_layout(
+                vec![1., 2., 3., 4., 5., 6.],
+                3,
+                2,
+                math::linear_algebra::Layout::ColMajor
+            )
+        );
+        assert_eq!(MatrixView::from(&fixed), matrix.view());
+        assert_eq!(
+            SMatrix::<3, 2>::try_from(matrix),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
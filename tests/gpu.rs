@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{
+        BufferLayout, BufferReader, BufferWriter, GpuData, Mat2, Mat3, Mat4, MathError, Matrix,
+        SMatrix, Vec2, Vec3, Vector,
+    };
+
+    // the little endian bytes of the floats where NaN stands for 4 bytes of padding
+    fn bytes(values: &[f32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|x| if x.is_nan() { [0; 4] } else { x.to_le_bytes() })
+            .collect()
+    }
+
+    const P: f32 = f32::NAN;
+
+    fn block(layout: BufferLayout) -> Vec<u8> {
+        let matrix = SMatrix::<4, 4>::from_fn(|row, col| (10 + row * 4 + col) as f32);
+        let mut writer = BufferWriter::new(layout);
+        writer.write(&1_f32);
+        writer.write(&Vec3::new([2., 3., 4.]));
+        writer.write(&5_f32);
+        writer.write(&matrix);
+        writer.write(&[6_f32, 7.]);
+        writer.into_bytes()
+    }
+
+    fn columns() -> Vec<f32> {
+        vec![
+            10., 14., 18., 22., 11., 15., 19., 23., 12., 16., 20., 24., 13., 17., 21., 25.,
+        ]
+    }
+
+    #[test]
+    fn std140_block() {
+        let mut expected = vec![1., P, P, P, 2., 3., 4., 5.];
+        expected.extend(columns());
+        expected.extend(vec![6., P, P, P, 7., P, P, P]);
+        assert_eq!(block(BufferLayout::Std140), bytes(&expected));
+    }
+
+    #[test]
+    fn std430_block() {
+        let mut expected = vec![1., P, P, P, 2., 3., 4., 5.];
+        expected.extend(columns());
+        expected.extend(vec![6., 7., P, P]);
+        assert_eq!(block(BufferLayout::Std430), bytes(&expected));
+    }
+
+    #[test]
+    fn matrix_columns() {
+        let mat2 = Mat2::new([[1., 2.], [3., 4.]]);
+        let mat3 = Mat3::new([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        let write = |layout, value: &dyn Fn(&mut BufferWriter)| {
+            let mut writer = BufferWriter::new(layout);
+            value(&mut writer);
+            writer.into_bytes()
+        };
+        assert_eq!(
+            write(BufferLayout::Std140, &|writer| writer.write(&mat2)),
+            bytes(&[1., 3., P, P, 2., 4., P, P])
+        );
+        assert_eq!(
+            write(BufferLayout::Std430, &|writer| writer.write(&mat2)),
+            bytes(&[1., 3., 2., 4.])
+        );
+        let expected = bytes(&[1., 4., 7., P, 2., 5., 8., P, 3., 6., 9., P]);
+        assert_eq!(
+            write(BufferLayout::Std140, &|writer| writer.write(&mat3)),
+            expected
+        );
+        assert_eq!(
+            write(BufferLayout::Std430, &|writer| writer.write(&mat3)),
+            expected
+        );
+        assert_eq!(
+            write(BufferLayout::Std430, &|writer| writer
+                .write_matrix(&Matrix::from(mat3))),
+            expected
+        );
+        let translation = write(BufferLayout::Std140, &|writer| {
+            writer.write(&Mat4::translation(Vec3::new([1., 2., 3.])))
+        });
+        assert_eq!(&translation[48..64], &bytes(&[1., 2., 3., 1.])[..]);
+    }
+
+    #[test]
+    fn non_square_matrices() {
+        // a mat2x3 has 2 columns of vec3 and a mat3x2 has 3 columns of vec2
+        let mat2x3 = SMatrix::<3, 2>::new([[1., 2.], [3., 4.], [5., 6.]]);
+        let mat3x2 = SMatrix::<2, 3>::new([[1., 2., 3.], [4., 5., 6.]]);
+        let write = |layout, value: &dyn Fn(&mut BufferWriter)| {
+            let mut writer = BufferWriter::new(layout);
+            value(&mut writer);
+            writer.into_bytes()
+        };
+        let expected = bytes(&[1., 3., 5., P, 2., 4., 6., P]);
+        assert_eq!(
+            write(BufferLayout::Std140, &|writer| writer.write(&mat2x3)),
+            expected
+        );
+        assert_eq!(
+            write(BufferLayout::Std430, &|writer| writer.write(&mat2x3)),
+            expected
+        );
+        assert_eq!(
+            write(BufferLayout::Std140, &|writer| writer.write(&mat3x2)),
+            bytes(&[1., 4., P, P, 2., 5., P, P, 3., 6., P, P])
+        );
+        assert_eq!(
+            write(BufferLayout::Std430, &|writer| writer.write(&mat3x2)),
+            bytes(&[1., 4., 2., 5., 3., 6.])
+        );
+
+        for layout in [BufferLayout::Std140, BufferLayout::Std430].iter() {
+            let bytes = write(*layout, &|writer| {
+                writer.write(&mat2x3);
+                writer.write(&mat3x2);
+            });
+            let mut reader = BufferReader::new(&bytes, *layout);
+            assert_eq!(reader.read::<SMatrix<3, 2>>(), Ok(mat2x3));
+            assert_eq!(reader.read::<SMatrix<2, 3>>(), Ok(mat3x2));
+        }
+    }
+
+    #[test]
+    fn vec2_and_scalar_alignment() {
+        let mut writer = BufferWriter::new(BufferLayout::Std430);
+        writer.write(&1_u32);
+        writer.write(&Vec2::new([2_i32, -3]));
+        writer.write(&4_f32);
+        assert_eq!(writer.offset(), 20);
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[4..8], &[0; 4]);
+        assert_eq!(&bytes[8..16], &[2, 0, 0, 0, 253, 255, 255, 255]);
+    }
+
+    #[derive(PartialEq, Debug, Clone, Copy)]
+    struct Pair {
+        a: f32,
+        b: f32,
+    }
+
+    impl GpuData for Pair {
+        fn write_to(&self, writer: &mut BufferWriter) {
+            writer.write_struct(|writer| {
+                writer.write(&self.a);
+                writer.write(&self.b);
+            });
+        }
+
+        fn read_from(reader: &mut BufferReader) -> Result<Self, MathError> {
+            reader.read_struct(|reader| {
+                Ok(Pair {
+                    a: reader.read()?,
+                    b: reader.read()?,
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn array_of_structs() {
+        let pairs = [Pair { a: 1., b: 2. }, Pair { a: 3., b: 4. }];
+        for (layout, expected) in [
+            (
+                BufferLayout::Std140,
+                bytes(&[5., P, P, P, 1., 2., P, P, 3., 4., P, P, 6., P, P, P]),
+            ),
+            (BufferLayout::Std430, bytes(&[5., 1., 2., 3., 4., 6.])),
+        ]
+        .iter()
+        {
+            let mut writer = BufferWriter::new(*layout);
+            writer.write(&5_f32);
+            writer.write_slice(&pairs);
+            writer.write(&6_f32);
+            let result = writer.into_bytes();
+            assert_eq!(&result, expected);
+
+            let mut reader = BufferReader::new(&result, *layout);
+            assert_eq!(reader.read::<f32>(), Ok(5.));
+            assert_eq!(reader.read_vec::<Pair>(2), Ok(pairs.to_vec()));
+            assert_eq!(reader.read::<f32>(), Ok(6.));
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        for layout in [BufferLayout::Std140, BufferLayout::Std430].iter() {
+            let bytes = block(*layout);
+            let mut reader = BufferReader::new(&bytes, *layout);
+            assert_eq!(reader.read::<f32>(), Ok(1.));
+            assert_eq!(reader.read_vector(3), Ok(Vector::new(vec![2., 3., 4.])));
+            assert_eq!(reader.read::<f32>(), Ok(5.));
+            let matrix = SMatrix::<4, 4>::from_fn(|row, col| (10 + row * 4 + col) as f32);
+            assert_eq!(reader.read_matrix(4, 4), Ok(Matrix::from(matrix)));
+            assert_eq!(reader.read::<[f32; 2]>(), Ok([6., 7.]));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut writer = BufferWriter::new(BufferLayout::Std140);
+        assert_eq!(
+            writer.try_write_vector(&Vector::new(vec![1., 2., 3., 4., 5.])),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            writer.try_write_matrix(&Matrix::new(vec![vec![1.]])),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(writer.offset(), 0);
+
+        let bytes = bytes(&[1., 2., 3.]);
+        let mut reader = BufferReader::new(&bytes, BufferLayout::Std430);
+        assert_eq!(reader.read::<f32>(), Ok(1.));
+        assert_eq!(
+            reader.read::<Vec2>(),
+            Err(MathError::IndexOutOfBounds { index: 15, len: 12 })
+        );
+    }
+}
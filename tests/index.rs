@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{MathError, Matrix, Vector};
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ])
+    }
+
+    #[test]
+    fn matrix_index() {
+        let mut matrix = matrix();
+        for i in 0..matrix.cols() {
+            for j in 0..matrix.rows() {
+                assert_eq!(matrix[(i, j)], matrix.index(i, j));
+            }
+        }
+
+        matrix[(2, 3)] = 0.;
+        matrix[(0, 0)] += 10.;
+        assert_eq!(matrix.index(2, 3), 0.);
+        assert_eq!(matrix.index(0, 0), 11.);
+
+        matrix.transpose();
+        assert_eq!(matrix[(3, 1)], 8.);
+        matrix[(3, 1)] = -8.;
+        matrix.transpose();
+        assert_eq!(matrix.index(1, 3), -8.);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 is out of bounds for len 4")]
+    fn matrix_index_panic() {
+        let matrix = matrix();
+        let _ = matrix[(0, 4)];
+    }
+
+    #[test]
+    fn vector_index() {
+        let mut vector = Vector::new(vec![1., 2., 3., 4.]);
+        vector[1] = 5.;
+        vector[3] -= 1.;
+        assert_eq!(vector, Vector::new(vec![1., 5., 3., 3.]));
+        assert_eq!(&vector[..2], &[1., 5.]);
+        assert_eq!(&vector[2..=3], &[3., 3.]);
+
+        vector[1..3].iter_mut().for_each(|x| *x = 0.);
+        assert_eq!(vector, Vector::new(vec![1., 0., 0., 3.]));
+
+        vector.set_index(0, 2.);
+        assert_eq!(vector[0], 2.);
+        assert_eq!(
+            vector.try_set_index(4, 1.),
+            Err(MathError::IndexOutOfBounds { index: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn view_index() {
+        let mut matrix = matrix();
+        let view = matrix.view().transpose();
+        assert_eq!(view[(3, 2)], 12.);
+        assert_eq!(matrix.col_view(1)[2], 7.);
+
+        let mut block = matrix.submatrix_mut(1, 1, 2, 2);
+        block[(1, 1)] = 0.;
+        block.col_mut(0)[1] *= 2.;
+        assert_eq!(block[(0, 1)], 14.);
+        assert_eq!(matrix.index(2, 2), 0.);
+    }
+
+    #[test]
+    fn slice() {
+        let matrix = matrix();
+        assert_eq!(
+            matrix.slice(1.., 1..3).to_matrix(),
+            Matrix::new(vec![vec![6., 7.], vec![10., 11.]])
+        );
+        assert_eq!(matrix.slice(.., ..), matrix);
+        assert_eq!(
+            matrix.slice(2, ..=1).to_matrix(),
+            Matrix::new(vec![vec![9., 10.]])
+        );
+        assert_eq!(matrix.slice(..2, 3)[(1, 0)], 8.);
+        assert_eq!(
+            matrix.try_slice(1..4, ..).map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds { index: 4, len: 3 })
+        );
+        assert_eq!(
+            matrix.try_slice(.., 5).map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds { index: 5, len: 4 })
+        );
+        assert_eq!(
+            matrix
+                .try_slice(0..=usize::MAX, ..)
+                .map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds {
+                index: usize::MAX,
+                len: 3
+            })
+        );
+        assert_eq!(
+            matrix
+                .try_slice(.., usize::MAX)
+                .map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds {
+                index: usize::MAX,
+                len: 4
+            })
+        );
+        let (start, end) = (2, 1);
+        assert_eq!(
+            matrix
+                .try_slice(start..end, ..)
+                .map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds { index: 2, len: 1 })
+        );
+    }
+
+    #[test]
+    fn slice_mut() {
+        let mut matrix = matrix();
+        matrix.slice_mut(.., 0).fill(0.);
+        matrix.slice_mut(1..=1, 2..)[(0, 1)] = 100.;
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![
+                vec![0., 2., 3., 4.],
+                vec![0., 6., 7., 100.],
+                vec![0., 10., 11., 12.],
+            ])
+        );
+    }
+
+    #[test]
+    fn kernel() {
+        // an in place forward elimination written with the index operators
+        let mut a = Matrix::new(vec![vec![2., 1., 1.], vec![4., 3., 3.], vec![8., 7., 9.]]);
+        let n = a.rows();
+        for k in 0..n {
+            for i in k + 1..n {
+                let factor = a[(i, k)] / a[(k, k)];
+                for j in k..n {
+                    a[(i, j)] -= factor * a[(k, j)];
+                }
+            }
+        }
+        let det: f32 = (0..n).map(|i| a[(i, i)]).product();
+        assert_eq!(det, 4.);
+        assert_eq!(a[(2, 0)], 0.);
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{
+        IterativeOptions, IterativeSolution, Jacobi, LinearOperator, MathError, Matrix, Vector,
+    };
+
+    // a diagonally dominant tridiagonal matrix with a varying diagonal
+    fn matrix(n: usize, symmetric: bool) -> Matrix<f64> {
+        let mut matrix = Matrix::zero(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 4. + (i % 7) as f64 * 10.;
+            if i > 0 {
+                matrix[(i, i - 1)] = -1.;
+            }
+            if i + 1 < n {
+                matrix[(i, i + 1)] = if symmetric { -1. } else { 2. };
+            }
+        }
+        matrix
+    }
+
+    fn check(matrix: &Matrix<f64>, b: &Vector<f64>, solution: &IterativeSolution<f64>) {
+        assert!(solution.converged());
+        let residual = (b.clone() - matrix.dot_vec(solution.solution())).mag() / b.mag();
+        assert!(residual <= f64::EPSILON.sqrt());
+        assert!((solution.residual() - residual).abs() < 1e-10);
+        assert_eq!(solution.history().len(), solution.iterations() + 1);
+        assert_eq!(solution.history()[0], 1.);
+    }
+
+    fn rhs(n: usize) -> Vector<f64> {
+        Vector::from_vec((0..n).map(|i| (i as f64).sin() + 1.).collect())
+    }
+
+    #[test]
+    fn cg() {
+        let (matrix, b) = (matrix(60, true), rhs(60));
+        let solution = matrix.cg(&b, &IterativeOptions::default());
+        check(&matrix, &b, &solution);
+
+        let jacobi = Jacobi::new(&matrix);
+        let options = IterativeOptions {
+            preconditioner: Some(&jacobi),
+            ..IterativeOptions::default()
+        };
+        let preconditioned = matrix.cg(&b, &options);
+        check(&matrix, &b, &preconditioned);
+        assert!(preconditioned.iterations() < solution.iterations());
+
+        let indefinite = Matrix::from_vec(vec![vec![1., 0.], vec![0., -1.]]);
+        assert_eq!(
+            indefinite.try_cg(
+                &Vector::from_vec(vec![1., 1.]),
+                &IterativeOptions::default()
+            ),
+            Err(MathError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn gmres() {
+        let (matrix, b) = (matrix(60, false), rhs(60));
+        let solution = matrix.gmres(&b, &IterativeOptions::default());
+        check(&matrix, &b, &solution);
+        assert!(solution.history().windows(2).all(|w| w[1] <= w[0]));
+
+        // restarting every 5 iterations still converges but needs more of them
+        let options = IterativeOptions {
+            restart: 5,
+            ..IterativeOptions::default()
+        };
+        let restarted = matrix.gmres(&b, &options);
+        check(&matrix, &b, &restarted);
+        assert!(restarted.iterations() > solution.iterations());
+
+        let jacobi = Jacobi::new(&matrix);
+        let options = IterativeOptions {
+            preconditioner: Some(&jacobi),
+            ..IterativeOptions::default()
+        };
+        check(&matrix, &b, &matrix.gmres(&b, &options));
+    }
+
+    #[test]
+    fn bicgstab() {
+        let (matrix, b) = (matrix(60, false), rhs(60));
+        check(
+            &matrix,
+            &b,
+            &matrix.bicgstab(&b, &IterativeOptions::default()),
+        );
+
+        let jacobi = Jacobi::new(&matrix);
+        let exact = matrix.solve(&b);
+        let options = IterativeOptions {
+            preconditioner: Some(&jacobi),
+            initial_guess: Some(&exact),
+            ..IterativeOptions::default()
+        };
+        let solution = matrix.bicgstab(&b, &options);
+        assert_eq!(solution.iterations(), 0);
+        assert_eq!(solution.solution(), &exact);
+    }
+
+    #[test]
+    fn max_iterations() {
+        let (general, b) = (matrix(60, false), rhs(60));
+        let options = IterativeOptions {
+            max_iterations: 3,
+            ..IterativeOptions::default()
+        };
+        for solution in [
+            general.gmres(&b, &options),
+            general.bicgstab(&b, &options),
+            matrix(60, true).cg(&b, &options),
+        ]
+        .iter()
+        {
+            assert!(!solution.converged());
+            assert_eq!(solution.iterations(), 3);
+            assert!(solution.residual() < 1.);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let options = IterativeOptions::default();
+        let rectangular = Matrix::from_vec(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(
+            rectangular.try_gmres(&Vector::from_vec(vec![1., 1.]), &options),
+            Err(MathError::NotSquare { cols: 2, rows: 3 })
+        );
+        let matrix = matrix(3, true);
+        assert_eq!(
+            matrix.try_bicgstab(&Vector::from_vec(vec![1., 1.]), &options),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        let jacobi = Jacobi::from_diagonal(&Vector::from_vec(vec![1., 2.]));
+        let options = IterativeOptions {
+            preconditioner: Some(&jacobi),
+            ..IterativeOptions::default()
+        };
+        assert_eq!(
+            matrix.try_cg(&rhs(3), &options),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Jacobi::try_from_diagonal(&Vector::from_vec(vec![1., 0.])),
+            Err(MathError::Singular)
+        );
+    }
+}
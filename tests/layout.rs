@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Layout, MathError, Matrix, Vector};
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ])
+    }
+
+    #[test]
+    fn layout() {
+        let mut matrix = matrix();
+        assert_eq!(matrix.layout(), Layout::RowMajor);
+        assert_eq!(matrix.strides(), (4, 1));
+        matrix.transpose();
+        assert_eq!(matrix.layout(), Layout::ColMajor);
+        assert_eq!(matrix.strides(), (1, 4));
+        assert_eq!(matrix.as_slice(), self::matrix().as_slice());
+    }
+
+    #[test]
+    fn transpose_non_square() {
+        let mut matrix = matrix();
+        matrix.transpose();
+        assert_eq!(matrix.cols(), 4);
+        assert_eq!(matrix.rows(), 3);
+        assert_eq!(matrix.index(3, 1), 8.);
+        assert_eq!(matrix.col(1), Vector::new(vec![2., 6., 10.]));
+        assert_eq!(matrix.row(2), Vector::new(vec![9., 10., 11., 12.]));
+
+        matrix.set_index(3, 2, 0.);
+        matrix.transpose();
+        assert_eq!(matrix.index(2, 3), 0.);
+        assert_eq!(
+            matrix.try_index(3, 0),
+            Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn to_layout() {
+        let matrix = matrix();
+        let col_major = matrix.to_layout(Layout::ColMajor);
+        assert_eq!(
+            col_major.as_slice(),
+            &[1., 5., 9., 2., 6., 10., 3., 7., 11., 4., 8., 12.]
+        );
+        assert_eq!(col_major, matrix);
+        assert_eq!(col_major.matrix_flatt(), matrix.matrix_flatt());
+
+        let row_major = col_major.to_layout(Layout::RowMajor);
+        assert_eq!(row_major.as_slice(), matrix.as_slice());
+
+        let mut transposed = matrix.clone();
+        transposed.transpose();
+        let materialized = transposed.to_layout(Layout::RowMajor);
+        assert_eq!(materialized.layout(), Layout::RowMajor);
+        assert_eq!(materialized, transposed);
+        assert_eq!(materialized.as_slice(), col_major.as_slice());
+    }
+
+    #[test]
+    fn strided() {
+        let matrix = matrix();
+        let padded = matrix.to_layout(Layout::Strided(5, 1));
+        assert_eq!(padded.layout(), Layout::Strided(5, 1));
+        assert_eq!(padded.as_slice().len(), 14);
+        assert_eq!(padded, matrix);
+        assert_eq!(padded.matrix_flatt(), matrix.matrix_flatt());
+
+        let data = padded.as_slice().to_vec();
+        let mut rebuilt = Matrix::from_layout(data, 3, 4, Layout::Strided(5, 1));
+        rebuilt.mul_scalar(&2.);
+        rebuilt.transpose();
+        rebuilt.add_vec(&Vector::new(vec![1., 1., 1.]));
+        assert_eq!(rebuilt.index(3, 2), 25.);
+        assert_eq!(rebuilt.as_slice()[4], 0.);
+
+        assert_eq!(
+            matrix.try_to_layout(Layout::Strided(2, 1)),
+            Err(MathError::OverlappingStrides { strides: (2, 1) })
+        );
+        assert_eq!(
+            Matrix::try_from_layout(vec![0.; 13], 3, 4, Layout::Strided(5, 1)),
+            Err(MathError::ShapeMismatch {
+                expected: 14,
+                actual: 13
+            })
+        );
+        assert_eq!(
+            Matrix::try_from_layout(vec![1., 2.], 2, 1, Layout::Strided(usize::MAX, 1)),
+            Err(MathError::ShapeMismatch {
+                expected: usize::MAX,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Matrix::try_from_layout(vec![1., 2.], 2, 2, Layout::Strided(usize::MAX, usize::MAX)),
+            Err(MathError::OverlappingStrides {
+                strides: (usize::MAX, usize::MAX)
+            })
+        );
+        assert_eq!(
+            matrix.try_to_layout(Layout::Strided(usize::MAX, 1)),
+            Err(MathError::OverlappingStrides {
+                strides: (usize::MAX, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn arithmetic_across_layouts() {
+        let a = matrix();
+        let b = a.to_layout(Layout::ColMajor);
+        let mut sum = a.clone();
+        sum.add_mat(&b);
+        let mut doubled = a.clone();
+        doubled.mul_scalar(&2.);
+        assert_eq!(sum, doubled);
+
+        let mut at = a.clone();
+        at.transpose();
+        assert_eq!(a.dot_mat(&at), a.dot_mat(&at.to_layout(Layout::RowMajor)));
+    }
+}
@@ -0,0 +1,159 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_mat_delta;
+    use math::linear_algebra::LuDecomposition;
+    use math::linear_algebra::MathError;
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Vector;
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![6., 1., 1., 4.],
+            vec![4., -2., 5., -7.],
+            vec![2., 8., 7., 3.],
+            vec![4., 1., 4., 2.],
+        ])
+    }
+
+    #[test]
+    fn new() {
+        let matrix = matrix();
+        let lu = LuDecomposition::new(&matrix);
+        assert_mat_delta(&lu.p().dot_mat(&matrix), &lu.l().dot_mat(&lu.u()), 1e-5);
+        assert!(!lu.is_singular());
+
+        let l = lu.l().matrix_flatt();
+        let u = lu.u().matrix_flatt();
+        for i in 0..4 {
+            assert_eq!(l[i * 4 + i], 1.);
+            for j in (i + 1)..4 {
+                assert_eq!(l[i * 4 + j], 0.);
+                assert_eq!(u[j * 4 + i], 0.);
+                assert!(l[j * 4 + i].abs() <= 1.);
+            }
+        }
+    }
+
+    #[test]
+    fn permutation() {
+        let matrix = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+        let lu = matrix.lu();
+        assert_eq!(lu.permutation(), vec![1, 0]);
+        assert_eq!(lu.p(), matrix);
+        assert_eq!(lu.det(), -1.);
+    }
+
+    #[test]
+    fn det() {
+        assert_delta!(matrix().lu().det(), -1046., 1e-2);
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = matrix();
+        let x = Vector::new(vec![1., -2., 3., 0.5]);
+        let b = matrix.dot_vec(&x);
+        let solution = matrix.lu().solve(&b);
+        solution
+            .vec()
+            .iter()
+            .zip(x.vec())
+            .for_each(|(a, b)| assert_delta!(a, b, 1e-5));
+    }
+
+    #[test]
+    fn solve_mat() {
+        let matrix = matrix();
+        let x = Matrix::new(vec![
+            vec![1., 2.],
+            vec![-2., 0.],
+            vec![3., 1.],
+            vec![0.5, -4.],
+        ]);
+        let b = matrix.dot_mat(&x);
+        assert_mat_delta(&matrix.lu().solve_mat(&b), &x, 1e-5);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = matrix();
+        let inverse = matrix.lu().inverse();
+        let identity = Matrix::new(vec![
+            vec![1., 0., 0., 0.],
+            vec![0., 1., 0., 0.],
+            vec![0., 0., 1., 0.],
+            vec![0., 0., 0., 1.],
+        ]);
+        assert_mat_delta(&matrix.dot_mat(&inverse), &identity, 1e-5);
+        assert_mat_delta(&inverse.dot_mat(&matrix), &identity, 1e-5);
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let matrix = matrix();
+        assert_mat_delta(&matrix.inverse(), &matrix.lu().inverse(), 0.);
+        assert_eq!(
+            Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]).try_inverse(),
+            Err(MathError::NotSquare { cols: 2, rows: 3 })
+        );
+        assert_eq!(
+            Matrix::new(vec![vec![1., 2.], vec![2., 4.]]).try_inverse(),
+            Err(MathError::Singular)
+        );
+
+        // the second pivot is 0.01 which is only singular for a larger tolerance
+        let matrix = Matrix::new(vec![vec![1., 1.], vec![1., 1.01]]);
+        assert!(matrix.try_inverse_with_tolerance(1e-3).is_ok());
+        assert_eq!(
+            matrix.try_inverse_with_tolerance(0.1),
+            Err(MathError::Singular)
+        );
+        assert!(LuDecomposition::new_with_tolerance(&matrix, 0.1).is_singular());
+    }
+
+    #[test]
+    fn singular() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+        assert!(matrix.lu().is_singular());
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn solve_singular_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+        let _ = matrix.lu().solve(&Vector::new(vec![1., 1.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has to be a square matrix")]
+    fn new_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let _ = LuDecomposition::new(&matrix);
+    }
+
+    #[test]
+    fn try_solve() {
+        let lu = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]).lu();
+        assert_eq!(
+            lu.try_solve(&Vector::new(vec![1., 1.])),
+            Err(MathError::Singular)
+        );
+        assert_eq!(lu.try_inverse(), Err(MathError::Singular));
+
+        let lu = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]).lu();
+        assert_eq!(
+            lu.try_solve(&Vector::new(vec![1., 1., 1.])),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            lu.try_solve(&Vector::new(vec![3., 9.])),
+            Ok(Vector::new(vec![1., 1.]))
+        );
+    }
+}
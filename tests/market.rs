@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{FormatError, MarketFormat, Matrix};
+
+    fn read(file: &str) -> Result<Matrix<f64>, FormatError> {
+        Matrix::from_matrix_market(file.as_bytes())
+    }
+
+    fn error(file: &str) -> String {
+        read(file).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn coordinate() {
+        let file = "%%MatrixMarket matrix coordinate real general
+%  comment
+
+3 2 3
+1 1 1.5
+3 2 -2e3
+2 1 4
+";
+        assert_eq!(
+            read(file).unwrap(),
+            Matrix::from_vec(vec![vec![1.5, 0.], vec![4., 0.], vec![0., -2000.]])
+        );
+        let file = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+        assert_eq!(
+            Matrix::<u8>::from_matrix_market(file.as_bytes()).unwrap(),
+            Matrix::from_vec(vec![vec![0, 1], vec![1, 0]])
+        );
+    }
+
+    #[test]
+    fn array() {
+        let file = "%%MatrixMarket matrix array integer general\n2 3\n1\n2\n3\n4\n5\n6\n";
+        assert_eq!(
+            Matrix::<i32>::from_matrix_market(file.as_bytes()).unwrap(),
+            Matrix::from_vec(vec![vec![1, 3, 5], vec![2, 4, 6]])
+        );
+        let file = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+        assert_eq!(
+            read(file).unwrap(),
+            Matrix::from_vec(vec![vec![1., 2.], vec![2., 3.]])
+        );
+        let file = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+        assert_eq!(
+            read(file).unwrap(),
+            Matrix::from_vec(vec![
+                vec![0., -1., -2.],
+                vec![1., 0., -3.],
+                vec![2., 3., 0.]
+            ])
+        );
+    }
+
+    #[test]
+    fn skew_symmetric_coordinate() {
+        let file = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 5\n";
+        assert_eq!(
+            Matrix::<i64>::from_matrix_market(file.as_bytes()).unwrap(),
+            Matrix::from_vec(vec![vec![0, -5], vec![5, 0]])
+        );
+        // the upper triangle can not be negated in an unsigned type or for the smallest signed value
+        let file = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 3\n";
+        assert_eq!(
+            Matrix::<u32>::from_matrix_market(file.as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "line 3: skew-symmetric needs a signed type that can negate the value"
+        );
+        let file = "%%MatrixMarket matrix array integer skew-symmetric\n2 2\n-128\n";
+        assert_eq!(
+            Matrix::<i8>::from_matrix_market(file.as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "line 3: skew-symmetric needs a signed type that can negate the value"
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let matrix = Matrix::from_vec(vec![vec![0.1, 0., -3.], vec![0., 1e-300, 7.25]]);
+        for format in [MarketFormat::Array, MarketFormat::Coordinate].iter() {
+            let mut file = Vec::new();
+            matrix.to_matrix_market(&mut file, *format).unwrap();
+            assert_eq!(Matrix::from_matrix_market(&file[..]).unwrap(), matrix);
+        }
+        let mut file = Vec::new();
+        matrix
+            .to_matrix_market(&mut file, MarketFormat::Array)
+            .unwrap();
+        assert!(String::from_utf8(file)
+            .unwrap()
+            .starts_with("%%MatrixMarket matrix array real general\n2 3\n0.1\n0\n0\n"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(error(""), "line 1: the file is empty");
+        assert_eq!(
+            error("%%MatrixMarket vector coordinate real general\n"),
+            "line 1: the header has to be `%%MatrixMarket matrix <format> <field> <symmetry>`"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate complex general\n"),
+            "line 1: unsupported field `complex`"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix array pattern general\n"),
+            "line 1: unsupported field `pattern`"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real hermitian\n"),
+            "line 1: unsupported symmetry `hermitian`"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n% c\n2 2\n"),
+            "line 3: expected 3 numbers, got 2"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n"),
+            "line 3: index 3 is out of bounds for the dimension 2"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n"),
+            "line 4: the file ended before all entries were read"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1\n2 2 2\n"),
+            "line 4: there are more entries than the size line declares"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n"),
+            "line 3: a symmetric matrix only stores the lower triangle"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 3\n1 1 4\n"),
+            "line 4: duplicate entry `1 1`"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n18446744073709551615 2 0\n"),
+            "line 2: the size 18446744073709551615 x 2 is too large"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix array real general\n4294967296 4294967296\n"),
+            "line 2: the size 4294967296 x 4294967296 is too large"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix array real symmetric\n2 3\n"),
+            "line 2: a symmetric matrix has to be square"
+        );
+        assert_eq!(
+            Matrix::<i32>::from_matrix_market(
+                "%%MatrixMarket matrix array real general\n1 1\n1.5\n".as_bytes()
+            )
+            .unwrap_err()
+            .to_string(),
+            "line 3: invalid value `1.5`"
+        );
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n1 1\nx\n"),
+            Err(FormatError::Parse { line: 3, .. })
+        ));
+    }
+}
@@ -1,24 +1,28 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 mod tests {
+    use math::linear_algebra::MathError;
     use math::linear_algebra::Matrix;
     use math::linear_algebra::Vector;
 
     #[test]
     fn det() {
         let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
-        assert_eq!(matrix.det(), -5.);
+        assert_delta!(matrix.det(), -2., 1e-5);
 
         let matrix = Matrix::new(vec![vec![3., 8.], vec![4., 6.]]);
-        assert_eq!(matrix.det(), 2.);
+        assert_delta!(matrix.det(), -14., 1e-5);
 
         let matrix = Matrix::new(vec![vec![4., 6.], vec![3., 8.]]);
-        assert_eq!(matrix.det(), 23.);
+        assert_delta!(matrix.det(), 14., 1e-5);
 
         let matrix = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.], vec![1., 4., 5.]]);
-        assert_eq!(matrix.det(), -122.);
+        assert_delta!(matrix.det(), 49., 1e-4);
 
         let matrix = Matrix::new(vec![vec![6., 1., 1.], vec![4., -2., 5.], vec![2., 8., 7.]]);
-        assert_eq!(matrix.det(), -410.);
+        assert_delta!(matrix.det(), -306., 1e-3);
 
         let matrix = Matrix::new(vec![
             vec![6., 1., 1., 4.],
@@ -26,7 +30,29 @@ mod tests {
             vec![2., 8., 7., 3.],
             vec![4., 1., 4., 2.],
         ]);
-        assert_eq!(matrix.det(), 2148.);
+        assert_delta!(matrix.det(), -1046., 1e-2);
+    }
+
+    #[test]
+    fn det_large() {
+        let n = 200;
+        let mut flatt = vec![0.; n * n];
+        for i in 0..n {
+            flatt[i * n + i] = 1.;
+            flatt[i * n + (i + 1) % n] = 0.5;
+        }
+        // det(I + 0.5 * C) where C is the cyclic shift = 1 - (-0.5)^n
+        let matrix = Matrix::new_flatt(flatt, n, n);
+        assert_delta!(matrix.det(), 1., 1e-4);
+    }
+
+    #[test]
+    fn det_singular() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+        assert_delta!(matrix.det(), 0., 1e-4);
+
+        let matrix = Matrix::new(vec![vec![0., 0.], vec![1., 2.]]);
+        assert_eq!(matrix.det(), 0.);
     }
 
     #[test]
@@ -42,13 +68,16 @@ mod tests {
         matrix.add_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![6.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 1.0, 7.0], vec![4.0, 4.0, 5.0]])
         );
 
         matrix.transpose();
         let vector = Vector::new(vec![-2., 6.]);
         matrix.add_vec(&vector);
-        assert_eq!(matrix.matrix_flatt(), vec![2.0, 4.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(
+            matrix.matrix_flatt(),
+            vec![2.0, 10.0, -1.0, 10.0, 5.0, 11.0]
+        );
     }
 
     #[test]
@@ -58,13 +87,16 @@ mod tests {
         matrix.sub_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-2.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -7.0, -5.0], vec![0.0, -4.0, -7.0]])
         );
 
         matrix.transpose();
         let vector = Vector::new(vec![-2., 6.]);
         matrix.sub_vec(&vector);
-        assert_eq!(matrix.matrix_flatt(), vec![2.0, 0.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(
+            matrix.matrix_flatt(),
+            vec![2.0, -6.0, -5.0, -10.0, -3.0, -13.0]
+        );
     }
 
     #[test]
@@ -74,7 +106,7 @@ mod tests {
         matrix.mul_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![8.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -12.0, 6.0], vec![4.0, 0.0, -6.0]])
         );
 
         matrix.transpose();
@@ -82,7 +114,7 @@ mod tests {
         matrix.mul_vec(&vector);
         assert_eq!(
             matrix.matrix_flatt(),
-            vec![-8.0, -16.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-8.0, 24.0, 24.0, 0.0, -12.0, -36.0]
         );
     }
 
@@ -93,7 +125,7 @@ mod tests {
         matrix.div_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.5, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -0.75, 1. / 6.], vec![1.0, 0.0, -1. / 6.]])
         );
 
         matrix.transpose();
@@ -101,7 +133,7 @@ mod tests {
         matrix.div_vec(&vector);
         assert_eq!(
             matrix.matrix_flatt(),
-            vec![-0.5, -0.25, -3.0, 0.0, 1.0, -1.0]
+            vec![-0.5, 1. / 6., 0.375, 0.0, 1. / 6. / -2., -1. / 6. / 6.]
         );
     }
 
@@ -113,13 +145,13 @@ mod tests {
         matrix1.add_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
 
         matrix1.transpose();
         let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
         matrix1.add_mat(&matrix2);
-        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, 7.0, 2.0, 3.0, 8.0]);
     }
 
     #[test]
@@ -130,7 +162,7 @@ mod tests {
         matrix1.sub_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
 
         matrix1.transpose();
@@ -138,7 +170,7 @@ mod tests {
         matrix1.sub_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![-2.0, -1.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-2.0, -1.0, -13.0, -2.0, -1.0, -10.0]
         );
     }
 
@@ -150,7 +182,7 @@ mod tests {
         matrix1.mul_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
         );
 
         matrix1.transpose();
@@ -158,7 +190,7 @@ mod tests {
         matrix1.mul_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![8.0, -56.0, -3.0, 0.0, 1.0, -1.0]
+            vec![8.0, -56.0, -63.0, 0.0, -15.0, -20.0]
         );
     }
 
@@ -170,7 +202,7 @@ mod tests {
         matrix1.div_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![2. / 7., 0.0, -0.25]])
         );
 
         matrix1.transpose();
@@ -178,7 +210,7 @@ mod tests {
         matrix1.div_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![0.5, -0.071428575, -3.0, 0.0, 1.0, -1.0]
+            vec![0.5, 2. / 7. / -4., -1. / 7., 0.0, 0.2 / -3., -0.05]
         );
     }
 
@@ -190,13 +222,13 @@ mod tests {
         matrix1 += matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
 
         matrix1.transpose();
         let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
         matrix1 += matrix2;
-        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, 7.0, 2.0, 3.0, 8.0]);
     }
 
     #[test]
@@ -207,7 +239,7 @@ mod tests {
         matrix1 -= matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
 
         matrix1.transpose();
@@ -215,7 +247,7 @@ mod tests {
         matrix1 -= matrix2;
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![-2.0, -1.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-2.0, -1.0, -13.0, -2.0, -1.0, -10.0]
         );
     }
 
@@ -227,7 +259,7 @@ mod tests {
         matrix1 *= matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
         );
 
         matrix1.transpose();
@@ -235,7 +267,7 @@ mod tests {
         matrix1 *= matrix2;
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![8.0, -56.0, -3.0, 0.0, 1.0, -1.0]
+            vec![8.0, -56.0, -63.0, 0.0, -15.0, -20.0]
         );
     }
 
@@ -247,7 +279,7 @@ mod tests {
         matrix1 /= matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![2. / 7., 0.0, -0.25]])
         );
 
         matrix1.transpose();
@@ -255,7 +287,7 @@ mod tests {
         matrix1 /= matrix2;
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![0.5, -0.071428575, -3.0, 0.0, 1.0, -1.0]
+            vec![0.5, 2. / 7. / -4., -1. / 7., 0.0, 0.2 / -3., -0.05]
         );
     }
 
@@ -266,7 +298,7 @@ mod tests {
 
         assert_eq!(
             matrix1 + matrix2,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
     }
 
@@ -277,7 +309,7 @@ mod tests {
 
         assert_eq!(
             matrix1 - matrix2,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
     }
 
@@ -288,7 +320,7 @@ mod tests {
 
         assert_eq!(
             matrix1 * matrix2,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
         );
     }
 
@@ -299,7 +331,7 @@ mod tests {
 
         assert_eq!(
             matrix1 / matrix2,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![2. / 7., 0.0, -0.25]])
         );
     }
 
@@ -404,7 +436,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "wrong row shape expected 3, got 4")]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn new() {
         let _ = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4., 1.]]);
     }
@@ -509,14 +541,14 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "index out of bounds max row 2")]
+    #[should_panic(expected = "index 3 is out of bounds for len 3")]
     fn row_panic() {
         let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
         let _ = matrix.row(3);
     }
 
     #[test]
-    #[should_panic(expected = "index out of bounds max col 1")]
+    #[should_panic(expected = "index 2 is out of bounds for len 2")]
     fn col_panic() {
         let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
         let _ = matrix.col(2);
@@ -538,7 +570,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "wrong vector shape expected 3, got 2")]
+    #[should_panic(expected = "wrong shape expected 3, got 2")]
     fn dot_vec_panic() {
         let matrix = Matrix::new(vec![vec![1., -1., 2.], vec![0., -3., 1.]]);
         assert_eq!(
@@ -546,4 +578,233 @@ mod tests {
             Vector::new(vec![1., -3.])
         );
     }
+
+    #[test]
+    fn dot_mat() {
+        let matrix1 = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let matrix2 = Matrix::new(vec![vec![7., 8.], vec![9., 10.], vec![11., 12.]]);
+        assert_eq!(
+            matrix1.dot_mat(&matrix2),
+            Matrix::new(vec![vec![58., 64.], vec![139., 154.]])
+        );
+        assert_eq!(
+            &matrix2 * &matrix1,
+            Matrix::new(vec![
+                vec![39., 54., 69.],
+                vec![49., 68., 87.],
+                vec![59., 82., 105.]
+            ])
+        );
+
+        let mut matrix3 = matrix1.clone();
+        matrix3.transpose();
+        assert_eq!(
+            matrix3.dot_mat(&matrix1),
+            Matrix::new(vec![
+                vec![17., 22., 27.],
+                vec![22., 29., 36.],
+                vec![27., 36., 45.]
+            ])
+        );
+    }
+
+    #[test]
+    fn dot_mat_blocked() {
+        let (m, k, n) = (70, 130, 90);
+        let a = Matrix::new_flatt((0..m * k).map(|i| (i % 7) as f32 - 3.).collect(), m, k);
+        let b = Matrix::new_flatt((0..k * n).map(|i| (i % 5) as f32 - 2.).collect(), k, n);
+        let result = a.dot_mat(&b);
+        assert_eq!(result.cols(), m);
+        assert_eq!(result.rows(), n);
+
+        let (a, b, result) = (a.matrix_flatt(), b.matrix_flatt(), result.matrix_flatt());
+        for i in 0..m {
+            for j in 0..n {
+                let expected: f32 = (0..k).map(|l| a[i * k + l] * b[l * n + j]).sum();
+                assert_eq!(result[i * n + j], expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong shape expected 3, got 2")]
+    fn dot_mat_panic() {
+        let matrix = Matrix::new(vec![vec![1., -1., 2.], vec![0., -3., 1.]]);
+        let _ = matrix.dot_mat(&matrix);
+    }
+
+    #[test]
+    fn try_new() {
+        let matrix = Matrix::try_new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+        assert_eq!(
+            matrix,
+            Ok(Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]))
+        );
+        assert_eq!(
+            Matrix::try_new(vec![vec![2., 3., 5.], vec![7., 1., 4., 1.]]),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+        assert_eq!(Matrix::try_new(vec![]), Err(MathError::EmptyInput));
+        assert_eq!(
+            Matrix::try_new_flatt(vec![1., 2., 3.], 2, 2),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn try_index() {
+        let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+        assert_eq!(matrix.try_index(1, 2), Ok(6.));
+        assert_eq!(
+            matrix.try_index(2, 0),
+            Err(MathError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        assert_eq!(
+            matrix.try_index(0, 3),
+            Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            matrix.try_set_index(0, 3, 1.),
+            Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            matrix.try_row(3),
+            Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            matrix.try_col(2),
+            Err(MathError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+
+        matrix.transpose();
+        assert_eq!(matrix.try_index(2, 1), Ok(6.));
+        assert_eq!(matrix.try_set_index(2, 1, 1.), Ok(()));
+        assert_eq!(matrix.try_col(2), Ok(Vector::new(vec![4., 1.])));
+    }
+
+    #[test]
+    fn try_arithmetic() {
+        let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
+        let matrix2 = Matrix::new(vec![vec![2., 3.], vec![7., 1.]]);
+        let before = matrix1.clone();
+
+        let error = MathError::ShapeMismatch {
+            expected: 3,
+            actual: 2,
+        };
+        assert_eq!(matrix1.try_add_mat(&matrix2), Err(error.clone()));
+        assert_eq!(matrix1.try_sub_mat(&matrix2), Err(error.clone()));
+        assert_eq!(matrix1.try_mul_mat(&matrix2), Err(error.clone()));
+        assert_eq!(matrix1.try_div_mat(&matrix2), Err(error.clone()));
+        let vector = Vector::new(vec![1., 2.]);
+        assert_eq!(matrix1.try_add_vec(&vector), Err(error.clone()));
+        assert_eq!(matrix1.try_dot_vec(&vector), Err(error.clone()));
+        assert_eq!(matrix1.try_dot_mat(&matrix2), Err(error));
+        assert_eq!(matrix1, before);
+
+        assert_eq!(
+            matrix1.try_add_mat(&Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]])),
+            Ok(())
+        );
+        assert_eq!(
+            matrix1,
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn try_decompositions() {
+        let matrix = Matrix::new(vec![vec![2., 1., 3.], vec![4., 5., 6.]]);
+        let error = MathError::NotSquare { cols: 2, rows: 3 };
+        assert_eq!(matrix.try_det(), Err(error.clone()));
+        assert_eq!(matrix.try_lu(), Err(error.clone()));
+        assert_eq!(matrix.try_cholesky(), Err(error.clone()));
+        assert_eq!(matrix.try_ldlt(), Err(error.clone()));
+        assert_eq!(matrix.try_symmetric_eigen(), Err(error.clone()));
+        assert_eq!(matrix.try_eigen(), Err(error));
+        assert_eq!(
+            matrix.try_qr(),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert!(matrix.try_svd().is_ok());
+
+        let matrix = Matrix::new(vec![vec![2., 1.], vec![4., 5.]]);
+        assert_eq!(matrix.try_det(), Ok(6.));
+        assert_eq!(matrix.try_cholesky(), Err(MathError::NotSymmetric));
+        assert_eq!(matrix.try_symmetric_eigen(), Err(MathError::NotSymmetric));
+    }
+
+    #[test]
+    fn error_display() {
+        let error: Box<dyn std::error::Error> = Box::new(MathError::NotSquare { cols: 2, rows: 3 });
+        assert_eq!(
+            error.to_string(),
+            "the matrix has to be a square matrix, got 2 cols and 3 rows"
+        );
+        assert_eq!(
+            MathError::NoConvergence { iterations: 64 }.to_string(),
+            "did not converge after 64 iterations"
+        );
+    }
+
+    #[test]
+    fn reference_ops() {
+        let a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let b = Matrix::new(vec![vec![2., 4., -1.], vec![1., 1., 1.]]);
+        assert_eq!(
+            &a + &b,
+            Matrix::new(vec![vec![3., 6., 2.], vec![5., 6., 7.]])
+        );
+        assert_eq!(&a - &b, a.clone() - b.clone());
+        assert_eq!(
+            &a * 2. - &b,
+            Matrix::new(vec![vec![0., 0., 7.], vec![7., 9., 11.]])
+        );
+        assert_eq!(
+            &a * &Vector::new(vec![1., 0., -1.]),
+            Vector::new(vec![-2., -2.])
+        );
+        let quotient = Matrix::new(vec![vec![0.5, 0.5, -3.], vec![4., 5., 6.]]);
+        assert_eq!(&a / &b, quotient);
+        assert_eq!(a.clone() / &b, quotient);
+        assert_eq!(&a / b.clone(), quotient);
+
+        let mut c = a.clone();
+        c *= &b;
+        c /= &b;
+        c += &b;
+        c -= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let a = Matrix::new(vec![vec![1., 2.], vec![4., 8.]]);
+        assert_eq!(&a + 1., Matrix::new(vec![vec![2., 3.], vec![5., 9.]]));
+        assert_eq!(&a / 2., Matrix::new(vec![vec![0.5, 1.], vec![2., 4.]]));
+        assert_eq!(2. * &a, &a * 2.);
+        assert_eq!(8. / &a, Matrix::new(vec![vec![8., 4.], vec![2., 1.]]));
+        assert_eq!(1. - a.clone(), -(&a - 1.));
+
+        let mut transposed = a.clone();
+        transposed.transpose();
+        assert_eq!(
+            1. - &transposed,
+            Matrix::new(vec![vec![0., -3.], vec![-1., -7.]])
+        );
+
+        let mut b = a;
+        b *= 2.;
+        b += 1.;
+        assert_eq!(b, Matrix::new(vec![vec![3., 5.], vec![9., 17.]]));
+    }
 }
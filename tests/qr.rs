@@ -0,0 +1,123 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{assert_mat_delta, identity};
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::QrDecomposition;
+    use math::linear_algebra::Vector;
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![12., -51., 4.],
+            vec![6., 167., -68.],
+            vec![-4., 24., -41.],
+            vec![-1., 1., 0.],
+            vec![2., 0., 3.],
+        ])
+    }
+
+    #[test]
+    fn thin() {
+        let matrix = matrix();
+        let qr = QrDecomposition::new(&matrix);
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!((q.cols(), q.rows()), (5, 3));
+        assert_eq!((r.cols(), r.rows()), (3, 3));
+        assert_mat_delta(&q.dot_mat(&r), &matrix, 1e-3);
+
+        let mut q_t = q.clone();
+        q_t.transpose();
+        assert_mat_delta(&q_t.dot_mat(&q), &identity(3), 1e-5);
+
+        let r = r.matrix_flatt();
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r[i * 3 + j], 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn full() {
+        let matrix = matrix();
+        let qr = matrix.qr();
+        let (q, r) = (qr.q_full(), qr.r_full());
+        assert_eq!((q.cols(), q.rows()), (5, 5));
+        assert_eq!((r.cols(), r.rows()), (5, 3));
+        assert_mat_delta(&q.dot_mat(&r), &matrix, 1e-3);
+
+        let mut q_t = q.clone();
+        q_t.transpose();
+        assert_mat_delta(&q_t.dot_mat(&q), &identity(5), 1e-5);
+    }
+
+    #[test]
+    fn square() {
+        let matrix = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.], vec![1., 4., 5.]]);
+        let qr = matrix.qr();
+        assert_mat_delta(&qr.q().dot_mat(&qr.r()), &matrix, 1e-5);
+        assert_eq!(qr.q(), qr.q_full());
+
+        let x = Vector::new(vec![1., 2., 3.]);
+        let solution = qr.solve(&matrix.dot_vec(&x));
+        solution
+            .vec()
+            .iter()
+            .zip(x.vec())
+            .for_each(|(a, b)| assert_delta!(a, b, 1e-5));
+    }
+
+    #[test]
+    fn solve() {
+        // fits y = 1 + 2 * x - 0.5 * x^2 to noisy points
+        let xs = [-2., -1., 0., 1., 2., 3.];
+        let noise = [0.01, -0.02, 0.015, -0.01, 0.005, 0.];
+        let matrix = Matrix::new(xs.iter().map(|&x| vec![1., x, x * x]).collect());
+        let b = Vector::new(
+            xs.iter()
+                .zip(noise.iter())
+                .map(|(&x, n)| 1. + 2. * x - 0.5 * x * x + n)
+                .collect(),
+        );
+        let coefficients = matrix.qr().solve(&b);
+        assert_delta!(coefficients.index(0), 1., 0.02);
+        assert_delta!(coefficients.index(1), 2., 0.02);
+        assert_delta!(coefficients.index(2), -0.5, 0.02);
+
+        // the residual has to be orthogonal to the columns of A
+        let mut residual = b.clone();
+        residual.sub_vec(&matrix.dot_vec(&coefficients));
+        let mut matrix_t = matrix.clone();
+        matrix_t.transpose();
+        matrix_t
+            .dot_vec(&residual)
+            .vec()
+            .iter()
+            .for_each(|x| assert_delta!(x, 0., 1e-4));
+    }
+
+    #[test]
+    fn solve_mat() {
+        let matrix = matrix();
+        let x = Matrix::new(vec![vec![1., 0.], vec![-1., 2.], vec![0.5, 3.]]);
+        let b = matrix.dot_mat(&x);
+        assert_mat_delta(&matrix.qr().solve_mat(&b), &x, 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn solve_rank_deficient_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+        assert!(!matrix.qr().is_full_rank());
+        let _ = matrix.qr().solve(&Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong shape expected 3, got 2")]
+    fn new_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let _ = matrix.qr();
+    }
+}
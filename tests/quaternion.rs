@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{EulerOrder, MathError, Matrix, Quaternion, Vec3, Vector};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    fn close(a: &Matrix<f64>, b: &Matrix<f64>) -> bool {
+        a.matrix_flatt()
+            .iter()
+            .zip(b.matrix_flatt().iter())
+            .all(|(x, y)| (x - y).abs() < 1e-12)
+    }
+
+    fn rotation(axis: usize, angle: f64) -> Matrix<f64> {
+        let (sin, cos) = angle.sin_cos();
+        let (a, b) = ((axis + 1) % 3, (axis + 2) % 3);
+        let mut matrix = Matrix::zero(3, 3);
+        matrix[(axis, axis)] = 1.;
+        matrix[(a, a)] = cos;
+        matrix[(b, b)] = cos;
+        matrix[(a, b)] = -sin;
+        matrix[(b, a)] = sin;
+        matrix
+    }
+
+    #[test]
+    fn hamilton_product() {
+        let i = Quaternion::new(0., 1., 0., 0.);
+        let j = Quaternion::new(0., 0., 1., 0.);
+        let k = Quaternion::new(0., 0., 0., 1.);
+        assert_eq!(i * i, -Quaternion::identity());
+        assert_eq!(i * j * k, -Quaternion::identity());
+        assert_eq!(k * i, j);
+        let q = Quaternion::new(1_f32, 2., 3., 4.);
+        assert_eq!(q.conj(), Quaternion::new(1., -2., -3., -4.));
+        assert_eq!(q.norm_sqr(), 30.);
+        assert_eq!((q * q.conj()).w, 30.);
+        assert!((q * q.inv() - Quaternion::identity()).norm() < 1e-6);
+        assert!((q.normalize().norm() - 1.).abs() < 1e-6);
+        let mut p = q;
+        p *= i;
+        p += q;
+        p -= q * 2.;
+        assert_eq!(p, q * i - q);
+    }
+
+    #[test]
+    fn rotate_vec() {
+        let q = Quaternion::from_axis_angle(&Vector::new(vec![1., 1., 1.]), 2. * PI as f32 / 3.);
+        let rotated = q.rotate_vec(&Vector::new(vec![1., 0., 0.]));
+        assert!(rotated.dist(&Vector::new(vec![0., 1., 0.])) < 1e-6);
+        let rotated = q * Vec3::new([0., 0., 1.]);
+        assert!(rotated.dist(Vec3::new([1., 0., 0.])) < 1e-6);
+        assert_eq!(
+            q.try_rotate_vec(&Vector::new(vec![1., 0.])),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert!(Quaternion::try_from_axis_angle(&Vector::new(vec![1., 0., 0., 0.]), 1.).is_err());
+    }
+
+    #[test]
+    fn axis_angle() {
+        let axis = Vector::from_vec(vec![2_f64, -1., 2.]);
+        let (result, angle) = Quaternion::from_axis_angle(&axis, 2.5).to_axis_angle();
+        assert!((angle - 2.5).abs() < 1e-12);
+        assert!(result.dist(&Vector::from_vec(vec![2. / 3., -1. / 3., 2. / 3.])) < 1e-12);
+        let (result, angle) = Quaternion::<f64>::identity().to_axis_angle();
+        assert_eq!(result, Vector::from_vec(vec![1., 0., 0.]));
+        assert_eq!(angle, 0.);
+        assert_eq!(
+            Quaternion::try_from_axis_angle(&Vector::from_vec(vec![0_f64, 0., 0.]), 1.),
+            Err(MathError::Singular)
+        );
+    }
+
+    #[test]
+    fn rotation_matrix() {
+        let vector = Vector::from_vec(vec![0.3, -1.2, 2.]);
+        for (axis, angle) in [
+            (vec![1., 2., 3.], 0.5),
+            (vec![1., 0., 0.], PI),
+            (vec![0., 1., 0.], PI),
+            (vec![0., 0., 1.], PI),
+            (vec![-1., 0.5, 0.], 3.),
+        ]
+        .iter()
+        {
+            let q = Quaternion::from_axis_angle(&Vector::from_vec(axis.clone()), *angle);
+            let matrix = q.to_rotation_matrix();
+            assert!(matrix.dot_vec(&vector).dist(&q.rotate_vec(&vector)) < 1e-12);
+            let result = Quaternion::from_rotation_matrix(&matrix);
+            assert!((result - q).norm() < 1e-12 || (result + q).norm() < 1e-12);
+        }
+        assert_eq!(
+            Quaternion::try_from_rotation_matrix(&Matrix::<f64>::zero(3, 2)),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn euler() {
+        for order in ORDERS.iter() {
+            for angles in [[0.1, 0.2, 0.3], [-2.5, 1.2, 3.], [0.7, FRAC_PI_2, -0.4]].iter() {
+                let q = Quaternion::from_euler(*angles, *order);
+                let axes = match order {
+                    EulerOrder::XYZ => [0, 1, 2],
+                    EulerOrder::XZY => [0, 2, 1],
+                    EulerOrder::YXZ => [1, 0, 2],
+                    EulerOrder::YZX => [1, 2, 0],
+                    EulerOrder::ZXY => [2, 0, 1],
+                    EulerOrder::ZYX => [2, 1, 0],
+                };
+                let expected = rotation(axes[0], angles[0])
+                    .dot_mat(&rotation(axes[1], angles[1]))
+                    .dot_mat(&rotation(axes[2], angles[2]));
+                assert!(close(&q.to_rotation_matrix(), &expected), "{:?}", order);
+                let result = q.to_euler(*order);
+                let roundtrip = Quaternion::from_euler(result, *order).to_rotation_matrix();
+                assert!(close(&roundtrip, &expected), "{:?} {:?}", order, result);
+            }
+            let result: [f64; 3] = Quaternion::from_euler([0.1, 0.2, 0.3], *order).to_euler(*order);
+            assert!((result[0] - 0.1).abs() < 1e-12);
+            assert!((result[1] - 0.2).abs() < 1e-12);
+            assert!((result[2] - 0.3).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let axis = Vector::new(vec![1., -1., 0.5]);
+        let a = Quaternion::from_axis_angle(&axis, -0.5);
+        let b = Quaternion::from_axis_angle(&axis, 2.5);
+        assert!((a.slerp(b, 0.) - a).norm() < 1e-6);
+        assert!((a.slerp(b, 1.) - b).norm() < 1e-6);
+        assert!((a.slerp(b, 0.5) - Quaternion::from_axis_angle(&axis, 1.)).norm() < 1e-6);
+        assert!((a.slerp(-b, 0.5) - Quaternion::from_axis_angle(&axis, 1.)).norm() < 1e-6);
+        assert!((a.nlerp(b, 0.5) - a.slerp(b, 0.5)).norm() < 1e-6);
+        assert!((a.slerp(a, 0.3) - a).norm() < 1e-6);
+    }
+}
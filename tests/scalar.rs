@@ -0,0 +1,86 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Vector;
+    use math::scalar::{Real, Scalar};
+
+    #[test]
+    fn default_f32() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let value: f32 = matrix.index(1, 1);
+        assert_eq!(value, 4.);
+
+        let vector = Vector::new(vec![1., 2.]);
+        let value: f32 = vector.index(0);
+        assert_eq!(value, 1.);
+    }
+
+    #[test]
+    fn integer_vector() {
+        let a = Vector::from_vec(vec![1, 2, 3]);
+        let b = Vector::from_vec(vec![4, 5, 6]);
+        assert_eq!(a.dot_vec(&b), 32);
+        assert_eq!(a.cross_vec(&b).vec(), vec![-3, 6, -3]);
+        assert_eq!((a + b).vec(), vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn integer_matrix() {
+        let a = Matrix::from_vec(vec![vec![1_i64, 2], vec![3, 4]]);
+        let b = Matrix::from_vec(vec![vec![5_i64, 6], vec![7, 8]]);
+        assert_eq!(a.dot_mat(&b).matrix_flatt(), vec![19, 22, 43, 50]);
+
+        let zero = Matrix::<u8>::zero(2, 3);
+        assert_eq!(zero.matrix_flatt(), vec![0; 6]);
+    }
+
+    #[test]
+    fn f64_decompositions() {
+        let matrix = Matrix::from_vec(vec![
+            vec![4_f64, 12., -16.],
+            vec![12., 37., -43.],
+            vec![-16., -43., 98.],
+        ]);
+        assert_delta!(matrix.det(), 36., 1e-9);
+        assert_delta!(matrix.lu().det(), 36., 1e-9);
+        assert_delta!(matrix.cholesky().det(), 36., 1e-9);
+
+        let b = Vector::from_vec(vec![1_f64, 2., 3.]);
+        let x = matrix.lu().solve(&b);
+        let y = matrix.dot_vec(&x);
+        y.vec()
+            .iter()
+            .zip(b.vec())
+            .for_each(|(y, b)| assert_delta!(y, b, 1e-9));
+
+        let svd = matrix.svd();
+        assert_delta!(
+            svd.norm2() / svd.singular_values().index(2),
+            svd.cond(),
+            1e-6
+        );
+
+        let eigen = matrix.symmetric_eigen();
+        let product: f64 = eigen.eigenvalues().vec().iter().product();
+        assert_delta!(product, 36., 1e-8);
+    }
+
+    #[test]
+    fn f64_precision() {
+        let a = Vector::from_vec(vec![1_f64, 0.]);
+        let b = Vector::from_vec(vec![1. + 1e-10, 0.]);
+        assert_delta!(a.dist(&b), 1e-10, 1e-15);
+    }
+
+    #[test]
+    fn traits() {
+        assert_eq!(i32::zero(), 0);
+        assert_eq!(u8::one(), 1);
+        assert_eq!(f64::epsilon(), f64::EPSILON);
+        assert_eq!(<f32 as Real>::sqrt(16.), 4.);
+        assert_eq!(f64::from_usize(3), 3.);
+    }
+}
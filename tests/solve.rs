@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{MathError, Matrix, SolveMethod, Vector};
+
+    fn close(a: &Vector<f64>, b: &Vector<f64>) -> bool {
+        (0..a.len()).all(|i| (a.index(i) - b.index(i)).abs() < 1e-10)
+    }
+
+    fn method(matrix: &Matrix<f64>) -> SolveMethod {
+        let b = Vector::from_vec(vec![1.; matrix.cols()]);
+        let solution = matrix.solve_refined(&b, 0);
+        assert!(solution.backward_error() < 1e-14);
+        solution.method()
+    }
+
+    #[test]
+    fn dispatch() {
+        let lower = Matrix::from_vec(vec![vec![2., 0., 0.], vec![1., 3., 0.], vec![4., 5., 6.]]);
+        assert_eq!(method(&lower), SolveMethod::LowerTriangular);
+        let mut upper = lower.clone();
+        upper.transpose();
+        assert_eq!(method(&upper), SolveMethod::UpperTriangular);
+        let diagonal = Matrix::from_vec(vec![vec![2., 0.], vec![0., 4.]]);
+        assert_eq!(method(&diagonal), SolveMethod::UpperTriangular);
+
+        let spd = Matrix::from_vec(vec![vec![4., 1., 2.], vec![1., 3., 0.], vec![2., 0., 5.]]);
+        assert_eq!(method(&spd), SolveMethod::Cholesky);
+        let indefinite =
+            Matrix::from_vec(vec![vec![1., 2., 3.], vec![2., 1., 0.], vec![3., 0., 1.]]);
+        assert_eq!(method(&indefinite), SolveMethod::Lu);
+        let general = Matrix::from_vec(vec![vec![0., 2., 1.], vec![1., 1., 0.], vec![3., 0., 1.]]);
+        assert_eq!(method(&general), SolveMethod::Lu);
+    }
+
+    #[test]
+    fn banded() {
+        // a tridiagonal matrix with a zero on the diagonal so the band needs pivoting
+        let n = 8;
+        let mut matrix = Matrix::<f64>::zero(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = if i == 3 { 0. } else { 2. + i as f64 };
+            if i > 0 {
+                matrix[(i, i - 1)] = -1.;
+            }
+            if i + 1 < n {
+                matrix[(i, i + 1)] = 1.5;
+            }
+        }
+        let expected = Vector::from_vec((0..n).map(|i| i as f64 - 3.).collect());
+        let b = matrix.dot_vec(&expected);
+        let solution = matrix.solve_refined(&b, 1);
+        assert_eq!(
+            solution.method(),
+            SolveMethod::Banded { lower: 1, upper: 1 }
+        );
+        assert!(close(solution.solution(), &expected));
+        assert!(close(&matrix.lu().solve(&b), &expected));
+    }
+
+    #[test]
+    fn rectangular() {
+        // fits the line y = a + b * x through the points (0, 1), (1, 3) and (2, 4)
+        let matrix = Matrix::from_vec(vec![vec![1., 0.], vec![1., 1.], vec![1., 2.]]);
+        let b = Vector::from_vec(vec![1., 3., 4.]);
+        let solution = matrix.solve_refined(&b, 1);
+        assert_eq!(solution.method(), SolveMethod::LeastSquares);
+        assert!(close(
+            solution.solution(),
+            &Vector::from_vec(vec![7. / 6., 1.5])
+        ));
+        assert!((solution.residual() - (1. / 6_f64).sqrt()).abs() < 1e-10);
+
+        // the minimum norm solution of x + y = 2 is (1, 1)
+        let matrix = Matrix::from_vec(vec![vec![1., 1.]]);
+        let solution = matrix.solve_refined(&Vector::from_vec(vec![2.]), 0);
+        assert_eq!(solution.method(), SolveMethod::MinimumNorm);
+        assert!(close(solution.solution(), &Vector::from_vec(vec![1., 1.])));
+        assert!(solution.residual() < 1e-12);
+    }
+
+    #[test]
+    fn solve_mat() {
+        let matrix =
+            Matrix::<f64>::from_vec(vec![vec![0., 2., 1.], vec![1., 1., 0.], vec![3., 0., 1.]]);
+        let identity = Matrix::from_vec(vec![vec![1., 0., 0.], vec![0., 1., 0.], vec![0., 0., 1.]]);
+        let inverse = matrix.solve_mat(&identity);
+        let product = matrix.dot_mat(&inverse).matrix_flatt();
+        let expected = identity.matrix_flatt();
+        assert!((0..9).all(|i| (product[i] - expected[i]).abs() < 1e-12));
+
+        let solution = matrix.solve_mat_refined(&identity, 3);
+        assert_eq!(solution.method(), SolveMethod::Lu);
+        assert!(solution.backward_error() < 1e-15);
+    }
+
+    #[test]
+    fn refinement() {
+        // a badly scaled system where refinement reduces the residual
+        let matrix = Matrix::from_vec(vec![
+            vec![1e-8, 1., 2.],
+            vec![1., 1e8, 3.],
+            vec![4., 5., 1e-8],
+        ]);
+        let b = Vector::from_vec(vec![1., 2., 3.]);
+        let plain = matrix.solve_refined(&b, 0);
+        let refined = matrix.solve_refined(&b, 3);
+        assert!(refined.residual() <= plain.residual());
+        assert!(refined.backward_error() < 1e-15);
+    }
+
+    #[test]
+    fn errors() {
+        let singular = Matrix::from_vec(vec![vec![1., 2.], vec![2., 4.]]);
+        let b = Vector::from_vec(vec![1., 2.]);
+        assert_eq!(singular.try_solve(&b), Err(MathError::Singular));
+        let triangular = Matrix::from_vec(vec![vec![1., 0.], vec![2., 0.]]);
+        assert_eq!(triangular.try_solve(&b), Err(MathError::Singular));
+        let rank_deficient = Matrix::from_vec(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+        assert_eq!(
+            rank_deficient.try_solve(&Vector::from_vec(vec![1., 2., 3.])),
+            Err(MathError::Singular)
+        );
+        assert_eq!(
+            singular.try_solve(&Vector::from_vec(vec![1., 2., 3.])),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,205 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::transpose;
+    use math::linear_algebra::{
+        CooMatrix, CscMatrix, CsrMatrix, IterativeOptions, Jacobi, LinearOperator, MathError,
+        Matrix, Vector,
+    };
+
+    fn dense() -> Matrix<f64> {
+        Matrix::from_vec(vec![
+            vec![4., 0., 1., 0.],
+            vec![0., 0., 0., 0.],
+            vec![2., 3., 0., -1.],
+        ])
+    }
+
+    #[test]
+    fn coo() {
+        let mut coo = CooMatrix::new(3, 4);
+        for &(row, col, value) in [
+            (2, 3, -1.),
+            (0, 2, 1.),
+            (2, 0, 2.),
+            (0, 0, 3.),
+            (2, 1, 3.),
+            (0, 0, 1.),
+        ]
+        .iter()
+        {
+            coo.push(row, col, value);
+        }
+        assert_eq!(coo.nnz(), 6);
+        assert_eq!(coo.to_dense(), dense());
+        let csr = coo.to_csr();
+        assert_eq!(csr, CsrMatrix::from_dense(&dense()));
+        assert_eq!(csr.offsets(), &[0, 2, 2, 5]);
+        assert_eq!(csr.indices(), &[0, 2, 0, 1, 3]);
+        assert_eq!(csr.values(), &[4., 1., 2., 3., -1.]);
+        assert_eq!(CscMatrix::from(&coo), CscMatrix::from_dense(&dense()));
+        assert_eq!(
+            coo.try_push(3, 0, 1.),
+            Err(MathError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let csr = CsrMatrix::from_dense(&dense());
+        let csc = csr.to_csc();
+        assert_eq!((csc.cols(), csc.rows()), (3, 4));
+        assert_eq!(csc.offsets(), &[0, 2, 3, 4, 5]);
+        assert_eq!(csc.indices(), &[0, 2, 2, 0, 2]);
+        assert_eq!(csc.to_dense(), dense());
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(Matrix::from(&csc), dense());
+        assert_eq!(csr.index(2, 1), 3.);
+        assert_eq!(csc.index(1, 1), 0.);
+        assert_eq!(csr.diagonal(), Vector::from_vec(vec![4., 0., 0.]));
+        assert_eq!(
+            csr.try_index(0, 4),
+            Err(MathError::IndexOutOfBounds { index: 4, len: 4 })
+        );
+
+        assert_eq!(csr.transpose().to_dense(), transpose(&dense()));
+        assert_eq!(csc.transpose().to_dense(), transpose(&dense()));
+        assert_eq!(CsrMatrix::<f64>::zero(2, 5).to_dense(), Matrix::zero(2, 5));
+    }
+
+    #[test]
+    fn from_parts() {
+        // unsorted indices and duplicates are accepted
+        let csr =
+            CsrMatrix::from_parts(2, 3, vec![0, 3, 4], vec![2, 0, 2, 1], vec![1., 2., 3., 4.]);
+        assert_eq!(
+            csr.to_dense(),
+            Matrix::from_vec(vec![vec![2., 0., 4.], vec![0., 4., 0.]])
+        );
+        assert_eq!(csr.indices(), &[0, 2, 1]);
+
+        assert_eq!(
+            CsrMatrix::try_from_parts(2, 3, vec![0, 1], vec![0], vec![1.]),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            CscMatrix::try_from_parts(2, 3, vec![0, 1, 1, 1], vec![2], vec![1.]),
+            Err(MathError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        assert_eq!(
+            CsrMatrix::try_from_parts(2, 3, vec![0, 2, 1], vec![0, 1], vec![1., 1.]),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            CsrMatrix::try_from_parts(2, 3, vec![0, 2, 2], vec![0], vec![1., 1.]),
+            Err(MathError::ShapeMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn products() {
+        let x = Vector::from_vec(vec![1., -2., 3., 0.5]);
+        let expected = dense().dot_vec(&x);
+        assert_eq!(CsrMatrix::from_dense(&dense()).dot_vec(&x), expected);
+        assert_eq!(CscMatrix::from_dense(&dense()).dot_vec(&x), expected);
+
+        let other = Matrix::from_vec(vec![
+            vec![1., 0.],
+            vec![0., 2.],
+            vec![-1., 0.],
+            vec![0., 6.],
+        ]);
+        let expected = dense().dot_mat(&other);
+        let csr = CsrMatrix::from_dense(&dense()).dot_mat(&CsrMatrix::from_dense(&other));
+        assert_eq!(csr.to_dense(), expected);
+        let csc = CscMatrix::from_dense(&dense()).dot_mat(&CscMatrix::from_dense(&other));
+        assert_eq!(csc.to_dense(), expected);
+        // the cancelling products are stored as an explicit zero
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csr.index(2, 1), 0.);
+
+        assert_eq!(
+            CsrMatrix::from_dense(&dense()).try_dot_vec(&Vector::from_vec(vec![1., 2., 3.])),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            CsrMatrix::from_dense(&other).try_dot_mat(&CsrMatrix::from_dense(&other)),
+            Err(MathError::ShapeMismatch {
+                expected: 2,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn add_and_scale() {
+        let a = CsrMatrix::from_dense(&dense());
+        let b = CsrMatrix::from_dense(&Matrix::from_vec(vec![
+            vec![-4., 1., 0., 0.],
+            vec![0., 0., 0., 2.],
+            vec![0., 0., 0., 1.],
+        ]));
+        let sum = a.clone() + b.clone();
+        assert_eq!(sum.to_dense(), dense() + b.to_dense());
+        assert_eq!((a.to_csc() + b.to_csc()).to_dense(), dense() + b.to_dense());
+        assert_eq!((a.clone() * 2.).to_dense(), dense() * 2.);
+
+        let mut a = a;
+        assert_eq!(
+            a.try_add_mat(&a.transpose()),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn iterative() {
+        // the 1d poisson matrix assembled from the element matrices of 100 elements
+        let n = 100;
+        let mut coo = CooMatrix::<f64>::new(n, n);
+        for element in 0..=n {
+            let nodes = [element.wrapping_sub(1), element];
+            for &i in nodes.iter().filter(|&&i| i < n) {
+                for &j in nodes.iter().filter(|&&j| j < n) {
+                    coo.push(i, j, if i == j { 1. } else { -1. });
+                }
+            }
+        }
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3 * n - 2);
+        assert_eq!(csr.index(5, 5), 2.);
+
+        let b = Vector::from_vec(vec![1.; n]);
+        let jacobi = Jacobi::from_diagonal(&csr.diagonal());
+        let options = IterativeOptions {
+            preconditioner: Some(&jacobi),
+            ..IterativeOptions::default()
+        };
+        let solution = csr.cg(&b, &options);
+        assert!(solution.converged());
+        let dense = csr.to_dense();
+        let x = dense.solve(&b);
+        assert!((0..n).all(|i| (solution.solution().index(i) - x.index(i)).abs() < 1e-4));
+        let options = IterativeOptions {
+            restart: n,
+            ..IterativeOptions::default()
+        };
+        assert!(csr.to_csc().gmres(&b, &options).converged());
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{
+        CooMatrix, CscMatrix, FillOrdering, MathError, Matrix, SparseCholesky, SparseLu,
+        SymbolicCholesky, SymbolicLu, Vector,
+    };
+
+    const ORDERINGS: [FillOrdering; 3] = [
+        FillOrdering::Natural,
+        FillOrdering::MinimumDegree,
+        FillOrdering::ReverseCuthillMcKee,
+    ];
+
+    // the 5 point laplacian of a grid with the nodes numbered by rows
+    fn poisson(width: usize, height: usize) -> CscMatrix<f64> {
+        let n = width * height;
+        let mut coo = CooMatrix::new(n, n);
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+                coo.push(i, i, 4.);
+                if x + 1 < width {
+                    coo.push(i, i + 1, -1.);
+                    coo.push(i + 1, i, -1.);
+                }
+                if y + 1 < height {
+                    coo.push(i, i + width, -1.);
+                    coo.push(i + width, i, -1.);
+                }
+            }
+        }
+        coo.to_csc()
+    }
+
+    fn rhs(n: usize) -> Vector<f64> {
+        Vector::from_vec((0..n).map(|i| (i % 7) as f64 - 3.).collect())
+    }
+
+    fn assert_solves(matrix: &CscMatrix<f64>, x: &Vector<f64>, b: &Vector<f64>) {
+        let mut residual = matrix.dot_vec(x);
+        residual -= b.clone();
+        assert!(residual.mag() <= 1e-10 * b.mag());
+    }
+
+    fn bandwidth(matrix: &CscMatrix<f64>, permutation: &[usize]) -> usize {
+        let mut inverse = vec![0; permutation.len()];
+        for (k, &i) in permutation.iter().enumerate() {
+            inverse[i] = k;
+        }
+        let (offsets, indices) = (matrix.offsets(), matrix.indices());
+        (0..matrix.cols())
+            .flat_map(|col| (offsets[col]..offsets[col + 1]).map(move |p| (indices[p], col)))
+            .map(|(row, col)| (inverse[row] as isize - inverse[col] as isize).unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn orderings() {
+        let matrix = poisson(8, 3);
+        for &ordering in ORDERINGS.iter() {
+            let mut permutation = ordering.permutation(&matrix);
+            permutation.sort_unstable();
+            assert_eq!(permutation, (0..24).collect::<Vec<usize>>());
+        }
+        // the rows of the long side of the grid are 8 apart in the natural order
+        let rcm = FillOrdering::ReverseCuthillMcKee.permutation(&matrix);
+        assert_eq!(bandwidth(&matrix, &(0..24).collect::<Vec<usize>>()), 8);
+        assert!(bandwidth(&matrix, &rcm) <= 4);
+        assert_eq!(
+            FillOrdering::MinimumDegree.try_permutation(&CscMatrix::<f64>::zero(2, 3)),
+            Err(MathError::NotSquare { cols: 2, rows: 3 })
+        );
+    }
+
+    #[test]
+    fn sparse_cholesky() {
+        let matrix = poisson(6, 5);
+        let b = rhs(30);
+        let expected = matrix.to_dense().solve(&b);
+        let natural = SparseCholesky::new(&matrix, FillOrdering::Natural);
+        for &ordering in ORDERINGS.iter() {
+            let cholesky = matrix.cholesky(ordering);
+            let x = cholesky.solve(&b);
+            assert_solves(&matrix, &x, &b);
+            let mut difference = x;
+            difference -= expected.clone();
+            assert!(difference.mag() <= 1e-10 * expected.mag());
+            // the fill reducing orderings do not fill in more than the band of the natural order
+            assert!(cholesky.l().nnz() <= natural.l().nnz());
+        }
+
+        // L * L^T is the permuted matrix
+        let cholesky = matrix.cholesky(FillOrdering::MinimumDegree);
+        let l = cholesky.l().to_dense();
+        let mut lt = l.clone();
+        lt.transpose();
+        let product = &l * &lt;
+        let p = cholesky.permutation();
+        for i in 0..30 {
+            for j in 0..30 {
+                assert!((product.index(i, j) - matrix.index(p[i], p[j])).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn symbolic_reuse() {
+        let matrix = poisson(4, 4);
+        let b = rhs(16);
+        let cholesky = SymbolicCholesky::new(&matrix, FillOrdering::MinimumDegree);
+        let lu = SymbolicLu::new(&matrix, FillOrdering::ReverseCuthillMcKee);
+        for scale in 1..4 {
+            let mut scaled = matrix.clone();
+            scaled.mul_scalar(&(scale as f64));
+            let factor = cholesky.factor(&scaled);
+            assert_eq!(factor.permutation(), cholesky.permutation());
+            assert_eq!(factor.l().nnz(), cholesky.nnz());
+            assert_solves(&scaled, &factor.solve(&b), &b);
+            assert_solves(&scaled, &lu.factor(&scaled).solve(&b), &b);
+        }
+
+        let other = poisson(8, 2);
+        assert_eq!(
+            cholesky.try_factor(&other).map(|_| ()),
+            Err(MathError::PatternMismatch)
+        );
+        assert_eq!(
+            lu.try_factor(&other).map(|_| ()),
+            Err(MathError::PatternMismatch)
+        );
+    }
+
+    #[test]
+    fn sparse_cholesky_errors() {
+        let indefinite = CscMatrix::from_dense(&Matrix::<f64>::from_vec(vec![
+            vec![1., 2., 0.],
+            vec![2., 1., 0.],
+            vec![0., 0., 1.],
+        ]));
+        assert_eq!(
+            indefinite.try_cholesky(FillOrdering::Natural).map(|_| ()),
+            Err(MathError::NotPositiveDefinite)
+        );
+        let unsymmetric_pattern =
+            CscMatrix::from_dense(&Matrix::<f64>::from_vec(vec![vec![4., 1.], vec![0., 4.]]));
+        assert_eq!(
+            SymbolicCholesky::try_new(&unsymmetric_pattern, FillOrdering::Natural).map(|_| ()),
+            Err(MathError::NotSymmetric)
+        );
+        let unsymmetric_values =
+            CscMatrix::from_dense(&Matrix::<f64>::from_vec(vec![vec![4., 1.], vec![2., 4.]]));
+        assert_eq!(
+            unsymmetric_values
+                .try_cholesky(FillOrdering::Natural)
+                .map(|_| ()),
+            Err(MathError::NotSymmetric)
+        );
+        assert_eq!(
+            poisson(2, 2)
+                .cholesky(FillOrdering::Natural)
+                .try_solve(&rhs(3))
+                .map(|_| ()),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn sparse_lu() {
+        // an unsymmetric matrix whose zero diagonal needs pivoting
+        let dense = Matrix::<f64>::from_vec(vec![
+            vec![0., 2., 0., 1., 0.],
+            vec![3., 0., 0., 0., 1.],
+            vec![0., 1., 0., 4., 0.],
+            vec![1., 0., 2., 0., 0.],
+            vec![0., 0., 5., 0., 2.],
+        ]);
+        let matrix = CscMatrix::from_dense(&dense);
+        let b = Vector::from_vec(vec![1., 2., 3., 4., 5.]);
+        let expected = dense.solve(&b);
+        for &ordering in ORDERINGS.iter() {
+            let lu = SparseLu::new(&matrix, ordering);
+            let x = lu.solve(&b);
+            assert_solves(&matrix, &x, &b);
+            let mut difference = x;
+            difference -= expected.clone();
+            assert!(difference.mag() <= 1e-10 * expected.mag());
+
+            // L * U is the permuted matrix
+            let product = &lu.l().to_dense() * &lu.u().to_dense();
+            let (p, q) = (lu.row_permutation(), lu.column_permutation());
+            for (i, &row) in p.iter().enumerate() {
+                assert_eq!(lu.l().index(i, i), 1.);
+                for (j, &col) in q.iter().enumerate() {
+                    assert!((product.index(i, j) - dense.index(row, col)).abs() < 1e-12);
+                }
+            }
+        }
+
+        let matrix = poisson(5, 4);
+        let b = rhs(20);
+        assert_solves(
+            &matrix,
+            &matrix.lu(FillOrdering::MinimumDegree).solve(&b),
+            &b,
+        );
+
+        let singular = CscMatrix::from_dense(&Matrix::<f64>::from_vec(vec![
+            vec![1., 2., 0.],
+            vec![2., 4., 0.],
+            vec![0., 1., 1.],
+        ]));
+        assert_eq!(
+            singular.try_lu(FillOrdering::Natural).map(|_| ()),
+            Err(MathError::Singular)
+        );
+    }
+}
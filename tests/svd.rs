@@ -0,0 +1,147 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{assert_mat_delta, identity, transpose};
+    use math::linear_algebra::Matrix;
+    use math::linear_algebra::Svd;
+
+    fn assert_svd(matrix: &Matrix, svd: &Svd, d: f32) {
+        let (m, n) = (matrix.cols(), matrix.rows());
+        let r = m.min(n);
+
+        let (u, sigma, vt) = (svd.u(), svd.sigma(), svd.vt());
+        assert_eq!((u.cols(), u.rows()), (m, r));
+        assert_eq!((sigma.cols(), sigma.rows()), (r, r));
+        assert_eq!((vt.cols(), vt.rows()), (r, n));
+        assert_mat_delta(&u.dot_mat(&sigma).dot_mat(&vt), matrix, d);
+        assert_mat_delta(&transpose(&u).dot_mat(&u), &identity(r), d);
+        assert_mat_delta(&vt.dot_mat(&transpose(&vt)), &identity(r), d);
+
+        let (u, sigma, vt) = (svd.u_full(), svd.sigma_full(), svd.vt_full());
+        assert_eq!((u.cols(), u.rows()), (m, m));
+        assert_eq!((sigma.cols(), sigma.rows()), (m, n));
+        assert_eq!((vt.cols(), vt.rows()), (n, n));
+        assert_mat_delta(&u.dot_mat(&sigma).dot_mat(&vt), matrix, d);
+        assert_mat_delta(&transpose(&u).dot_mat(&u), &identity(m), d);
+        assert_mat_delta(&vt.dot_mat(&transpose(&vt)), &identity(n), d);
+
+        let s = svd.singular_values().vec();
+        for i in 1..r {
+            assert!(s[i - 1] >= s[i]);
+            assert!(s[i] >= 0.);
+        }
+    }
+
+    #[test]
+    fn tall() {
+        let matrix = Matrix::new_rand(7, 4);
+        assert_svd(&matrix, &matrix.svd(), 1e-5);
+    }
+
+    #[test]
+    fn wide() {
+        let matrix = Matrix::new_rand(3, 6);
+        assert_svd(&matrix, &Svd::new(&matrix), 1e-5);
+    }
+
+    #[test]
+    fn known() {
+        let matrix = Matrix::new(vec![vec![3., 2., 2.], vec![2., 3., -2.]]);
+        let svd = matrix.svd();
+        let s = svd.singular_values();
+        assert_delta!(s.index(0), 5., 1e-5);
+        assert_delta!(s.index(1), 3., 1e-5);
+        assert_delta!(svd.norm2(), 5., 1e-5);
+        assert_delta!(svd.cond(), 5. / 3., 1e-5);
+        assert_svd(&matrix, &svd, 1e-5);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let u = Matrix::new(vec![vec![1.], vec![2.], vec![-1.], vec![0.5]]);
+        let v = Matrix::new(vec![vec![2., 0., 1.]]);
+        let w = Matrix::new(vec![vec![0.], vec![1.], vec![1.], vec![1.]]);
+        let x = Matrix::new(vec![vec![1., 1., -1.]]);
+        let matrix = Matrix::new_flatt(
+            u.dot_mat(&v)
+                .matrix_flatt()
+                .iter()
+                .zip(w.dot_mat(&x).matrix_flatt())
+                .map(|(a, b)| a + b)
+                .collect(),
+            4,
+            3,
+        );
+
+        let svd = matrix.svd();
+        assert_eq!(svd.rank(1e-4), 2);
+        assert!(svd.cond() > 1e5);
+        assert_svd(&matrix, &svd, 1e-5);
+        assert_mat_delta(&svd.low_rank(2), &matrix, 1e-5);
+    }
+
+    #[test]
+    fn zero() {
+        let matrix = Matrix::new_zero(3, 2);
+        let svd = matrix.svd();
+        assert_eq!(svd.rank(0.), 0);
+        assert_eq!(svd.norm2(), 0.);
+        assert_svd(&matrix, &svd, 1e-6);
+        assert_eq!(svd.pinv(), Matrix::new_zero(2, 3));
+    }
+
+    #[test]
+    fn low_rank() {
+        let matrix = Matrix::new_rand(6, 5);
+        let svd = matrix.svd();
+        let s = svd.singular_values();
+
+        // the error of the best rank k approximation is the singular value k in the 2-norm
+        for k in 0..5 {
+            let error = Matrix::new_flatt(
+                matrix
+                    .matrix_flatt()
+                    .iter()
+                    .zip(svd.low_rank(k).matrix_flatt())
+                    .map(|(a, b)| a - b)
+                    .collect(),
+                6,
+                5,
+            );
+            assert_delta!(error.svd().norm2(), s.index(k), 1e-5);
+        }
+        assert_mat_delta(&svd.low_rank(5), &matrix, 1e-5);
+    }
+
+    #[test]
+    fn pinv() {
+        // the four moore penrose conditions
+        for matrix in [Matrix::new_rand(5, 3), Matrix::new_rand(2, 4)].iter() {
+            let pinv = matrix.svd().pinv();
+            assert_mat_delta(&matrix.dot_mat(&pinv).dot_mat(matrix), matrix, 1e-4);
+            assert_mat_delta(&pinv.dot_mat(matrix).dot_mat(&pinv), &pinv, 1e-4);
+            let ap = matrix.dot_mat(&pinv);
+            assert_mat_delta(&transpose(&ap), &ap, 1e-4);
+            let pa = pinv.dot_mat(matrix);
+            assert_mat_delta(&transpose(&pa), &pa, 1e-4);
+        }
+
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_mat_delta(&matrix.svd().pinv(), &matrix.lu().inverse(), 1e-4);
+    }
+
+    #[test]
+    fn matrix_pinv() {
+        // the rank deficient matrix only keeps the singular value 5 * sqrt(2)
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+        let pinv = matrix.pinv(1e-4);
+        assert_eq!((pinv.cols(), pinv.rows()), (2, 3));
+        assert_mat_delta(&matrix.dot_mat(&pinv).dot_mat(&matrix), &matrix, 1e-4);
+        assert_mat_delta(&pinv, &(transpose(&matrix) / 70.), 1e-6);
+
+        // a large tolerance drops every singular value
+        assert_mat_delta(&matrix.pinv(10.), &Matrix::zero(2, 3), 0.);
+    }
+}
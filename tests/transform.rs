@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Mat4, Quaternion, Vec3};
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close(a: Vec3<f64>, b: Vec3<f64>) -> bool {
+        a.dist(b) < 1e-12
+    }
+
+    #[test]
+    fn compose() {
+        let matrix = Mat4::translation(Vec3::new([1., 0., 0.]))
+            * Mat4::rotation_z(FRAC_PI_2)
+            * Mat4::scale(Vec3::new([2., 2., 2.]));
+        let point = Vec3::new([1., 0., 0.]);
+        assert!(close(
+            matrix.transform_point(point),
+            Vec3::new([1., 2., 0.])
+        ));
+        assert!(close(matrix.transform_dir(point), Vec3::new([0., 2., 0.])));
+    }
+
+    #[test]
+    fn axis_rotations() {
+        let x = Vec3::new([1., 0., 0.]);
+        let y = Vec3::new([0., 1., 0.]);
+        let z = Vec3::new([0., 0., 1.]);
+        assert!(close(Mat4::rotation_x(FRAC_PI_2).transform_dir(y), z));
+        assert!(close(Mat4::rotation_y(FRAC_PI_2).transform_dir(z), x));
+        assert!(close(Mat4::rotation_z(FRAC_PI_2).transform_dir(x), y));
+        for (axis, matrix) in [
+            (x, Mat4::rotation_x(0.7)),
+            (y, Mat4::rotation_y(0.7)),
+            (z, Mat4::rotation_z(0.7)),
+        ]
+        .iter()
+        {
+            let rotation = Mat4::rotation(*axis * 3., 0.7);
+            assert!((rotation - *matrix)
+                .as_slice()
+                .iter()
+                .all(|x| x.abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn rotation_matches_quaternion() {
+        let axis = Vec3::new([1., -2., 0.5]);
+        let quaternion = Quaternion::from_axis_angle(&axis, 1.3);
+        let point = Vec3::new([0.3, 2., -1.]);
+        assert!(close(
+            Mat4::rotation(axis, 1.3).transform_point(point),
+            quaternion * point
+        ));
+        assert_eq!(
+            Mat4::from_quaternion(Quaternion::<f64>::identity()),
+            Mat4::identity()
+        );
+    }
+
+    #[test]
+    fn look_at() {
+        let eye = Vec3::new([1., 2., 3.]);
+        let target = Vec3::new([4., 2., 3.]);
+        let view = Mat4::look_at(eye, target, Vec3::new([0., 1., 0.]));
+        assert!(close(view.transform_point(eye), Vec3::zero()));
+        assert!(close(
+            view.transform_point(target),
+            Vec3::new([0., 0., -3.])
+        ));
+        assert!(close(
+            view.transform_dir(Vec3::new([0., 1., 0.])),
+            Vec3::new([0., 1., 0.])
+        ));
+        assert!(close(
+            view.transform_dir(Vec3::new([0., 0., 1.])),
+            Vec3::new([1., 0., 0.])
+        ));
+    }
+
+    #[test]
+    fn projections() {
+        let perspective = Mat4::perspective(FRAC_PI_2, 1., 0.5, 50.);
+        assert!(close(
+            perspective.transform_point(Vec3::new([0., 0., -0.5])),
+            Vec3::new([0., 0., -1.])
+        ));
+        assert!(close(
+            perspective.transform_point(Vec3::new([-5., 5., -5.])),
+            Vec3::new([
+                -1.,
+                1.,
+                perspective.transform_point(Vec3::new([0., 0., -5.]))[2]
+            ])
+        ));
+        let orthographic = Mat4::orthographic(-1., 3., -2., 2., 0., 10.);
+        assert!(close(
+            orthographic.transform_point(Vec3::new([-1., -2., 0.])),
+            Vec3::new([-1., -1., -1.])
+        ));
+        assert!(close(
+            orthographic.transform_point(Vec3::new([3., 2., -10.])),
+            Vec3::new([1., 1., 1.])
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use math::linear_algebra::MathError;
     use math::linear_algebra::Vector;
 
     #[test]
@@ -124,7 +125,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "this only works with 3 dimensional vectors")]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn cross_vec_panic() {
         let vector1 = Vector::new(vec![1., 0., 0., 2.]);
         let vector2 = Vector::new(vec![0., 1., 0., 2.]);
@@ -220,9 +221,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "the other vector has not the same len self.len() = 3, other.len() = 4"
-    )]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn dot_vec_panic() {
         let vector1 = Vector::new(vec![2., 7., 1.]);
         let vector2 = Vector::new(vec![8., 2., 8., 1.]);
@@ -238,9 +237,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "the other vector has not the same len self.len() = 3, other.len() = 4"
-    )]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn mul_vec_panic() {
         let mut vector1 = Vector::new(vec![0., 2., 3.]);
         let vector2 = Vector::new(vec![3., 1., 3., 1.]);
@@ -256,9 +253,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "the other vector has not the same len self.len() = 3, other.len() = 4"
-    )]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn add_vec_panic() {
         let mut vector1 = Vector::new(vec![0., 2., 3.]);
         let vector2 = Vector::new(vec![3., 1., 3., 1.]);
@@ -274,9 +269,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "the other vector has not the same len self.len() = 3, other.len() = 4"
-    )]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn sub_vec_panic() {
         let mut vector1 = Vector::new(vec![0., 2., 3.]);
         let vector2 = Vector::new(vec![3., 1., 3., 1.]);
@@ -292,12 +285,98 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "the other vector has not the same len self.len() = 3, other.len() = 4"
-    )]
+    #[should_panic(expected = "wrong shape expected 3, got 4")]
     fn div_vec_panic() {
         let mut vector1 = Vector::new(vec![0., 2., 3.]);
         let vector2 = Vector::new(vec![3., 1., 3., 1.]);
         vector1.mul_vec(&vector2);
     }
+
+    #[test]
+    fn try_arithmetic() {
+        let mut vector1 = Vector::new(vec![0., 2., 3.]);
+        let vector2 = Vector::new(vec![3., 1., 3., 1.]);
+        let error = MathError::ShapeMismatch {
+            expected: 3,
+            actual: 4,
+        };
+
+        assert_eq!(vector1.try_add_vec(&vector2), Err(error.clone()));
+        assert_eq!(vector1.try_sub_vec(&vector2), Err(error.clone()));
+        assert_eq!(vector1.try_mul_vec(&vector2), Err(error.clone()));
+        assert_eq!(vector1.try_div_vec(&vector2), Err(error.clone()));
+        assert_eq!(vector1.try_dot_vec(&vector2), Err(error.clone()));
+        assert_eq!(vector1.try_dist(&vector2), Err(error));
+        assert_eq!(vector1, Vector::new(vec![0., 2., 3.]));
+
+        assert_eq!(vector1.try_add_vec(&Vector::new(vec![1., 1., 1.])), Ok(()));
+        assert_eq!(vector1, Vector::new(vec![1., 3., 4.]));
+    }
+
+    #[test]
+    fn try_cross_vec() {
+        let vector1 = Vector::new(vec![1., 0., 0.]);
+        let vector2 = Vector::new(vec![0., 1.]);
+        assert_eq!(
+            vector1.try_cross_vec(&vector2),
+            Err(MathError::ShapeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            vector1.try_cross_vec(&Vector::new(vec![0., 1., 0.])),
+            Ok(Vector::new(vec![0., 0., 1.]))
+        );
+    }
+
+    #[test]
+    fn try_index() {
+        let vector = Vector::new(vec![1., 3., 6.]);
+        assert_eq!(vector.try_index(0), Ok(1.));
+        assert_eq!(
+            vector.try_index(5),
+            Err(MathError::IndexOutOfBounds { index: 5, len: 3 })
+        );
+    }
+
+    #[test]
+    fn reference_ops() {
+        let a = Vector::new(vec![1., 2., 3.]);
+        let b = Vector::new(vec![4., -2., 0.5]);
+        assert_eq!(&a + &b, Vector::new(vec![5., 0., 3.5]));
+        assert_eq!(&a - &b, Vector::new(vec![-3., 4., 2.5]));
+        assert_eq!(&a * &b, Vector::new(vec![4., -4., 1.5]));
+        assert_eq!(&a / &b, Vector::new(vec![0.25, -1., 6.]));
+        assert_eq!(&a * 2. + &b, Vector::new(vec![6., 2., 6.5]));
+        assert_eq!(a.clone() - &b, &a - b.clone());
+        assert_eq!(a, Vector::new(vec![1., 2., 3.]));
+
+        let mut c = a.clone();
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let a = Vector::new(vec![1., 2., 4.]);
+        assert_eq!(&a + 1., Vector::new(vec![2., 3., 5.]));
+        assert_eq!(&a - 1., Vector::new(vec![0., 1., 3.]));
+        assert_eq!(&a / 2., Vector::new(vec![0.5, 1., 2.]));
+        assert_eq!(2. * &a, &a * 2.);
+        assert_eq!(1. + &a, &a + 1.);
+        assert_eq!(8. / a.clone(), Vector::new(vec![8., 4., 2.]));
+        assert_eq!(1. - &a, Vector::new(vec![0., -1., -3.]));
+        assert_eq!(-&a, Vector::new(vec![-1., -2., -4.]));
+        assert_eq!(-a.clone(), -1. * a.clone());
+
+        let mut b = a;
+        b *= 3.;
+        b -= 1.;
+        assert_eq!(b, Vector::new(vec![2., 5., 11.]));
+
+        let integers = Vector::from_vec(vec![1_i32, 2, 3]);
+        assert_eq!(10 - &integers * 2, Vector::from_vec(vec![8, 6, 4]));
+    }
 }
@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{MathError, Matrix, Vector, VectorView};
+
+    fn matrix() -> Matrix {
+        Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ])
+    }
+
+    #[test]
+    fn view() {
+        let matrix = matrix();
+        let view = matrix.view();
+        assert_eq!(view.cols(), matrix.cols());
+        assert_eq!(view.rows(), matrix.rows());
+        for i in 0..matrix.cols() {
+            for j in 0..matrix.rows() {
+                assert_eq!(view.index(i, j), matrix.index(i, j));
+            }
+        }
+        assert_eq!(view.to_matrix(), matrix);
+        assert_eq!(view, matrix);
+    }
+
+    #[test]
+    fn col_row() {
+        let matrix = matrix();
+        for i in 0..matrix.cols() {
+            assert_eq!(matrix.col_view(i), matrix.col(i));
+        }
+        for j in 0..matrix.rows() {
+            assert_eq!(matrix.row_view(j), matrix.row(j));
+        }
+    }
+
+    #[test]
+    fn transpose() {
+        let mut transposed = matrix();
+        transposed.transpose();
+        let view = transposed.view();
+        assert_eq!(view.to_matrix().matrix_flatt(), transposed.matrix_flatt());
+        assert_eq!(view.transpose(), matrix());
+        for i in 0..transposed.cols() {
+            assert_eq!(transposed.col_view(i), transposed.col(i));
+        }
+        assert_eq!(
+            transposed.diagonal().to_vector(),
+            Vector::new(vec![1., 6., 11.])
+        );
+    }
+
+    #[test]
+    fn submatrix() {
+        let matrix = matrix();
+        let block = matrix.submatrix(1, 2, 2, 2);
+        assert_eq!(
+            block.to_matrix(),
+            Matrix::new(vec![vec![7., 8.], vec![11., 12.]])
+        );
+        assert_eq!(block.col(1).to_vector(), Vector::new(vec![11., 12.]));
+        assert_eq!(block.row(0).to_vector(), Vector::new(vec![7., 11.]));
+
+        let inner = block.submatrix(1, 0, 1, 2);
+        assert_eq!(inner.to_matrix(), Matrix::new(vec![vec![11., 12.]]));
+
+        assert_eq!(
+            matrix
+                .try_submatrix(2, 0, 2, 1)
+                .map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds { index: 4, len: 3 })
+        );
+        assert_eq!(
+            matrix
+                .try_submatrix(usize::MAX, 0, 2, 1)
+                .map(|view| view.to_matrix()),
+            Err(MathError::IndexOutOfBounds {
+                index: usize::MAX,
+                len: 3
+            })
+        );
+        assert_eq!(
+            block.try_index(0, 2),
+            Err(MathError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+    }
+
+    #[test]
+    fn step() {
+        let matrix = matrix();
+        let view = matrix.view().step(2, 3);
+        assert_eq!(
+            view.to_matrix(),
+            Matrix::new(vec![vec![1., 4.], vec![9., 12.]])
+        );
+
+        let vector = Vector::new(vec![1., 2., 3., 4., 5., 6., 7.]);
+        let view = vector.view().step(3);
+        assert_eq!(view.to_vector(), Vector::new(vec![1., 4., 7.]));
+        assert_eq!(view.slice(1, 2).to_vector(), Vector::new(vec![4., 7.]));
+        assert!(vector.view().slice(7, 0).is_empty());
+    }
+
+    #[test]
+    fn mutable() {
+        let mut matrix = matrix();
+        matrix.submatrix_mut(0, 0, 2, 2).fill(0.);
+        matrix.col_view_mut(2).add_scalar(&1.);
+        matrix.row_view_mut(3).mul_scalar(&-1.);
+        matrix.diagonal_mut().set_index(2, 100.);
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![
+                vec![0., 0., 3., -4.],
+                vec![0., 0., 7., -8.],
+                vec![10., 11., 100., -13.],
+            ])
+        );
+
+        let mut transposed = self::matrix();
+        transposed.transpose();
+        transposed.col_view_mut(0).fill(0.);
+        transposed.transpose();
+        assert_eq!(transposed.row(0), Vector::new(vec![0., 0., 0.]));
+    }
+
+    #[test]
+    fn nested_mutable() {
+        let mut matrix = matrix();
+        let mut view = matrix.view_mut();
+        let mut block = view.submatrix_mut(1, 1, 2, 3);
+        block.step_mut(1, 2).mul_scalar(&10.);
+        block.row_mut(1).sub_scalar(&1.);
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![
+                vec![1., 2., 3., 4.],
+                vec![5., 60., 6., 80.],
+                vec![9., 100., 10., 120.],
+            ])
+        );
+    }
+
+    #[test]
+    fn arithmetic_with_views() {
+        let a = matrix();
+        let mut b = Matrix::new_zero(3, 4);
+        b.view_mut().add_mat(&a);
+        b.view_mut().sub_mat(a.view());
+        assert_eq!(b, Matrix::new_zero(3, 4));
+
+        b.submatrix_mut(0, 0, 2, 2)
+            .copy_from(a.submatrix(1, 2, 2, 2));
+        b.submatrix_mut(0, 0, 2, 2)
+            .mul_mat(&Matrix::new(vec![vec![2., 2.], vec![2., 2.]]));
+        b.submatrix_mut(0, 0, 2, 2).div_mat(a.submatrix(1, 2, 2, 2));
+        assert_eq!(
+            b.submatrix(0, 0, 2, 2),
+            Matrix::new(vec![vec![2., 2.], vec![2., 2.]])
+        );
+
+        assert_eq!(
+            b.view_mut().try_add_mat(a.submatrix(0, 0, 2, 2)),
+            Err(MathError::ShapeMismatch {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn dot_with_views() {
+        let a = matrix();
+        let block = a.submatrix(0, 1, 2, 3);
+        let product = a.dot_mat(a.view().transpose());
+        let expected = a.dot_mat(&a.view().transpose().to_matrix());
+        assert_eq!(product, expected);
+        assert_eq!(block.dot_mat(block.transpose()), {
+            let block = block.to_matrix();
+            let mut transposed = block.clone();
+            transposed.transpose();
+            block.dot_mat(&transposed)
+        });
+
+        let vector = Vector::new(vec![1., 0., 2., 0., 3., 0., 4., 0.]);
+        let strided = vector.view().step(2);
+        assert_eq!(a.dot_vec(strided), a.dot_vec(&strided.to_vector()));
+        assert_eq!(a.col_view(0).dot_vec(strided), 30.);
+
+        let mut sum = Vector::new(vec![1., 1., 1.]);
+        sum.add_vec(a.row_view(0));
+        sum.mul_vec(a.diagonal());
+        assert_eq!(sum, Vector::new(vec![2., 36., 110.]));
+        assert_eq!(sum.dist(a.diagonal()), (1_f32 + 900. + 9801.).sqrt());
+    }
+
+    #[test]
+    fn slice() {
+        let data = [1., 2., 3., 4.];
+        let view = VectorView::from_slice(&data);
+        assert_eq!(view.iter().sum::<f32>(), 10.);
+        assert_eq!(
+            view.try_slice(3, 2).map(|view| view.to_vector()),
+            Err(MathError::IndexOutOfBounds { index: 5, len: 4 })
+        );
+        assert_eq!(
+            view.try_slice(usize::MAX, 2).map(|view| view.to_vector()),
+            Err(MathError::IndexOutOfBounds {
+                index: usize::MAX,
+                len: 4
+            })
+        );
+        assert_eq!(format!("{:?}", view.step(2)), "[1.0, 3.0]");
+    }
+}